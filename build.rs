@@ -5,12 +5,17 @@ fn main() {}
 fn main() {
     println!("cargo:rerun-if-env-changed=MADAMIRU_VERSION");
     println!("cargo:rerun-if-changed=assets/windows/manifest.xml");
+    println!("cargo:rerun-if-changed=assets/windows/manifest-console-detached.xml");
 
     #[cfg(windows)]
     {
         let mut res = winres::WindowsResource::new();
         res.set_icon("assets/icon.ico");
-        res.set_manifest_file("assets/windows/manifest.xml");
+        if cfg!(feature = "console-allocation-policy") {
+            res.set_manifest_file("assets/windows/manifest-console-detached.xml");
+        } else {
+            res.set_manifest_file("assets/windows/manifest.xml");
+        }
         res.compile().unwrap();
     }
 
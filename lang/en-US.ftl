@@ -1,8 +1,14 @@
 thing-application = Application
+# The proportion between the width and height of media.
+thing-aspect-ratio = Aspect ratio
 thing-audio = Audio
 # How media will be fit into the available space (scale/crop/etc).
 thing-content-fit = Content fit
 thing-error = Error
+# A source used when the main sources can't provide enough media.
+thing-fallback-source = Fallback source
+# A color effect applied to media, like grayscale.
+thing-filter = Filter
 # https://en.wikipedia.org/wiki/Glob_(programming)
 thing-glob = Glob
 thing-image = Image
@@ -10,70 +16,251 @@ thing-items-per-line = Items per line
 thing-key-shift = Shift
 thing-language = Language
 thing-layout = Layout
+# How much detail to write to the log file.
+thing-log-level = Log level
 thing-media = Media
+# A label for something, like a group of media players.
+thing-name = Name
+# A fallback label for a group of media players, based on its position.
+thing-numbered-grid = Grid {$number}
+# How visible media is, where lower values make it dimmer.
+thing-opacity = Opacity
 thing-orientation = Orientation
 # Path to a file/folder on the system.
 thing-path = Path
 thing-playlist = Playlist
+# A bundle of settings for a common use case.
+thing-preset = Preset
+# The button in the main toolbar to shuffle or check for new media.
+thing-refresh-button = Refresh button
+# How images are smoothed when they're scaled (smooth/pixelated).
+thing-scaling = Scaling
 thing-settings = Settings
+# Audio that plays alongside all of the groups.
+thing-soundtrack = Soundtrack
+# The location where a specific file was found.
+thing-source = Source
 # Locations to find media.
 thing-sources = Sources
 # Visual theme for the application.
 thing-theme = Theme
+# How often the display refreshes while media is playing.
+thing-update-rate = Update rate
+thing-video = Video
 
 action-add-player = Add player
+# If disabled, a group avoids media that another group is already showing.
+action-allow-duplicate-media-across-groups = Allow media that's already shown in other groups
+# Copy a group's layout settings (but not its sources) to every group.
+action-apply-to-all-grids = Apply to all grids
 action-cancel = Cancel
 action-check-for-updates = Check for application updates automatically
+# Look for new files in the sources without changing what's playing.
+action-check-for-new-media = Check for new media
+action-choose-preset = Choose a preset
+action-choose-soundtrack = Choose soundtrack
 action-close = Close
+action-collapse-duplicate-files = Collapse duplicate files
 action-confirm = Confirm
 action-confirm-when-discarding-unsaved-playlist = Confirm when discarding unsaved playlist
+action-confirm-when-exiting-while-playing = Confirm when exiting while media is playing
+action-copy-to-folder = Copy to folder
+action-create-grid-per-subfolder = Create a grid for each subfolder
 action-crop = Crop
+# Instead of picking media at random, go through the files in order by path.
+action-cycle-through-media-in-order = Cycle through media in order
 action-desynchronize = Desynchronize
+# Make a copy of a group of media players.
+action-duplicate = Duplicate
+action-edit-sources = Edit sources
+# Resize all groups so that they take up equal space.
+action-even-out-layout = Even out layout
 action-exit-app = Exit application
+# When searching folders for media, also search inside of shortcuts/links to other folders.
+action-follow-symlinked-folders = Follow symlinked folders
+# Replace each glob source with the files that it currently matches.
+action-freeze-globs = Freeze globs into file list
+# Stop showing a file until the app is restarted or another playlist is loaded.
+action-hide-for-this-session = Hide for this session
 action-jump-position = Jump to random position
+# Keep a player's content fit override when it switches to new media.
+action-keep-content-fit-for-new-media = Keep content fit for new media
+# This prevents the display from dimming or going to sleep.
+action-keep-screen-awake-during-playback = Keep screen awake during playback
+# For videos, from a file with the same name, like `movie.srt` for `movie.mp4`.
+action-load-subtitles-automatically = Load subtitles automatically
+# Prevent groups from being dragged or resized by accident.
+action-lock-layout = Lock layout
+# Temporarily show one group of players on its own, filling the window.
+action-maximize = Maximize
+# Show the same media as another group of players.
+action-mirror-another-grid = Mirror another grid
+action-move-to-folder = Move to folder
+# Move a file to the system's trash/recycle bin.
+action-move-to-trash = Move to trash
 action-mute = Mute
+action-mute-new-players = Mute newly added players
+# Playback continues, but without sound.
+action-mute-when-window-is-minimized = Mute when window is minimized
+# Adjust the volume of each audio file so that they all sound about as loud.
+action-normalize-audio-volume = Normalize audio volume
+action-open-config-folder = Open config folder
 action-open-file = Open file
 action-open-folder = Open folder
+action-open-log-folder = Open log folder
 action-open-playlist = Open playlist
 action-pause = Pause
+action-pause-all-audio = Pause all audio
+action-pause-all-images = Pause all images
+action-pause-all-videos = Pause all videos
+# This happens if the window is minimized.
+action-pause-when-window-is-hidden = Pause when window is hidden
 # This happens if the user switches to another app or minimizes this app.
 action-pause-when-window-loses-focus = Pause when window loses focus
+# Assign a folder to the next free ctrl+number shortcut for moving/copying files.
+action-pin-to-keyboard-shortcut = Pin to keyboard shortcut
 action-play = Play
+action-play-all-audio = Play all audio
+action-play-all-images = Play all images
+action-play-all-videos = Play all videos
 action-play-for-this-many-seconds = Play for this many seconds
+action-play-images-for-this-many-seconds = Play images for this many seconds
+# Play a random part of each audio/video file instead of the whole thing.
+action-play-random-clips-of-this-many-seconds = Play random clips of this many seconds
+# Rearrange the grids into a new random layout and shuffle their media.
+action-randomize-layout = Randomize layout
+action-remove-soundtrack = Remove soundtrack
+# This will copy some diagnostic information and open the issue tracker.
+action-report-issue = Report an issue
+# Play the same media again.
+action-repeat = Repeat
+action-rescan-sources-automatically = Check sources for new media automatically
+# Go back to the group's content fit when a player switches to new media.
+action-reset-content-fit-for-new-media = Reset content fit for new media
+# Undo freezing globs into a file list.
+action-restore-globs = Restore globs
+# Undo maximizing a group of players.
+action-restore-layout = Restore layout
+action-restore-last-session-on-launch = Restore last session on launch
 action-save-playlist = Save playlist
 action-save-playlist-as-new-file = Save playlist as new file
+action-save-playlist-automatically = Save changes to playlist file automatically
+action-save-window-size-in-playlist = Save window size in playlist
 action-scale = Scale
 action-scale-down = Scale down
+# Scale images without blending pixels, which keeps pixel art crisp.
+action-scale-pixelated = Pixelated
+# Scale images by blending pixels, which is the usual behavior.
+action-scale-smoothly = Smooth
 action-select-folder = Select folder
 action-select-file = Select file
+# Show how many players are active and how much media is available in a group's title bar.
+action-show-media-counter = Show media counter
+# For photos, this is when they were taken. Otherwise, it's when the file was last changed.
+action-show-media-date = Show media date in player overlay
+action-show-notice-when-no-media-found = Show a notice in grids when no media is found
+# A thin bar at the bottom of each player that's always visible.
+action-show-progress-bars = Show progress bars on players
 action-shuffle = Shuffle
+action-shuffle-and-check-for-new-media = Shuffle and check for new media
+action-shuffle-when-selected = Shuffle when this group is selected
+# Mute all other groups so that only this one can be heard.
+action-solo-audio = Solo audio
 action-split-horizontally = Split horizontally
 action-split-vertically = Split vertically
 action-start-new-playlist = Start new playlist
+# Cycle through a list of playlists from the config file on a timer.
+action-start-playlist-rotation = Start playlist rotation
+# This is for the arrow key shortcuts that move backward/forward in media.
+action-step-this-many-seconds = Step forward/backward by this many seconds
+# Stop playing media, but keep the player in place.
+action-stop = Stop
+action-stop-playlist-rotation = Stop playlist rotation
 action-stretch = Stretch
 action-synchronize = Synchronize
+action-toggle-pause-on-click = Toggle pause on click
+# What the space key pauses when no grid or player is selected.
+action-toggle-pause-with-space = Toggle pause with space
+action-unlock-layout = Unlock layout
 action-unmute = Unmute
+action-unpin-from-keyboard-shortcut = Unpin from keyboard shortcut
+# Restore the mute state of each group from before one was soloed.
+action-unsolo-audio = Unsolo audio
+# Make the outline around the selected player thicker and easier to see.
+action-use-high-contrast-selection = Use high-contrast selection outline
 action-view-releases = View releases
+# What to do when a player reaches the end of its media.
+action-when-media-ends = When media ends
 
+# What the space key pauses when nothing is selected.
+state-all-players = All players
+state-balanced = Balanced
+# A grid preset with many small players, like a sheet of photo thumbnails.
+state-contact-sheet = Contact sheet
 # This refers to the dark-colored theme.
 state-dark = Dark
+state-double-click = Double click
+# A color filter that removes all color.
+state-grayscale = Grayscale
 state-horizontal = Horizontal
+# A color filter that inverts all colors.
+state-inverted = Inverted
 # This refers to the light-colored theme.
 state-light = Light
+state-off = Off
+state-original = Original
+# What the space key pauses when nothing is selected.
+state-player-under-cursor = Player under cursor
+state-power-saving = Power saving
+# What the space key pauses when nothing is selected.
+# With this option, it does nothing unless something is selected.
+state-selection-only = Selection only
+# A color filter with brownish tones, like an old photograph.
+state-sepia = Sepia
+state-single-click = Single click
+# A grid preset with one player that shows the whole media.
+state-single-focus = Single focus
+state-smooth = Smooth
 state-vertical = Vertical
+# A grid preset with rows of widescreen players.
+state-video-wall = Video wall
 
+# Shown in a group's title bar, like "3 / 240".
+tell-active-players-of-total-media = {$active} / {$total}
+# Shown over a video while it waits for more data to load.
+tell-buffering = Buffering...
 tell-config-is-invalid = The config file is invalid.
+tell-media-is-playing = Media is still playing.
+# Shown in place of media when the player is very small.
+tell-player-is-too-small = Too small, resize to view
 tell-player-will-loop = Player will loop
 tell-player-will-shuffle = Player will shuffle
 tell-playlist-has-unsaved-changes = Your playlist has unsaved changes.
 tell-playlist-is-invalid = The playlist file is invalid.
 tell-new-version-available = An application update is available: {$version}.
 tell-no-media-found-in-sources = No more media found in the configured sources.
+tell-no-subfolders-found = No subfolders found.
+tell-only-first-folders-will-be-used = Only the first {$total} folders will be used.
+tell-skipped-files-over-size-limit = Files skipped for exceeding the size limit: {$total}
+tell-unable-to-copy-file = Unable to copy file.
 tell-unable-to-determine-media-duration = Unable to determine media duration.
+tell-unable-to-load-soundtrack = Unable to load soundtrack.
+tell-unable-to-move-file = Unable to move file.
+tell-unable-to-move-file-to-trash = Unable to move file to trash.
 tell-unable-to-open-path = Unable to open path.
 tell-unable-to-open-url = Unable to open URL.
 tell-unable-to-save-playlist = Unable to save playlist.
+# "Pipeline" is a technical term from GStreamer, which is used for video playback.
+tell-video-pipeline-is-invalid = The custom video pipeline is invalid, so the default one will be used instead.
 
+# The sources of each grid will stay the same.
+ask-apply-layout-to-grids = Apply these layout settings to all grids? Total: {$total}
+ask-copy-file-to-folder = Copy this file to which folder?
 ask-discard-changes = Discard changes?
+ask-exit-anyway = Exit anyway?
 ask-load-new-playlist-anyway = Load a new playlist anyway?
+ask-move-file-to-folder = Move this file to which folder?
+ask-move-file-to-trash = Move this file to the trash?
+# This replaces all of the current groups.
+ask-replace-layout-with-folder-grids = Replace the current layout with one grid per folder? Total: {$total}
 ask-view-release-notes = Would you like to view the release notes?
@@ -38,6 +38,16 @@ pub fn parse_sources(sources: Vec<StrictPath>) -> Vec<media::Source> {
     }
 }
 
+pub fn parse_playlist_from_stdin() -> Result<Playlist, Error> {
+    use std::io::Read;
+
+    let mut content = String::new();
+    std::io::stdin()
+        .read_to_string(&mut content)
+        .map_err(|e| Error::PlaylistInvalid { why: e.to_string() })?;
+    Playlist::load_from_string(&content).map_err(|e| Error::PlaylistInvalid { why: e.to_string() })
+}
+
 pub fn parse() -> Result<Cli, clap::Error> {
     use clap::Parser;
     Cli::try_parse()
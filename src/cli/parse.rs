@@ -83,6 +83,16 @@ pub struct Cli {
     #[clap(long)]
     pub glob: Vec<String>,
 
+    /// Hide controls that would change the layout or settings,
+    /// such as for a public display.
+    #[clap(long)]
+    pub kiosk: bool,
+
+    /// Read a playlist from stdin (YAML or JSON) instead of from a file.
+    /// This cannot be combined with other sources.
+    #[clap(long, conflicts_with_all = ["sources", "glob"])]
+    pub playlist_stdin: bool,
+
     #[clap(subcommand)]
     pub sub: Option<Subcommand>,
 }
@@ -105,8 +115,30 @@ mod tests {
                 config: None,
                 sources: vec![],
                 glob: vec![],
+                kiosk: false,
+                playlist_stdin: false,
                 sub: None,
             },
         );
     }
+
+    #[test]
+    fn accepts_cli_with_playlist_from_stdin() {
+        check_args(
+            &["madamiru", "--playlist-stdin"],
+            Cli {
+                config: None,
+                sources: vec![],
+                glob: vec![],
+                kiosk: false,
+                playlist_stdin: true,
+                sub: None,
+            },
+        );
+    }
+
+    #[test]
+    fn rejects_cli_with_playlist_from_stdin_and_sources() {
+        assert!(Cli::try_parse_from(["madamiru", "--playlist-stdin", "foo.mp4"]).is_err());
+    }
 }
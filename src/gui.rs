@@ -1,3 +1,5 @@
+#[cfg(feature = "http-api")]
+mod api;
 mod app;
 mod button;
 mod common;
@@ -5,9 +7,12 @@ mod dropdown;
 mod font;
 mod grid;
 mod icon;
+#[cfg(feature = "media-controls")]
+mod media_controls;
 mod modal;
 mod player;
 mod shortcuts;
+mod soundtrack;
 mod style;
 mod undoable;
 mod widget;
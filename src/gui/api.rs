@@ -0,0 +1,292 @@
+//! Local HTTP server for controlling the application from other programs.
+//! See `docs/help/http-api.md` for the available endpoints.
+
+use std::{
+    io::{BufRead, BufReader, ErrorKind, Write},
+    net::{IpAddr, TcpListener, TcpStream},
+    time::Duration,
+};
+
+use iced::futures::{channel::mpsc, SinkExt, Stream, StreamExt};
+
+use crate::{gui::common::Message, prelude::StrictPath};
+
+/// How long to wait between checks for new connections.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How long to wait for a client to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+const STATUS_NO_CONTENT: &str = "204 No Content";
+const STATUS_BAD_REQUEST: &str = "400 Bad Request";
+const STATUS_UNAUTHORIZED: &str = "401 Unauthorized";
+const STATUS_FORBIDDEN: &str = "403 Forbidden";
+const STATUS_NOT_FOUND: &str = "404 Not Found";
+const STATUS_METHOD_NOT_ALLOWED: &str = "405 Method Not Allowed";
+
+pub fn serve(settings: &(String, u16, Option<String>)) -> impl Stream<Item = Message> {
+    let (host, port, token) = settings.clone();
+
+    iced::stream::channel(10, move |mut output| async move {
+        let (sender, mut receiver) = mpsc::unbounded();
+
+        let handle = tokio::task::spawn_blocking(move || {
+            let Some(token) = token else {
+                log::error!("Not starting HTTP API because no token is configured");
+                return;
+            };
+
+            let listener = match TcpListener::bind((host.as_str(), port)) {
+                Ok(listener) => listener,
+                Err(e) => {
+                    log::error!("Unable to start HTTP API on {host}:{port} | {e:?}");
+                    return;
+                }
+            };
+
+            if let Err(e) = listener.set_nonblocking(true) {
+                log::error!("Unable to configure HTTP API listener | {e:?}");
+                return;
+            }
+
+            log::info!("HTTP API listening on {host}:{port}");
+
+            // The receiver is dropped when the subscription ends (e.g., the API is disabled),
+            // which is our signal to stop and release the port.
+            while !sender.is_closed() {
+                match listener.accept() {
+                    Ok((stream, _)) => {
+                        if let Some(message) = handle_connection(stream, &token) {
+                            let _ = sender.unbounded_send(message);
+                        }
+                    }
+                    Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                        std::thread::sleep(POLL_INTERVAL);
+                    }
+                    Err(e) => {
+                        log::warn!("HTTP API connection failed | {e:?}");
+                    }
+                }
+            }
+
+            log::info!("HTTP API stopped");
+        });
+
+        while let Some(message) = receiver.next().await {
+            let _ = output.send(message).await;
+        }
+
+        if let Err(e) = handle.await {
+            log::error!("Failed to join task for HTTP API: {e:?}");
+        }
+    })
+}
+
+fn handle_connection(mut stream: TcpStream, token: &str) -> Option<Message> {
+    let _ = stream.set_nonblocking(false);
+    let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+
+    let mut reader = BufReader::new(&stream);
+
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() {
+        return None;
+    }
+
+    let mut headers = vec![];
+    let mut line = String::new();
+    loop {
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) | Err(_) => break,
+            Ok(_) if line.trim().is_empty() => break,
+            Ok(_) => {
+                if let Some((name, value)) = line.split_once(':') {
+                    headers.push((name.trim().to_lowercase(), value.trim().to_string()));
+                }
+            }
+        }
+    }
+
+    let (status, message) = match check_headers(&headers, token).and_then(|_| parse_request(&request_line)) {
+        Ok(message) => (STATUS_NO_CONTENT, Some(message)),
+        Err(status) => (status, None),
+    };
+
+    log::debug!("HTTP API request: {} -> {status}", request_line.trim());
+
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+    );
+
+    message
+}
+
+/// Only accept requests from programs that know the token.
+/// Browsers send an `Origin` header and may use another site's name as the `Host`,
+/// so we reject those to keep web pages from reaching the API.
+fn check_headers(headers: &[(String, String)], token: &str) -> Result<(), &'static str> {
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    };
+
+    if header("origin").is_some() {
+        return Err(STATUS_FORBIDDEN);
+    }
+
+    if !header("host").is_some_and(is_loopback_host) {
+        return Err(STATUS_FORBIDDEN);
+    }
+
+    match header("authorization").and_then(|value| value.strip_prefix("Bearer ")) {
+        Some(given) if given.trim() == token => Ok(()),
+        _ => Err(STATUS_UNAUTHORIZED),
+    }
+}
+
+fn is_loopback_host(host: &str) -> bool {
+    let host = match host.strip_prefix('[') {
+        Some(rest) => rest.split(']').next().unwrap_or_default(),
+        None => host.rsplit_once(':').map(|(host, _port)| host).unwrap_or(host),
+    };
+
+    host.eq_ignore_ascii_case("localhost") || host.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+}
+
+fn parse_request(request_line: &str) -> Result<Message, &'static str> {
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(STATUS_BAD_REQUEST);
+    };
+
+    let Ok(url) = url::Url::parse("http://localhost").and_then(|base| base.join(target)) else {
+        return Err(STATUS_BAD_REQUEST);
+    };
+
+    let message = match url.path() {
+        "/play" => Message::SetPause(false),
+        "/pause" => Message::SetPause(true),
+        "/shuffle" | "/next" => Message::Shuffle,
+        "/playlist" => {
+            let Some((_, path)) = url.query_pairs().find(|(key, _)| key == "path") else {
+                return Err(STATUS_BAD_REQUEST);
+            };
+            // This asks before discarding unsaved changes to the current playlist.
+            Message::PlaylistLoadRecent {
+                path: StrictPath::new(path.to_string()),
+            }
+        }
+        _ => return Err(STATUS_NOT_FOUND),
+    };
+
+    if method != "POST" {
+        return Err(STATUS_METHOD_NOT_ALLOWED);
+    }
+
+    Ok(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn can_parse_request() {
+        assert!(matches!(
+            parse_request("POST /pause HTTP/1.1"),
+            Ok(Message::SetPause(true))
+        ));
+        assert!(matches!(parse_request("POST /next HTTP/1.1"), Ok(Message::Shuffle)));
+        assert!(matches!(
+            parse_request("POST /playlist?path=%2Ftmp%2Fa%20b.madamiru HTTP/1.1"),
+            Ok(Message::PlaylistLoadRecent { path }) if path == StrictPath::new("/tmp/a b.madamiru")
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_request() {
+        assert_eq!(STATUS_BAD_REQUEST, parse_request("").unwrap_err());
+        assert_eq!(
+            STATUS_BAD_REQUEST,
+            parse_request("POST /playlist HTTP/1.1").unwrap_err()
+        );
+        assert_eq!(STATUS_NOT_FOUND, parse_request("POST /unknown HTTP/1.1").unwrap_err());
+        assert_eq!(
+            STATUS_METHOD_NOT_ALLOWED,
+            parse_request("GET /pause HTTP/1.1").unwrap_err()
+        );
+    }
+
+    fn headers(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn can_check_headers() {
+        let token = "secret";
+
+        assert_eq!(
+            Ok(()),
+            check_headers(
+                &headers(&[("host", "127.0.0.1:47808"), ("authorization", "Bearer secret")]),
+                token
+            )
+        );
+        assert_eq!(
+            Ok(()),
+            check_headers(
+                &headers(&[("host", "[::1]:47808"), ("authorization", "Bearer secret")]),
+                token
+            )
+        );
+        assert_eq!(
+            Ok(()),
+            check_headers(
+                &headers(&[("host", "localhost"), ("authorization", "Bearer secret")]),
+                token
+            )
+        );
+        assert_eq!(
+            Err(STATUS_UNAUTHORIZED),
+            check_headers(&headers(&[("host", "127.0.0.1:47808")]), token)
+        );
+        assert_eq!(
+            Err(STATUS_UNAUTHORIZED),
+            check_headers(
+                &headers(&[("host", "127.0.0.1:47808"), ("authorization", "Bearer wrong")]),
+                token
+            )
+        );
+        assert_eq!(
+            Err(STATUS_FORBIDDEN),
+            check_headers(
+                &headers(&[
+                    ("host", "127.0.0.1:47808"),
+                    ("origin", "http://example.com"),
+                    ("authorization", "Bearer secret")
+                ]),
+                token
+            )
+        );
+        assert_eq!(
+            Err(STATUS_FORBIDDEN),
+            check_headers(
+                &headers(&[("host", "example.com:47808"), ("authorization", "Bearer secret")]),
+                token
+            )
+        );
+        assert_eq!(
+            Err(STATUS_FORBIDDEN),
+            check_headers(&headers(&[("authorization", "Bearer secret")]), token)
+        );
+    }
+}
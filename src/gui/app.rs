@@ -1,39 +1,51 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeSet, HashMap, HashSet},
     num::NonZeroUsize,
     time::{Duration, Instant},
 };
 
-use iced::{keyboard, widget::pane_grid, Length, Subscription, Task};
+use iced::{
+    keyboard,
+    widget::{container, mouse_area, pane_grid},
+    Length, Subscription, Task,
+};
 use itertools::Itertools;
 
 use crate::{
     gui::{
         button,
-        common::{BrowseFileSubject, Flags, Message, PaneEvent, Selection, Step, UndoSubject},
+        common::{BrowseFileSubject, BrowseSubject, Flags, Message, PaneEvent, Selection, Step, Transfer, UndoSubject},
         grid::{self, Grid},
         icon::Icon,
         modal::{self, Modal},
         player::{self, Player},
         shortcuts::{Shortcut, TextHistories, TextHistory},
+        soundtrack::Soundtrack,
         style,
-        widget::{Column, Container, DropDown, Element, PaneGrid, Responsive, Row, Stack},
+        widget::{text, Column, Container, DropDown, Element, PaneGrid, Responsive, Row, Space, Stack},
     },
     lang, media,
     path::StrictPath,
-    prelude::{Change, Error, STEAM_DECK},
+    prelude::{app_dir, set_log_level, Change, Error, STEAM_DECK},
     resource::{
-        cache::Cache,
-        config::{self, Config},
+        cache::{Cache, ContentHash, LastSession},
+        config::{self, Config, Playback, SpaceTarget},
         playlist::{self, Playlist},
+        status::Status,
         ResourceFile, SaveableResourceFile,
     },
 };
 
+const IDLE_TICK_INTERVAL: Duration = Duration::from_secs(1);
+/// When creating a grid per subfolder, more than this would leave each grid too small to be useful.
+const MAX_FOLDER_GRIDS: usize = 16;
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum SaveKind {
     Config,
     Cache,
+    Playlist,
+    Status,
 }
 
 pub struct App {
@@ -46,16 +58,74 @@ pub struct App {
     grids: pane_grid::State<Grid>,
     media: media::Collection,
     last_tick: Instant,
+    last_tick_idle: bool,
     #[allow(unused)] // TODO: https://github.com/iced-rs/iced/pull/2691
     dragging_pane: bool,
     dragged_files: HashSet<StrictPath>,
+    /// Files dropped onto the window that are waiting for us to find which player is under the cursor.
+    dropped_files: Vec<StrictPath>,
+    /// Set by the refresh button so that it shuffles after the rescan that it started.
+    shuffle_after_scan: bool,
+    /// The OS sends one event per dropped file without saying where it landed,
+    /// so we go by the cursor's last known position.
+    cursor_position: Option<iced::Point>,
     viewing_menu: bool,
     viewing_pane_controls: Option<grid::Id>,
     playlist_path: Option<StrictPath>,
     playlist_dirty: bool,
+    /// Prevents grids from being dragged or resized.
+    layout_locked: bool,
+    /// Index of the current playlist in the configured rotation, if it's running.
+    rotation: Option<usize>,
+    soundtrack: Option<Soundtrack>,
+    /// From the current playlist, so that they're kept when it's saved again.
+    playlist_settings: Option<playlist::Settings>,
+    /// Pause/mute state from the current playlist's settings.
+    /// This applies until everything is paused/muted by hand,
+    /// and it isn't saved in the config.
+    session_paused: Option<bool>,
+    session_muted: Option<bool>,
+    /// The grid whose audio is soloed, along with each grid's mute state from before.
+    solo_audio: Option<(grid::Id, HashMap<grid::Id, bool>)>,
     selection: Selection,
     #[cfg_attr(not(feature = "audio"), allow(unused))]
     default_audio_output_device: Option<String>,
+    app_background: Option<iced::widget::image::Handle>,
+    /// Hide and ignore anything that would change the layout or settings.
+    kiosk: bool,
+    window_focused: bool,
+    window_size: Option<iced::Size>,
+    last_mouse_activity: Instant,
+    /// When each grid was last visible, for unloading the least recently shown ones first.
+    grids_last_shown: HashMap<grid::Id, Instant>,
+    /// Media that was unloaded from hidden grids to stay within the memory budget,
+    /// so that it can be loaded again when the grids are shown.
+    unloaded_media: HashMap<grid::Id, Vec<(player::Id, media::Media)>>,
+    /// Whether the top controls, grid title bars, and cursor are hidden due to inactivity.
+    chrome_hidden: bool,
+    /// Whether we muted every player because the window was minimized.
+    /// This only lasts for the session, so the saved mute setting isn't changed.
+    muted_for_minimize: bool,
+    /// Whether we paused every player because the window was hidden.
+    /// This only lasts for the session, so the saved pause setting isn't changed.
+    paused_for_hide: bool,
+    /// Whether the battery was low as of the last check,
+    /// so that we only pause once each time it runs low.
+    #[cfg(feature = "battery")]
+    battery_low: bool,
+    /// Prevents the screen from sleeping for as long as it's held.
+    #[cfg(feature = "keep-awake")]
+    keep_awake: Option<keepawake::KeepAwake>,
+    #[cfg(feature = "keep-awake")]
+    keep_awake_wanted: bool,
+    /// A player that the cursor entered, along with when,
+    /// which is waiting for `view.overlay_hover_delay_ms` before showing its controls.
+    pending_hover: Option<(grid::Id, player::Id, Instant)>,
+    #[cfg(feature = "media-controls")]
+    media_controls: Option<std::sync::mpsc::Sender<crate::gui::media_controls::Command>>,
+    /// What we last published to the OS's media controls.
+    #[cfg(feature = "media-controls")]
+    now_playing: Option<crate::gui::media_controls::NowPlaying>,
 }
 
 impl App {
@@ -72,32 +142,123 @@ impl App {
         self.show_modal(Modal::Error { variant: error })
     }
 
+    /// Advance a player to its next media, as long as it's still showing the given media.
+    fn move_on_from_media(&mut self, grid_id: grid::Id, player_id: player::Id, media: &media::Media) {
+        let Some(grid) = self.grids.get_mut(grid_id) else {
+            return;
+        };
+
+        // The player may have moved on while a modal was open.
+        if grid.player(player_id).and_then(|player| player.media()) != Some(media) {
+            return;
+        }
+
+        if let Some(update) = grid.update(
+            grid::Event::Player {
+                player_id,
+                event: player::Event::Refresh,
+            },
+            &mut self.media,
+            &self.config.playback,
+        ) {
+            self.handle_grid_update(update, grid_id);
+        }
+        self.sync_on_screen_media();
+    }
+
     fn save(&mut self) {
         let threshold = Duration::from_secs(1);
         let now = Instant::now();
 
+        let mut ready = vec![];
         self.pending_save.retain(|item, then| {
             if (now - *then) < threshold {
                 return true;
             }
 
+            ready.push(item.clone());
+            false
+        });
+
+        for item in ready {
             match item {
                 SaveKind::Config => self.config.save(),
                 SaveKind::Cache => self.cache.save(),
+                SaveKind::Playlist => self.autosave_playlist(),
+                SaveKind::Status => self.write_status(),
             }
+        }
+    }
 
-            false
-        });
+    fn autosave_playlist(&mut self) {
+        if let Some(path) = self.playlist_path.as_ref() {
+            match self.build_playlist().save_to(path) {
+                Ok(_) => {
+                    self.playlist_dirty = false;
+                }
+                Err(e) => {
+                    log::error!("Unable to auto-save playlist: {path:?} | {e:?}");
+                }
+            }
+        }
+    }
+
+    fn write_status(&self) {
+        let playback = self.playback();
+        let status = Status {
+            paused: playback.paused,
+            muted: playback.muted,
+            playlist: self.playlist_path.clone(),
+            active_players: self.grids.iter().map(|(_grid_id, grid)| grid.active_players()).sum(),
+        };
+        status.save_to(&self.config.status_file.path());
     }
 
     fn save_config(&mut self) {
         self.pending_save.insert(SaveKind::Config, Instant::now());
     }
 
+    fn save_status(&mut self) {
+        if self.config.status_file.enabled {
+            self.pending_save.insert(SaveKind::Status, Instant::now());
+        }
+    }
+
     fn save_cache(&mut self) {
         self.pending_save.insert(SaveKind::Cache, Instant::now());
     }
 
+    fn mark_playlist_dirty(&mut self) {
+        self.playlist_dirty = true;
+        self.save_status();
+
+        if self.config.view.autosave_playlist && self.playlist_path.is_some() {
+            self.pending_save.insert(SaveKind::Playlist, Instant::now());
+        }
+    }
+
+    /// Load the next playlist in the rotation after `current`,
+    /// skipping any that no longer exist.
+    fn rotate_playlist(&mut self, current: Option<usize>) -> Task<Message> {
+        let playlists = &self.config.rotation.playlists;
+        let start = current.map(|index| index + 1).unwrap_or(0);
+
+        for offset in 0..playlists.len() {
+            let index = (start + offset) % playlists.len();
+            let entry = &playlists[index];
+            if entry.path.is_file() {
+                let path = entry.path.clone();
+                self.rotation = Some(index);
+                return self.update(Message::PlaylistLoad { path });
+            }
+            log::warn!("Skipping missing playlist in rotation: {:?}", &entry.path);
+        }
+
+        log::warn!("Stopping rotation because no playlists are available");
+        self.rotation = None;
+        Task::none()
+    }
+
     fn open_url(url: String) -> Task<Message> {
         let url2 = url.clone();
         Task::future(async move {
@@ -125,13 +286,31 @@ impl App {
                 Config::default()
             }
         };
-        let cache = Cache::load().unwrap_or_default().migrate_config(&mut config);
+        let mut cache = Cache::load().unwrap_or_default().migrate_config(&mut config);
+        cache.prune_recent_playlists();
+        cache.prune_content_hashes();
+        player::prune_converted_subtitles();
         lang::set(config.view.language);
 
-        let sources = flags.sources.clone();
+        let sources = if flags.sources.is_empty() && flags.playlist.is_none() && config.view.restore_last_session {
+            cache.last_session.sources()
+        } else {
+            flags.sources.clone()
+        };
 
         let text_histories = TextHistories::new(&config);
 
+        media::set_scan_concurrency(config.playback.scan_concurrency);
+
+        #[cfg(feature = "video")]
+        if let Err(e) = player::set_video_pipeline(config.playback.video_pipeline.as_deref()) {
+            errors.push(e);
+        }
+
+        if std::env::var("RUST_LOG").is_err() {
+            set_log_level(config.view.log_level);
+        }
+
         log::debug!("Config on startup: {config:?}");
 
         let mut commands = vec![
@@ -141,12 +320,8 @@ impl App {
             iced::window::oldest().and_then(|id| iced::window::resize(id, iced::Size::new(930.0, 600.0))),
         ];
 
-        if config.release.check && cache.should_check_app_update() {
-            commands.push(Task::future(async move {
-                let result = crate::metadata::Release::fetch().await;
-
-                Message::AppReleaseChecked(result.map_err(|x| x.to_string()))
-            }))
+        if config.release.should_check() && cache.should_check_app_update() {
+            commands.push(Self::check_app_release());
         }
 
         let mut playlist_dirty = false;
@@ -158,36 +333,78 @@ impl App {
             media::Source::Glob { .. } => None,
         });
 
-        let grids = match playlist_path.as_ref() {
-            Some(path) => match Playlist::load_from(path) {
-                Ok(playlist) => {
-                    commands.push(Self::find_media(
-                        playlist.sources(),
-                        media::RefreshContext::Launch,
-                        playlist_path.clone(),
-                    ));
-                    Self::load_playlist(playlist)
-                }
-                Err(e) => {
-                    playlist_path = None;
-                    errors.push(e);
-                    let (grids, _grid_id) = pane_grid::State::new(Grid::new(&grid::Settings::default()));
-                    grids
+        let mut selection = Selection::default();
+        let mut layout_locked = false;
+        let mut soundtrack = None;
+        let mut playlist_settings = None;
+        let playlist = match (flags.playlist.clone(), playlist_path.as_ref()) {
+            (Some(playlist), _) => {
+                // It isn't saved anywhere yet.
+                playlist_dirty = true;
+                Some(Ok(playlist))
+            }
+            (None, Some(path)) => Some(Playlist::load_from(path)),
+            (None, None) => None,
+        };
+
+        let grids = match playlist {
+            Some(Ok(playlist)) => {
+                if let Some(path) = playlist_path.as_ref() {
+                    cache.add_recent_playlist(path.clone());
+                    cache.save();
                 }
-            },
+                commands.push(Self::find_media(
+                    playlist.sources(),
+                    media::RefreshContext::Launch,
+                    playlist_path.clone(),
+                    config.playback.follow_symlinks,
+                    config.playback.ignore_marker(),
+                    config.playback.max_file_size_mb,
+                ));
+                commands.push(Self::resize_window_for_playlist(playlist.window));
+                layout_locked = playlist.layout_locked;
+                playlist_settings = playlist.settings;
+                commands.push(Self::apply_playlist_settings(playlist_settings));
+                let playback = Self::session_playback(&config.playback, playlist_settings.as_ref());
+                soundtrack = playlist
+                    .soundtrack
+                    .clone()
+                    .map(|soundtrack| Soundtrack::new(soundtrack, playlist_path.as_ref(), &playback));
+                let (grids, active) = Self::load_playlist(playlist);
+                selection = active;
+                grids
+            }
+            Some(Err(e)) => {
+                playlist_path = None;
+                errors.push(e);
+                let (grids, _grid_id) = pane_grid::State::new(Grid::new(&grid::Settings::default()));
+                grids
+            }
             None => {
+                // When the app is opened with specific files (e.g., via a file association),
+                // we can play them right away with one player per file.
+                let only_files = !sources.is_empty() && sources.iter().all(|source| source.is_file());
+
                 let grid_settings = grid::Settings::default().with_sources(sources.clone());
-                let (grids, grid_id) = pane_grid::State::new(Grid::new(&grid_settings));
+                let grid = if only_files {
+                    Grid::new_with_players(&grid_settings, sources.len())
+                } else {
+                    Grid::new(&grid_settings)
+                };
+                let (grids, grid_id) = pane_grid::State::new(grid);
 
                 if sources.is_empty() {
                     modals.push(Modal::new_grid_settings(grid_id, grid_settings));
-                } else {
+                } else if !only_files {
                     playlist_dirty = true;
                 }
                 commands.push(Self::find_media(
                     sources,
                     media::RefreshContext::Launch,
                     playlist_path.clone(),
+                    config.playback.follow_symlinks,
+                    config.playback.ignore_marker(),
+                    config.playback.max_file_size_mb,
                 ));
                 grids
             }
@@ -208,22 +425,321 @@ impl App {
                 grids,
                 media: Default::default(),
                 last_tick: Instant::now(),
+                last_tick_idle: false,
                 dragging_pane: false,
                 dragged_files: Default::default(),
+                dropped_files: vec![],
+                shuffle_after_scan: false,
+                cursor_position: None,
                 viewing_menu: false,
                 viewing_pane_controls: None,
                 playlist_path,
                 playlist_dirty,
-                selection: Default::default(),
+                layout_locked,
+                rotation: None,
+                soundtrack,
+                playlist_settings,
+                session_paused: playlist_settings.and_then(|settings| settings.paused),
+                session_muted: playlist_settings.and_then(|settings| settings.muted),
+                solo_audio: None,
+                selection,
                 #[cfg(feature = "audio")]
                 default_audio_output_device: Self::get_audio_device(),
                 #[cfg(not(feature = "audio"))]
                 default_audio_output_device: None,
+                app_background: Self::load_app_background(&config),
+                kiosk: flags.kiosk || config.view.kiosk,
+                window_focused: true,
+                window_size: None,
+                last_mouse_activity: Instant::now(),
+                grids_last_shown: Default::default(),
+                unloaded_media: Default::default(),
+                chrome_hidden: false,
+                muted_for_minimize: false,
+                paused_for_hide: false,
+                #[cfg(feature = "battery")]
+                battery_low: false,
+                #[cfg(feature = "keep-awake")]
+                keep_awake: None,
+                #[cfg(feature = "keep-awake")]
+                keep_awake_wanted: false,
+                pending_hover: None,
+                #[cfg(feature = "media-controls")]
+                media_controls: None,
+                #[cfg(feature = "media-controls")]
+                now_playing: None,
             },
             Task::batch(commands),
         )
     }
 
+    #[cfg(feature = "update-check")]
+    fn check_app_release() -> Task<Message> {
+        Task::future(async move {
+            let result = crate::metadata::Release::fetch().await;
+
+            Message::AppReleaseChecked(result.map_err(|x| x.to_string()))
+        })
+    }
+
+    #[cfg(not(feature = "update-check"))]
+    fn check_app_release() -> Task<Message> {
+        Task::none()
+    }
+
+    fn load_app_background(config: &Config) -> Option<iced::widget::image::Handle> {
+        match &config.view.app_background {
+            config::AppBackground::Theme => None,
+            config::AppBackground::Color(_) => None,
+            config::AppBackground::Image(path) => match path.as_std_path_buf() {
+                Ok(path) => Some(iced::widget::image::Handle::from_path(path)),
+                Err(e) => {
+                    log::error!("Unable to load background image: {path:?} | {e:?}");
+                    None
+                }
+            },
+        }
+    }
+
+    #[cfg(feature = "keep-awake")]
+    fn update_keep_awake(&mut self) {
+        let wanted = self.config.playback.keep_awake && self.window_focused && self.all_paused() == Some(false);
+        if wanted == self.keep_awake_wanted {
+            return;
+        }
+        self.keep_awake_wanted = wanted;
+
+        if !wanted {
+            self.keep_awake = None;
+            return;
+        }
+
+        match keepawake::Builder::default()
+            .display(true)
+            .idle(true)
+            .reason("Playing media")
+            .app_name(lang::app_name())
+            .app_reverse_domain(crate::prelude::LINUX_APP_ID)
+            .create()
+        {
+            Ok(keep_awake) => {
+                self.keep_awake = Some(keep_awake);
+            }
+            Err(e) => {
+                log::warn!("Unable to keep screen awake: {e:?}");
+            }
+        }
+    }
+
+    /// Unload media from grids hidden behind a maximized grid,
+    /// starting with the least recently shown, until we're within the memory budget.
+    fn enforce_memory_budget(&mut self, now: Instant) {
+        let maximized = self.grids.maximized();
+        let hidden = |grid_id: grid::Id| maximized.is_some_and(|maximized| maximized != grid_id);
+
+        for (grid_id, _grid) in self.grids.iter() {
+            if !hidden(*grid_id) {
+                self.grids_last_shown.insert(*grid_id, now);
+            }
+        }
+        self.grids_last_shown
+            .retain(|grid_id, _| self.grids.get(*grid_id).is_some());
+
+        let Some(budget) = self.config.playback.memory_budget() else {
+            return;
+        };
+
+        let mut used: usize = self.grids.iter().map(|(_grid_id, grid)| grid.estimated_memory()).sum();
+        if used <= budget {
+            return;
+        }
+
+        let mut candidates: Vec<_> = self
+            .grids
+            .iter()
+            .map(|(grid_id, _grid)| *grid_id)
+            .filter(|grid_id| hidden(*grid_id))
+            .collect();
+        candidates.sort_by_key(|grid_id| self.grids_last_shown.get(grid_id).copied());
+
+        for grid_id in candidates {
+            let Some(grid) = self.grids.get_mut(grid_id) else {
+                continue;
+            };
+
+            for player_id in grid.player_ids() {
+                if used <= budget {
+                    return;
+                }
+
+                if let Some((media, freed)) = grid.unload_player(player_id) {
+                    log::info!("Unloading media to stay within memory budget: {media:?}");
+                    used = used.saturating_sub(freed);
+                    self.unloaded_media.entry(grid_id).or_default().push((player_id, media));
+                }
+            }
+        }
+    }
+
+    /// Load media again that was unloaded while its grid was hidden.
+    fn reload_unloaded_media(&mut self) {
+        for (grid_id, unloaded) in std::mem::take(&mut self.unloaded_media) {
+            let Some(grid) = self.grids.get_mut(grid_id) else {
+                continue;
+            };
+
+            for (player_id, media) in unloaded {
+                if grid.player(player_id).is_some_and(|player| player.media().is_none()) {
+                    grid.swap_player_media(player_id, &media, &mut self.media, &self.config.playback);
+                }
+            }
+        }
+        self.sync_on_screen_media();
+    }
+
+    fn update_chrome_visibility(&mut self, now: Instant) {
+        let Some(autohide_after) = self.config.view.autohide_after() else {
+            self.chrome_hidden = false;
+            return;
+        };
+
+        // Don't pull anything out from under the user while they're interacting with it.
+        if !self.modals.is_empty() || self.viewing_menu || self.viewing_pane_controls.is_some() {
+            self.last_mouse_activity = now;
+            self.chrome_hidden = false;
+            return;
+        }
+
+        self.chrome_hidden = now.saturating_duration_since(self.last_mouse_activity) >= autohide_after;
+    }
+
+    /// Select a grid by its position in the layout, counting from the top-left.
+    /// If another grid is maximized, then this grid takes its place.
+    fn select_grid_at(&mut self, index: usize) {
+        let Some(grid_id) = self.grids.layout().panes().get(index).copied() else {
+            return;
+        };
+        let Some(grid) = self.grids.get(grid_id) else {
+            return;
+        };
+
+        let player_ids = grid.player_ids();
+        let player_id = (player_ids.len() == 1).then(|| player_ids[0]);
+        let previous = self.selection.pair().map(|(grid_id, _player_id)| grid_id);
+
+        if self.grids.maximized().is_some_and(|maximized| maximized != grid_id) {
+            self.viewing_pane_controls = None;
+            self.grids.maximize(grid_id);
+            self.reload_unloaded_media();
+        }
+
+        self.selection = Selection::new(grid_id, player_id);
+        if previous != Some(grid_id) {
+            self.shuffle_grid_on_focus(grid_id);
+        }
+    }
+
+    fn shuffle_grid_on_focus(&mut self, grid_id: grid::Id) {
+        let Some(grid) = self.grids.get_mut(grid_id) else {
+            return;
+        };
+
+        if grid.shuffles_on_focus() {
+            grid.update_all_players(player::Event::Refresh, &mut self.media, &self.config.playback);
+            self.sync_mirrored_grids();
+            self.sync_on_screen_media();
+        }
+    }
+
+    /// Show a player's controls once the cursor has stayed over it long enough.
+    fn update_pending_hover(&mut self, now: Instant) {
+        let Some((grid_id, player_id, since)) = self.pending_hover else {
+            return;
+        };
+
+        if now.saturating_duration_since(since) < self.config.view.overlay_hover_delay() {
+            return;
+        }
+
+        self.pending_hover = None;
+        if let Some(grid) = self.grids.get_mut(grid_id) {
+            grid.update(
+                grid::Event::Player {
+                    player_id,
+                    event: player::Event::MouseEnter,
+                },
+                &mut self.media,
+                &self.config.playback,
+            );
+        }
+    }
+
+    #[cfg(feature = "media-controls")]
+    fn now_playing(&self) -> Option<crate::gui::media_controls::NowPlaying> {
+        if let Some((grid_id, Some(player_id))) = self.selection.pair() {
+            let selected = self
+                .grids
+                .get(grid_id)
+                .and_then(|grid| grid.player(player_id))
+                .and_then(Player::now_playing);
+            if selected.is_some() {
+                return selected;
+            }
+        }
+
+        let candidates: Vec<_> = self
+            .grids
+            .iter()
+            .flat_map(|(_grid_id, grid)| grid.player_ids().into_iter().filter_map(|id| grid.player(id)))
+            .filter_map(Player::now_playing)
+            .collect();
+
+        candidates
+            .iter()
+            .find(|now_playing| now_playing.paused == Some(false))
+            .or(candidates.first())
+            .cloned()
+    }
+
+    /// The Windows integration needs to know which window it belongs to.
+    #[cfg(all(feature = "media-controls", target_os = "windows"))]
+    fn attach_media_controls(sender: std::sync::mpsc::Sender<crate::gui::media_controls::Command>) -> Task<Message> {
+        iced::window::oldest()
+            .and_then(|id| iced::window::run(id, crate::gui::media_controls::window_handle))
+            .map(move |hwnd| {
+                let _ = sender.send(crate::gui::media_controls::Command::Attach { hwnd });
+                Message::Ignore
+            })
+    }
+
+    #[cfg(all(feature = "media-controls", not(target_os = "windows")))]
+    fn attach_media_controls(sender: std::sync::mpsc::Sender<crate::gui::media_controls::Command>) -> Task<Message> {
+        let _ = sender.send(crate::gui::media_controls::Command::Attach { hwnd: None });
+        Task::none()
+    }
+
+    #[cfg(feature = "media-controls")]
+    fn update_media_controls(&mut self) {
+        let Some(sender) = &self.media_controls else {
+            return;
+        };
+
+        let now_playing = self.now_playing().unwrap_or_default();
+        if self.now_playing.as_ref() == Some(&now_playing) {
+            return;
+        }
+
+        if sender
+            .send(crate::gui::media_controls::Command::Update(now_playing.clone()))
+            .is_err()
+        {
+            log::warn!("Media controls are no longer available");
+            self.media_controls = None;
+            return;
+        }
+        self.now_playing = Some(now_playing);
+    }
+
     pub fn title(&self) -> String {
         let base = lang::window_title();
 
@@ -235,13 +751,174 @@ impl App {
 
     pub fn theme(&self) -> crate::gui::style::Theme {
         crate::gui::style::Theme::from(self.config.view.theme)
+            .with_selection(&self.config.view.selection)
+            .with_tiles(self.config.view.tile_radius, self.config.view.tile_shadow)
     }
 
     fn refresh(&mut self, context: media::RefreshContext) {
+        // New grids may need to be muted for the soundtrack before they pick any media.
+        self.sync_soundtrack_mute();
         self.media.prune(&self.all_sources());
-        for (_id, grid) in self.grids.iter_mut() {
-            grid.refresh(&mut self.media, &self.config.playback, context);
+        let playback = self.playback();
+        let grid_ids: Vec<_> = self.grids.iter().map(|(grid_id, _)| *grid_id).collect();
+        for grid_id in grid_ids {
+            // Earlier grids may have just picked new media.
+            self.sync_on_screen_media();
+            if let Some(grid) = self.grids.get_mut(grid_id) {
+                grid.refresh(&mut self.media, &playback, context);
+            }
         }
+        self.sync_mirrored_grids();
+        self.sync_on_screen_media();
+        self.update_media_totals();
+    }
+
+    /// Saved content hashes for media that's about to be compared.
+    fn known_content_hashes(
+        &self,
+        media: &media::Media,
+        candidates: &[media::Media],
+    ) -> Vec<(StrictPath, ContentHash)> {
+        std::iter::once(media)
+            .chain(candidates)
+            .filter_map(|media| {
+                self.cache
+                    .content_hashes
+                    .get(media.path())
+                    .map(|hash| (media.path().clone(), hash.clone()))
+            })
+            .collect()
+    }
+
+    fn remember_content_hashes(&mut self, hashes: Vec<(StrictPath, ContentHash)>) {
+        if hashes.is_empty() {
+            return;
+        }
+        self.cache.content_hashes.extend(hashes);
+        self.save_cache();
+    }
+
+    /// Keep the grids muted while there's a soundtrack.
+    fn sync_soundtrack_mute(&mut self) {
+        let forced = self.soundtrack.is_some();
+        let playback = self.playback();
+        for (_, grid) in self.grids.iter_mut() {
+            grid.set_force_muted(forced, &mut self.media, &playback);
+        }
+    }
+
+    fn update_media_totals(&mut self) {
+        for (_, grid) in self.grids.iter_mut() {
+            grid.update_media_total(&self.media);
+        }
+    }
+
+    fn apply_grid_settings(&mut self, grid_id: grid::Id, settings: grid::Settings) {
+        let Some(grid) = self.grids.get_mut(grid_id) else {
+            return;
+        };
+
+        let filter_changed = grid.settings().filter != settings.filter;
+        let change = grid.set_settings(settings);
+        if filter_changed {
+            grid.reload_players(&mut self.media, &self.config.playback);
+        }
+        grid.update_media_total(&self.media);
+
+        match change {
+            Change::Same => {}
+            Change::Different => {
+                self.mark_playlist_dirty();
+            }
+        }
+    }
+
+    /// Follow a chain of mirrored grids to the one that actually chooses the media.
+    /// This is `None` if the chain is broken or loops back on itself.
+    fn mirror_root(&self, grid_id: grid::Id) -> Option<grid::Id> {
+        let mut current = grid_id;
+        let mut visited = HashSet::new();
+
+        while let Some(next) = self.grids.get(current)?.mirror_of() {
+            if !visited.insert(current) {
+                return None;
+            }
+            current = next;
+        }
+
+        Some(current)
+    }
+
+    /// Make each mirror show the same media as the grid that it mirrors.
+    fn sync_mirrored_grids(&mut self) {
+        let mirrors: Vec<_> = self
+            .grids
+            .iter()
+            .filter(|(_, grid)| grid.mirror_of().is_some())
+            .map(|(grid_id, _)| *grid_id)
+            .collect();
+
+        for grid_id in mirrors {
+            let Some(media_list) = self
+                .mirror_root(grid_id)
+                .and_then(|root| self.grids.get(root))
+                .map(|root| root.media_list())
+            else {
+                continue;
+            };
+
+            if let Some(grid) = self.grids.get_mut(grid_id) {
+                grid.mirror(&media_list, &mut self.media, &self.config.playback);
+            }
+        }
+    }
+
+    /// Other grids that a grid could mirror without creating a loop.
+    fn mirror_options(&self, grid_id: grid::Id) -> Vec<grid::MirrorOption> {
+        self.grids
+            .layout()
+            .panes()
+            .into_iter()
+            .enumerate()
+            .filter(|(_, other)| *other != grid_id)
+            .filter(|(_, other)| {
+                let mut current = *other;
+                let mut visited = HashSet::new();
+                while let Some(next) = self.grids.get(current).and_then(|grid| grid.mirror_of()) {
+                    if next == grid_id || !visited.insert(current) {
+                        return false;
+                    }
+                    current = next;
+                }
+                true
+            })
+            .map(|(index, other)| grid::MirrorOption {
+                grid_id: other,
+                label: self
+                    .grids
+                    .get(other)
+                    .and_then(|grid| grid.name())
+                    .map(|name| name.to_string())
+                    .unwrap_or_else(|| lang::thing::numbered_grid(index + 1)),
+            })
+            .collect()
+    }
+
+    /// Let the media collection know what's shown in all grids,
+    /// for any grids that avoid duplicates across the screen.
+    /// This should be called after any players change their media.
+    fn sync_on_screen_media(&mut self) {
+        if self.grids.iter().all(|(_, grid)| grid.allows_duplicates_on_screen()) {
+            return;
+        }
+
+        let media = self
+            .grids
+            .iter()
+            .flat_map(|(_, grid)| grid.active_media())
+            .cloned()
+            .collect();
+        self.media.set_on_screen(media);
     }
 
     fn all_idle(&self) -> bool {
@@ -265,6 +942,23 @@ impl App {
         relevant.then_some(true)
     }
 
+    fn category_paused(&self, category: player::Category) -> Option<bool> {
+        let mut relevant = false;
+        for (_grid_id, grid) in self.grids.iter() {
+            match grid.category_paused(category) {
+                Some(true) => {
+                    relevant = true;
+                }
+                Some(false) => {
+                    return Some(false);
+                }
+                None => {}
+            }
+        }
+
+        relevant.then_some(true)
+    }
+
     fn all_muted(&self) -> Option<bool> {
         let mut relevant = false;
         for (_grid_id, grid) in self.grids.iter() {
@@ -283,12 +977,17 @@ impl App {
     }
 
     fn set_paused(&mut self, paused: bool) {
+        self.session_paused = None;
         self.config.playback.paused = paused;
         self.save_config();
+        self.save_status();
 
         for (_grid_id, grid) in self.grids.iter_mut() {
             grid.update_all_players(player::Event::SetPause(paused), &mut self.media, &self.config.playback);
         }
+        if let Some(soundtrack) = self.soundtrack.as_mut() {
+            soundtrack.update(player::Event::SetPause(paused), &self.config.playback);
+        }
     }
 
     fn generate_event_in_selection(
@@ -333,13 +1032,110 @@ impl App {
         }
     }
 
+    /// Find split ratios that give each grid an equal share of the space
+    /// along each row and column.
+    fn even_layout_ratios(node: &pane_grid::Node, ratios: &mut Vec<(pane_grid::Split, f32)>) {
+        /// How many grids are lined up along the axis within this node.
+        fn weight(node: &pane_grid::Node, along: pane_grid::Axis) -> f32 {
+            match node {
+                pane_grid::Node::Pane(_) => 1.0,
+                pane_grid::Node::Split { axis, a, b, .. } => {
+                    if *axis == along {
+                        weight(a, along) + weight(b, along)
+                    } else {
+                        weight(a, along).max(weight(b, along))
+                    }
+                }
+            }
+        }
+
+        if let pane_grid::Node::Split { id, axis, a, b, .. } = node {
+            let first = weight(a, *axis);
+            let second = weight(b, *axis);
+            ratios.push((*id, first / (first + second)));
+
+            Self::even_layout_ratios(a, ratios);
+            Self::even_layout_ratios(b, ratios);
+        }
+    }
+
+    /// Split the selected grid, or the only grid if there's just one.
+    fn split_selected_grid(&mut self, axis: pane_grid::Axis) -> Task<Message> {
+        let grid_id = match self.selection.pair() {
+            Some((grid_id, _)) => grid_id,
+            None if self.grids.len() == 1 => match self.grids.iter().next() {
+                Some((grid_id, _)) => *grid_id,
+                None => return Task::none(),
+            },
+            None => return Task::none(),
+        };
+
+        self.update(Message::Pane {
+            event: PaneEvent::Split { grid_id, axis },
+        })
+    }
+
     fn set_muted(&mut self, muted: bool) {
+        self.session_muted = None;
         self.config.playback.muted = muted;
         self.save_config();
+        self.save_status();
 
         for (_grid_id, grid) in self.grids.iter_mut() {
             grid.update_all_players(player::Event::SetMute(muted), &mut self.media, &self.config.playback);
         }
+        if let Some(soundtrack) = self.soundtrack.as_mut() {
+            soundtrack.update(player::Event::SetMute(muted), &self.config.playback);
+        }
+    }
+
+    /// Mute or unmute every player for now, without changing the saved setting.
+    fn apply_muted(&mut self, muted: bool) {
+        let playback = self.config.playback.with_muted(muted);
+
+        for (_grid_id, grid) in self.grids.iter_mut() {
+            grid.update_all_players(player::Event::SetMute(muted), &mut self.media, &playback);
+        }
+        if let Some(soundtrack) = self.soundtrack.as_mut() {
+            soundtrack.update(player::Event::SetMute(muted), &playback);
+        }
+    }
+
+    /// Pause or resume every player for now, without changing the saved setting.
+    fn apply_paused(&mut self, paused: bool) {
+        let playback = self.config.playback.with_paused(paused);
+
+        for (_grid_id, grid) in self.grids.iter_mut() {
+            grid.update_all_players(player::Event::SetPause(paused), &mut self.media, &playback);
+        }
+        if let Some(soundtrack) = self.soundtrack.as_mut() {
+            soundtrack.update(player::Event::SetPause(paused), &playback);
+        }
+    }
+
+    /// There's no window event for minimizing, so we have to ask.
+    fn check_minimized(&self) -> Task<Message> {
+        let pause_when_hidden = self.config.playback.pause_when_hidden;
+        if !self.config.playback.mute_on_minimize
+            && !self.muted_for_minimize
+            && !pause_when_hidden
+            && !self.paused_for_hide
+        {
+            return Task::none();
+        }
+
+        let focused = self.window_focused;
+        iced::window::oldest()
+            .and_then(iced::window::is_minimized)
+            .map(move |minimized| match minimized {
+                Some(true) => Message::WindowMinimized,
+                Some(false) => Message::WindowRestored,
+                // Not every platform can tell us, so fall back to the focus state.
+                // Losing focus doesn't mean that the window is minimized, so this doesn't mute.
+                None if pause_when_hidden && !focused => Message::WindowHidden,
+                None if pause_when_hidden => Message::WindowRestored,
+                None => Message::Ignore,
+            })
     }
 
     fn set_volume(&mut self, volume: f32) {
@@ -349,6 +1145,14 @@ impl App {
         for (_grid_id, grid) in self.grids.iter_mut() {
             grid.update_all_players(player::Event::SetVolume(volume), &mut self.media, &self.config.playback);
         }
+        if let Some(soundtrack) = self.soundtrack.as_mut() {
+            soundtrack.update(player::Event::SetVolume(volume), &self.config.playback);
+        }
+    }
+
+    fn set_show_info(&mut self, show: bool) {
+        self.config.view.show_info = show;
+        self.save_config();
     }
 
     fn set_synchronized(&mut self, synchronized: bool) {
@@ -360,59 +1164,261 @@ impl App {
         self.grids.iter().any(|(_grid_id, grid)| grid.can_jump())
     }
 
+    fn can_seek(&self) -> bool {
+        self.grids.iter().any(|(_grid_id, grid)| grid.can_seek())
+    }
+
+    /// How often to tick the players.
+    /// When nothing is progressing (e.g., everything is paused),
+    /// we can tick much less often to save CPU.
+    fn tick_interval(&self) -> Duration {
+        if self.pending_hover.is_some()
+            || self.grids.iter().any(|(_grid_id, grid)| grid.is_progressing())
+            || self
+                .soundtrack
+                .as_ref()
+                .is_some_and(|soundtrack| soundtrack.is_progressing())
+        {
+            self.config.playback.update_rate.interval()
+        } else {
+            IDLE_TICK_INTERVAL
+        }
+    }
+
     fn all_sources(&self) -> Vec<media::Source> {
         self.grids
             .iter()
-            .flat_map(|(_grid_id, grid)| grid.sources())
+            .flat_map(|(_grid_id, grid)| grid.all_sources())
             .unique()
-            .cloned()
             .collect()
     }
 
+    /// The player that the cursor is currently over,
+    /// including one that it only just entered.
+    fn hovered_player(&self) -> Option<(grid::Id, player::Id)> {
+        self.grids
+            .iter()
+            .find_map(|(grid_id, grid)| grid.hovered_player().map(|player_id| (*grid_id, player_id)))
+            .or_else(|| {
+                self.pending_hover
+                    .map(|(grid_id, player_id, _since)| (grid_id, player_id))
+            })
+    }
+
+    /// Look up where each player is on screen so that we can tell which one received a drop.
+    fn find_drop_targets(&self) -> Task<Message> {
+        let queries: Vec<_> = self
+            .grids
+            .iter()
+            .flat_map(|(grid_id, grid)| {
+                let grid_id = *grid_id;
+                grid.player_ids().into_iter().map(move |player_id| {
+                    container::visible_bounds(player::container_id(grid_id, player_id))
+                        .map(move |bounds| (grid_id, player_id, bounds))
+                })
+            })
+            .collect();
+
+        if queries.is_empty() {
+            return Task::done(Message::FileDropTargets(vec![]));
+        }
+
+        Task::batch(queries).collect().map(Message::FileDropTargets)
+    }
+
+    /// When files/folders are dropped onto a specific player,
+    /// play the files in that player and the ones after it,
+    /// and add any folders to that grid's sources.
+    /// Returns any files that didn't fit or couldn't be identified.
+    fn drop_paths_on_player(
+        &mut self,
+        grid_id: grid::Id,
+        player_id: player::Id,
+        paths: Vec<StrictPath>,
+    ) -> (Task<Message>, Vec<StrictPath>) {
+        let Some(grid) = self.grids.get_mut(grid_id) else {
+            return (Task::none(), paths);
+        };
+
+        let player_ids = grid.player_ids();
+        let start = player_ids.iter().position(|id| *id == player_id).unwrap_or(0);
+        let mut slots = player_ids[start..].iter().chain(&player_ids[..start]).copied();
+
+        let mut folders = vec![];
+        let mut leftover = vec![];
+        for path in paths {
+            if path.is_dir() {
+                folders.push(path);
+                continue;
+            }
+
+            match (media::Media::identify(&path), slots.next()) {
+                (Some(media), Some(player_id)) => {
+                    grid.swap_player_media(player_id, &media, &mut self.media, &self.config.playback);
+                }
+                _ => {
+                    leftover.push(path);
+                }
+            }
+        }
+
+        if folders.is_empty() {
+            self.sync_on_screen_media();
+            return (Task::none(), leftover);
+        }
+
+        let context = media::RefreshContext::Edit;
+        let settings = grid
+            .settings()
+            .with_sources(folders.into_iter().map(media::Source::new_path).collect());
+        let sources = settings.sources.clone();
+
+        match grid.set_settings(settings) {
+            Change::Same => {}
+            Change::Different => {
+                self.mark_playlist_dirty();
+            }
+        }
+
+        self.refresh(context);
+        let task = Self::find_media(
+            sources,
+            context,
+            self.playlist_path.clone(),
+            self.config.playback.follow_symlinks,
+            self.config.playback.ignore_marker(),
+            self.config.playback.max_file_size_mb,
+        );
+        (task, leftover)
+    }
+
     fn find_media(
         sources: Vec<media::Source>,
         context: media::RefreshContext,
         playlist: Option<StrictPath>,
+        follow_symlinks: bool,
+        ignore_marker: Option<String>,
+        max_file_size: media::SizeLimits,
     ) -> Task<Message> {
         log::info!("Finding media ({context:?})");
         let mut tasks = vec![];
 
         for source in sources {
-            let playlist = playlist.clone();
-            tasks.push(Task::future(async move {
-                match tokio::task::spawn_blocking(move || {
-                    media::Collection::find(media::Scan::Source {
-                        source,
-                        original_source: None,
-                        playlist,
-                        context,
-                    })
-                })
-                .await
-                {
-                    Ok(scans) => Message::MediaScanned(scans),
-                    Err(error) => {
-                        log::error!("Failed to join task for media scan: {error:?}");
-                        Message::Ignore
-                    }
-                }
+            tasks.push(Self::find_media_one(media::Scan::Source {
+                source,
+                original_source: None,
+                playlist: playlist.clone(),
+                context,
+                follow_symlinks,
+                ignore_marker: ignore_marker.clone(),
+                max_file_size,
             }));
         }
 
         Task::batch(tasks)
     }
 
+    /// Results are reported in batches as they're found,
+    /// so that large sources can start playing before the scan is done.
+    /// Scans wait for a permit first, so that only a limited number run at once.
     fn find_media_one(scan: media::Scan) -> Task<Message> {
-        Task::future(async move {
-            match tokio::task::spawn_blocking(move || media::Collection::find(scan)).await {
-                Ok(scans) => Message::MediaScanned(scans),
-                Err(_) => Message::Ignore,
-            }
-        })
+        use iced::futures::{SinkExt, StreamExt};
+
+        let permits = media::scan_permits();
+        media::start_scan();
+
+        Task::run(
+            iced::stream::channel(10, move |mut output| async move {
+                let (sender, mut receiver) = iced::futures::channel::mpsc::unbounded();
+                let permit = permits.acquire_owned().await;
+
+                let handle = tokio::task::spawn_blocking(move || {
+                    // Release the permit once the scan is done.
+                    let _permit = permit;
+                    media::Collection::find(scan, |scans| {
+                        let _ = sender.unbounded_send(scans);
+                    })
+                });
+
+                while let Some(scans) = receiver.next().await {
+                    let _ = output.send(scans).await;
+                }
+
+                if let Err(error) = handle.await {
+                    log::error!("Failed to join task for media scan: {error:?}");
+                }
+            }),
+            Message::MediaScanned,
+        )
+        .chain(Task::done(Message::MediaScanFinished))
+    }
+
+    fn build_playlist(&self) -> Playlist {
+        let active = self.selection.pair().and_then(|(grid_id, player_id)| {
+            let grid = self.grids.layout().panes().into_iter().position(|x| x == grid_id)?;
+            Some(playlist::Active {
+                grid,
+                player: player_id.map(|x| x.0),
+            })
+        });
+
+        let window = self
+            .window_size
+            .filter(|_| self.config.view.save_window_size_in_playlist)
+            .map(|size| playlist::WindowSize {
+                width: size.width.round() as u32,
+                height: size.height.round() as u32,
+            });
+
+        Playlist::new(
+            Self::build_playlist_layout(&self.grids, self.grids.layout()),
+            active,
+            window,
+            self.layout_locked,
+            self.soundtrack.as_ref().map(|soundtrack| soundtrack.path().clone()),
+            self.playlist_settings,
+        )
+    }
+
+    /// The playlist's pause/mute settings are handled by `session_playback`,
+    /// so this only covers the window.
+    fn apply_playlist_settings(settings: Option<playlist::Settings>) -> Task<Message> {
+        if settings.is_some_and(|settings| settings.fullscreen) {
+            iced::window::oldest().and_then(|id| iced::window::set_mode(id, iced::window::Mode::Fullscreen))
+        } else {
+            Task::none()
+        }
+    }
+
+    /// Playback settings with the playlist's pause/mute state applied,
+    /// for when there's no `App` yet.
+    fn session_playback(playback: &Playback, settings: Option<&playlist::Settings>) -> Playback {
+        playback
+            .with_paused_maybe(settings.and_then(|settings| settings.paused))
+            .with_muted_maybe(settings.and_then(|settings| settings.muted))
+    }
+
+    /// Playback settings for this session,
+    /// which may be paused/muted by the playlist without changing the config.
+    fn playback(&self) -> Playback {
+        self.config
+            .playback
+            .with_paused_maybe(self.session_paused)
+            .with_muted_maybe(self.session_muted)
     }
 
-    fn build_playlist(&self) -> Playlist {
-        Playlist::new(Self::build_playlist_layout(&self.grids, self.grids.layout()))
+    fn resize_window_for_playlist(window: Option<playlist::WindowSize>) -> Task<Message> {
+        let Some(window) = window else {
+            return Task::none();
+        };
+
+        if !window.is_reasonable() {
+            log::warn!("Ignoring unreasonable window size from playlist: {window:?}");
+            return Task::none();
+        }
+
+        let size = iced::Size::new(window.width as f32, window.height as f32);
+        iced::window::oldest().and_then(move |id| iced::window::resize(id, size))
     }
 
     fn build_playlist_layout(panes: &pane_grid::State<Grid>, node: &pane_grid::Node) -> playlist::Layout {
@@ -435,17 +1441,46 @@ impl App {
             pane_grid::Node::Pane(pane) => match panes.get(*pane) {
                 Some(grid) => {
                     let grid::Settings {
+                        name,
                         sources,
+                        fallback_source,
                         content_fit,
+                        filter_method,
                         orientation,
                         orientation_limit,
+                        aspect_override,
+                        image_duration,
+                        clip_length,
+                        filter,
+                        on_end,
+                        show_counter,
+                        allow_duplicates_on_screen,
+                        shuffle_on_focus,
+                        cycle_in_order,
+                        opacity,
+                        mirror_of,
                     } = grid.settings();
                     playlist::Layout::Group(playlist::Group {
+                        name,
                         sources,
+                        fallback_source,
                         max_media: grid.total_players(),
                         content_fit,
+                        filter_method,
                         orientation,
                         orientation_limit,
+                        aspect_override,
+                        image_duration,
+                        clip_length,
+                        filter,
+                        on_end,
+                        show_counter,
+                        allow_duplicates_on_screen,
+                        shuffle_on_focus,
+                        cycle_in_order,
+                        opacity,
+                        mirror_of: mirror_of
+                            .and_then(|mirror_of| panes.layout().panes().iter().position(|pane| *pane == mirror_of)),
                     })
                 }
                 None => playlist::Layout::Group(playlist::Group::default()),
@@ -453,9 +1488,121 @@ impl App {
         }
     }
 
-    fn load_playlist(playlist: Playlist) -> pane_grid::State<Grid> {
+    fn load_playlist(playlist: Playlist) -> (pane_grid::State<Grid>, Selection) {
+        let mirrors: Vec<_> = playlist.layout.groups().iter().map(|group| group.mirror_of).collect();
         let configuration = Self::load_playlist_layout(playlist.layout);
-        pane_grid::State::with_configuration(configuration)
+        let mut grids = pane_grid::State::with_configuration(configuration);
+
+        let panes = grids.layout().panes();
+        for (grid_id, mirror_of) in panes.iter().zip(mirrors) {
+            if let Some(grid) = grids.get_mut(*grid_id) {
+                grid.set_mirror_of(mirror_of.and_then(|index| panes.get(index).copied()));
+            }
+        }
+
+        let selection = Self::load_playlist_selection(&grids, playlist.active);
+        (grids, selection)
+    }
+
+    /// Split the folders in half recursively, alternating the axis,
+    /// so that the grids end up roughly the same size.
+    fn folder_grids_layout(folders: &[StrictPath], axis: pane_grid::Axis) -> pane_grid::Configuration<Grid> {
+        if let [folder] = folders {
+            let mut settings = grid::Settings::default().with_source(media::Source::new_path(folder.clone()));
+            settings.set_name(folder.leaf().unwrap_or_default());
+            return pane_grid::Configuration::Pane(Grid::new(&settings));
+        }
+
+        let (first, second) = folders.split_at(folders.len().div_ceil(2));
+        let next_axis = match axis {
+            pane_grid::Axis::Horizontal => pane_grid::Axis::Vertical,
+            pane_grid::Axis::Vertical => pane_grid::Axis::Horizontal,
+        };
+
+        pane_grid::Configuration::Split {
+            axis,
+            ratio: first.len() as f32 / folders.len() as f32,
+            a: Box::new(Self::folder_grids_layout(first, next_axis)),
+            b: Box::new(Self::folder_grids_layout(second, next_axis)),
+        }
+    }
+
+    /// Shuffle the order of the existing grids, keeping each of them exactly once.
+    fn random_grid_settings(grids: &pane_grid::State<Grid>) -> Vec<(grid::Id, grid::Settings)> {
+        use rand::seq::SliceRandom;
+
+        let mut available: Vec<_> = grids
+            .iter()
+            .map(|(grid_id, grid)| (*grid_id, grid.settings()))
+            .collect();
+        available.shuffle(&mut rand::rng());
+        available
+    }
+
+    fn randomize_layout(&mut self) {
+        let (old_ids, settings): (Vec<_>, Vec<_>) = Self::random_grid_settings(&self.grids).into_iter().unzip();
+        let mirrors: Vec<_> = settings
+            .iter()
+            .map(|settings| {
+                settings
+                    .mirror_of
+                    .and_then(|mirror_of| old_ids.iter().position(|old_id| *old_id == mirror_of))
+            })
+            .collect();
+
+        self.grids = pane_grid::State::with_configuration(Self::random_layout(settings));
+
+        let panes = self.grids.layout().panes();
+        for (grid_id, mirror_of) in panes.iter().zip(mirrors) {
+            if let Some(grid) = self.grids.get_mut(*grid_id) {
+                grid.set_mirror_of(mirror_of.and_then(|index| panes.get(index).copied()));
+            }
+        }
+    }
+
+    fn random_layout(mut settings: Vec<grid::Settings>) -> pane_grid::Configuration<Grid> {
+        use rand::Rng;
+
+        if settings.len() <= 1 {
+            return pane_grid::Configuration::Pane(Grid::new(&settings.pop().unwrap_or_default()));
+        }
+
+        let mut rng = rand::rng();
+        let total = settings.len();
+        let split = rng.random_range(1..total);
+        let second = settings.split_off(split);
+        let axis = if rng.random_bool(0.5) {
+            pane_grid::Axis::Horizontal
+        } else {
+            pane_grid::Axis::Vertical
+        };
+
+        let ratio = split as f32 / total as f32 + rng.random_range(-0.15..=0.15);
+
+        pane_grid::Configuration::Split {
+            axis,
+            ratio: ratio.clamp(0.2, 0.8),
+            a: Box::new(Self::random_layout(settings)),
+            b: Box::new(Self::random_layout(second)),
+        }
+    }
+
+    fn load_playlist_selection(grids: &pane_grid::State<Grid>, active: Option<playlist::Active>) -> Selection {
+        let Some(active) = active else {
+            return Selection::default();
+        };
+
+        let Some(grid_id) = grids.layout().panes().get(active.grid).copied() else {
+            return Selection::default();
+        };
+
+        let player_id = active.player.map(player::Id);
+        let total_players = grids.get(grid_id).map(|grid| grid.total_players()).unwrap_or_default();
+
+        match player_id {
+            Some(player_id) if player_id.0 >= total_players => Selection::default(),
+            _ => Selection::new(grid_id, player_id),
+        }
     }
 
     fn load_playlist_layout(layout: playlist::Layout) -> pane_grid::Configuration<Grid> {
@@ -475,23 +1622,76 @@ impl App {
                 b: Box::new(Self::load_playlist_layout(*second)),
             },
             playlist::Layout::Group(playlist::Group {
+                name,
                 sources,
+                fallback_source,
                 max_media,
                 content_fit,
+                filter_method,
                 orientation,
                 orientation_limit,
+                aspect_override,
+                image_duration,
+                clip_length,
+                filter,
+                on_end,
+                show_counter,
+                allow_duplicates_on_screen,
+                shuffle_on_focus,
+                cycle_in_order,
+                opacity,
+                mirror_of: _,
             }) => {
                 let settings = grid::Settings {
+                    name,
                     sources,
+                    fallback_source,
                     content_fit,
+                    filter_method,
                     orientation,
                     orientation_limit,
+                    aspect_override,
+                    image_duration,
+                    clip_length,
+                    filter,
+                    on_end,
+                    show_counter,
+                    allow_duplicates_on_screen,
+                    shuffle_on_focus,
+                    cycle_in_order,
+                    opacity,
+                    // This is resolved once all of the grids exist.
+                    mirror_of: None,
                 };
                 pane_grid::Configuration::Pane(Grid::new_with_players(&settings, max_media))
             }
         }
     }
 
+    /// Whether any battery is discharging and below the threshold percentage.
+    /// This can block for a moment, so it's run in the background.
+    #[cfg(feature = "battery")]
+    fn is_battery_low(threshold: u8) -> bool {
+        use starship_battery::units::ratio::percent;
+
+        let check = || -> Result<bool, starship_battery::Error> {
+            let manager = starship_battery::Manager::new()?;
+            let low = manager.batteries()?.flatten().any(|battery| {
+                battery.state() == starship_battery::State::Discharging
+                    && battery.state_of_charge().get::<percent>() < threshold as f32
+            });
+            Ok(low)
+        };
+
+        match check() {
+            Ok(low) => low,
+            Err(e) => {
+                log::warn!("Unable to check battery: {e:?}");
+                false
+            }
+        }
+    }
+
     #[cfg(feature = "audio")]
     fn get_audio_device() -> Option<String> {
         use rodio::cpal::traits::{DeviceTrait, HostTrait};
@@ -522,17 +1722,43 @@ impl App {
         }
     }
 
+    /// Restore the mute state of each grid from before any grid was soloed.
+    fn unsolo_audio(&mut self) {
+        let Some((_grid_id, before)) = self.solo_audio.take() else {
+            return;
+        };
+
+        for (grid_id, muted) in before {
+            if let Some(grid) = self.grids.get_mut(grid_id) {
+                grid.update_all_players(player::Event::SetMute(muted), &mut self.media, &self.config.playback);
+            }
+        }
+    }
+
     fn update_playback(&mut self) {
-        if let Some(paused) = self.all_paused() {
-            self.config.playback.paused = paused;
+        // While hidden, everything is paused regardless of the setting.
+        if let Some(paused) = self.all_paused().filter(|_| !self.paused_for_hide) {
+            if self.session_paused.is_some() {
+                self.session_paused = Some(paused);
+            } else {
+                self.config.playback.paused = paused;
+            }
         }
 
-        if let Some(muted) = self.all_muted() {
-            if self.config.playback.muted != muted {
+        // While minimized or playing a soundtrack, the grids are muted regardless of the setting.
+        if let Some(muted) = self
+            .all_muted()
+            .filter(|_| !self.muted_for_minimize && self.soundtrack.is_none())
+        {
+            if self.session_muted.is_some() {
+                self.session_muted = Some(muted);
+            } else if self.config.playback.muted != muted {
                 self.config.playback.muted = muted;
                 self.save_config();
             }
         }
+
+        self.save_status();
     }
 
     fn synchronize_players(&mut self, originator: grid::Id, category: player::Category, event: player::Event) {
@@ -551,6 +1777,11 @@ impl App {
         let mut out = vec![];
 
         for (grid_id, grid) in self.grids.iter() {
+            // Other grids are hidden while one is maximized.
+            if self.grids.maximized().is_some_and(|maximized| maximized != *grid_id) {
+                continue;
+            }
+
             let player_ids = grid.player_ids();
             if player_ids.len() != 1 {
                 out.push((*grid_id, None));
@@ -593,8 +1824,34 @@ impl App {
             grid::Update::Step { category, step } => {
                 self.synchronize_players(grid_id, category, player::Event::Step(step));
             }
+            grid::Update::PlayerStopped => {
+                self.update_playback();
+            }
+            grid::Update::DeleteToTrash { player_id, media } => {
+                self.show_modal(Modal::ConfirmDeleteToTrash {
+                    grid_id,
+                    player_id,
+                    media,
+                });
+            }
+            grid::Update::Blacklist { player_id, media } => {
+                self.media.blacklist(&media);
+                self.move_on_from_media(grid_id, player_id, &media);
+            }
+            grid::Update::Transfer {
+                player_id,
+                media,
+                transfer,
+            } => {
+                self.show_modal(Modal::Transfer {
+                    grid_id,
+                    player_id,
+                    media,
+                    transfer,
+                });
+            }
             grid::Update::PlayerClosed => {
-                self.playlist_dirty = true;
+                self.mark_playlist_dirty();
                 self.update_playback();
                 self.selection.ensure_valid_in_grid(self.selectables_in_grid());
 
@@ -608,27 +1865,77 @@ impl App {
     }
 
     pub fn update(&mut self, message: Message) -> Task<Message> {
+        if self.kiosk && message.is_editing() {
+            return Task::none();
+        }
+
         match message {
             Message::Ignore => Task::none(),
             Message::Exit { force } => {
+                if self.pending_save.remove(&SaveKind::Playlist).is_some() {
+                    self.autosave_playlist();
+                }
+
                 if self.playlist_dirty && !force && self.config.view.confirm_discard_playlist {
                     self.show_modal(Modal::ConfirmDiscardPlaylist { exit: true });
                     return Task::none();
                 }
 
+                if !force && self.config.view.confirm_exit_while_playing && self.all_paused() == Some(false) {
+                    self.show_modal(Modal::ConfirmExitWhilePlaying);
+                    return Task::none();
+                }
+
                 // If we don't pause first, you may still hear the videos for a moment after the app closes.
                 for (_grid_id, grid) in self.grids.iter_mut() {
                     grid.update_all_players(player::Event::SetPause(true), &mut self.media, &self.config.playback);
                 }
+
+                self.cache.last_session = match self.playlist_path.clone() {
+                    Some(playlist) => LastSession {
+                        playlist: Some(playlist),
+                        sources: vec![],
+                    },
+                    None => LastSession {
+                        playlist: None,
+                        sources: self.all_sources(),
+                    },
+                };
+                self.cache.save();
+                player::remove_converted_subtitles();
+
                 std::process::exit(0)
             }
             Message::Tick(instant) => {
-                let elapsed = instant - self.last_tick;
+                let mut elapsed = instant - self.last_tick;
                 self.last_tick = instant;
 
-                for (_id, grid) in self.grids.iter_mut() {
-                    grid.tick(elapsed, &mut self.media, &self.config.playback);
+                // While idle, something may have been unpaused partway through the slower interval,
+                // so we don't want to credit it with the time before that.
+                if self.last_tick_idle {
+                    elapsed = elapsed.min(self.config.playback.update_rate.interval());
+                }
+
+                let grid_ids: Vec<_> = self.grids.iter().map(|(grid_id, _)| *grid_id).collect();
+                for grid_id in grid_ids {
+                    if let Some(grid) = self.grids.get_mut(grid_id) {
+                        grid.tick(elapsed, &mut self.media, &self.config.playback);
+                    }
+                    // Later grids may need to avoid what this one just picked.
+                    self.sync_on_screen_media();
                 }
+                if let Some(soundtrack) = self.soundtrack.as_mut() {
+                    soundtrack.tick(elapsed, &self.config.playback);
+                }
+                self.sync_mirrored_grids();
+                self.last_tick_idle = self.tick_interval() == IDLE_TICK_INTERVAL;
+                #[cfg(feature = "keep-awake")]
+                self.update_keep_awake();
+                self.update_chrome_visibility(instant);
+                self.update_pending_hover(instant);
+                self.enforce_memory_budget(instant);
+                #[cfg(feature = "media-controls")]
+                self.update_media_controls();
                 Task::none()
             }
             #[cfg(feature = "audio")]
@@ -637,7 +1944,39 @@ impl App {
                     for (_id, grid) in self.grids.iter_mut() {
                         grid.reload_audio(&self.config.playback);
                     }
+                    if let Some(soundtrack) = self.soundtrack.as_mut() {
+                        soundtrack.reload_audio(&self.config.playback);
+                    }
+                }
+                Task::none()
+            }
+            #[cfg(feature = "battery")]
+            Message::CheckBattery => {
+                let Some(threshold) = self.config.playback.pause_on_low_battery else {
+                    return Task::none();
+                };
+
+                Task::future(async move {
+                    match tokio::task::spawn_blocking(move || Self::is_battery_low(threshold)).await {
+                        Ok(low) => Message::BatteryChecked(low),
+                        Err(e) => {
+                            log::error!("Failed to join task for battery check: {e:?}");
+                            Message::Ignore
+                        }
+                    }
+                })
+            }
+            #[cfg(feature = "battery")]
+            Message::BatteryChecked(low) => {
+                let Some(threshold) = self.config.playback.pause_on_low_battery else {
+                    return Task::none();
+                };
+
+                if low && !self.battery_low && !self.config.playback.paused {
+                    log::info!("Pausing because the battery is below {threshold}%");
+                    self.set_paused(true);
                 }
+                self.battery_low = low;
                 Task::none()
             }
             Message::Save => {
@@ -658,6 +1997,39 @@ impl App {
                         .image_duration
                         .push(&self.config.playback.image_duration.to_string());
                 }
+                if self
+                    .text_histories
+                    .seek_step_image
+                    .current()
+                    .parse::<NonZeroUsize>()
+                    .is_err()
+                {
+                    self.text_histories
+                        .seek_step_image
+                        .push(&self.config.playback.seek_step_image.to_string());
+                }
+                if self
+                    .text_histories
+                    .seek_step_audio
+                    .current()
+                    .parse::<NonZeroUsize>()
+                    .is_err()
+                {
+                    self.text_histories
+                        .seek_step_audio
+                        .push(&self.config.playback.seek_step_audio.to_string());
+                }
+                if self
+                    .text_histories
+                    .seek_step_video
+                    .current()
+                    .parse::<NonZeroUsize>()
+                    .is_err()
+                {
+                    self.text_histories
+                        .seek_step_video
+                        .push(&self.config.playback.seek_step_video.to_string());
+                }
 
                 Task::none()
             }
@@ -679,26 +2051,124 @@ impl App {
                             self.config.playback.image_duration = value;
                         }
                     }
+                    config::Event::SeekStepImageRaw(value) => {
+                        self.text_histories.seek_step_image.push(&value.to_string());
+                        if let Ok(value) = value.parse::<NonZeroUsize>() {
+                            self.config.playback.seek_step_image = value;
+                        }
+                    }
+                    config::Event::SeekStepAudioRaw(value) => {
+                        self.text_histories.seek_step_audio.push(&value.to_string());
+                        if let Ok(value) = value.parse::<NonZeroUsize>() {
+                            self.config.playback.seek_step_audio = value;
+                        }
+                    }
+                    config::Event::SeekStepVideoRaw(value) => {
+                        self.text_histories.seek_step_video.push(&value.to_string());
+                        if let Ok(value) = value.parse::<NonZeroUsize>() {
+                            self.config.playback.seek_step_video = value;
+                        }
+                    }
+                    config::Event::MuteWhenWindowIsMinimized(value) => {
+                        self.config.playback.mute_on_minimize = value;
+                    }
+                    config::Event::PauseWhenWindowIsHidden(value) => {
+                        self.config.playback.pause_when_hidden = value;
+                    }
+                    config::Event::LoadSubtitles(value) => {
+                        self.config.playback.load_subtitles = value;
+                    }
                     config::Event::PauseWhenWindowLosesFocus(value) => {
                         self.config.playback.pause_on_unfocus = value;
                     }
                     config::Event::ConfirmWhenDiscardingUnsavedPlaylist(value) => {
                         self.config.view.confirm_discard_playlist = value;
                     }
+                    config::Event::ConfirmWhenExitingWhilePlaying(value) => {
+                        self.config.view.confirm_exit_while_playing = value;
+                    }
+                    config::Event::ClickToPause(value) => {
+                        self.config.playback.click_to_pause = value;
+                    }
+                    config::Event::SpaceTarget(value) => {
+                        self.config.input.space_target = value;
+                    }
+                    config::Event::MuteNewPlayers(value) => {
+                        self.config.playback.mute_new_players = value;
+                    }
+                    config::Event::ShowNoMediaNotice(value) => {
+                        self.config.view.show_no_media_notice = value;
+                    }
+                    config::Event::ShowMediaDate(value) => {
+                        self.config.view.show_media_date = value;
+                    }
+                    config::Event::ShowProgressBars(value) => {
+                        self.config.view.show_progress_bars = value;
+                    }
+                    config::Event::AutosavePlaylist(value) => {
+                        self.config.view.autosave_playlist = value;
+                        if value && self.playlist_dirty {
+                            self.mark_playlist_dirty();
+                        }
+                    }
+                    config::Event::SaveWindowSizeInPlaylist(value) => {
+                        self.config.view.save_window_size_in_playlist = value;
+                    }
+                    config::Event::RestoreLastSession(value) => {
+                        self.config.view.restore_last_session = value;
+                    }
+                    config::Event::FollowSymlinks(value) => {
+                        self.config.playback.follow_symlinks = value;
+                    }
+                    config::Event::CollapseDuplicates(value) => {
+                        self.config.playback.collapse_duplicates = value;
+                    }
+                    config::Event::AutoRescan(value) => {
+                        self.config.playback.auto_rescan = value;
+                    }
+                    config::Event::KeepAwake(value) => {
+                        self.config.playback.keep_awake = value;
+                    }
+                    config::Event::PinDestination { folder, pinned } => {
+                        if pinned {
+                            self.config.pin_destination(folder);
+                        } else {
+                            self.config.unpin_destination(&folder);
+                        }
+                    }
+                    config::Event::UpdateRate(value) => {
+                        self.config.playback.update_rate = value;
+                    }
+                    config::Event::RefreshButton(value) => {
+                        self.config.view.refresh_button = value;
+                    }
+                    config::Event::HighContrastSelection(value) => {
+                        self.config.view.selection.high_contrast = value;
+                    }
+                    config::Event::OnEnd(value) => {
+                        self.config.playback.on_end = value;
+                    }
+                    config::Event::NormalizeAudio(value) => {
+                        self.config.playback.normalize_audio = value;
+                        #[cfg(feature = "audio")]
+                        for (_id, grid) in self.grids.iter_mut() {
+                            grid.reload_audio(&self.config.playback);
+                        }
+                    }
+                    config::Event::LogLevel(value) => {
+                        self.config.view.log_level = value;
+                        set_log_level(value);
+                    }
                 }
                 self.save_config();
                 Task::none()
             }
             Message::CheckAppRelease => {
-                if !self.cache.should_check_app_update() {
+                if !self.config.release.should_check() || !self.cache.should_check_app_update() {
                     return Task::none();
                 }
 
-                Task::future(async move {
-                    let result = crate::metadata::Release::fetch().await;
-
-                    Message::AppReleaseChecked(result.map_err(|x| x.to_string()))
-                })
+                Self::check_app_release()
             }
             Message::AppReleaseChecked(outcome) => {
                 self.save_cache();
@@ -794,7 +2264,14 @@ impl App {
                                     iced::widget::operation::focus_next()
                                 }
                             } else {
+                                let previous = self.selection.pair().map(|(grid_id, _player_id)| grid_id);
                                 self.selection.cycle(self.selectables(), modifiers.shift());
+                                let current = self.selection.pair().map(|(grid_id, _player_id)| grid_id);
+
+                                // Moving between players in the same grid doesn't count.
+                                if let Some(grid_id) = current.filter(|grid_id| previous != Some(*grid_id)) {
+                                    self.shuffle_grid_on_focus(grid_id);
+                                }
                                 Task::none()
                             }
                         }
@@ -811,7 +2288,19 @@ impl App {
                         Key::Named(key::Named::Space) => {
                             if self.modals.is_empty() {
                                 self.generate_event_in_selection(
-                                    |app| Some(Message::SetPause(!app.config.playback.paused)),
+                                    |app| match app.config.input.space_target {
+                                        SpaceTarget::Global => Some(Message::SetPause(!app.playback().paused)),
+                                        SpaceTarget::HoveredTile => {
+                                            let (grid_id, player_id) = app.hovered_player()?;
+                                            let player = app.grids.get(grid_id)?.player(player_id)?;
+                                            Some(Message::Player {
+                                                grid_id,
+                                                player_id,
+                                                event: player::Event::SetPause(!player.is_paused().unwrap_or_default()),
+                                            })
+                                        }
+                                        SpaceTarget::Selection => None,
+                                    },
                                     |grid_id, grid| {
                                         Some(PaneEvent::SetPause {
                                             grid_id,
@@ -865,6 +2354,17 @@ impl App {
 
                             if self.modals.is_empty() {
                                 match c.as_str() {
+                                    "H" | "h" if modifiers.is_empty() => self.generate_event_in_selection(
+                                        |_| None,
+                                        |_, _| None,
+                                        |_| Some(player::Event::Blacklist),
+                                    ),
+                                    "H" | "h" if command_shift => self.split_selected_grid(pane_grid::Axis::Vertical),
+                                    "V" | "v" if command_shift => self.split_selected_grid(pane_grid::Axis::Horizontal),
+                                    "I" | "i" if modifiers.is_empty() => {
+                                        self.set_show_info(!self.config.view.show_info);
+                                        Task::none()
+                                    }
                                     "J" | "j" => self.generate_event_in_selection(
                                         |_| Some(Message::SeekRandom),
                                         |grid_id, _| Some(PaneEvent::SeekRandom { grid_id }),
@@ -874,7 +2374,7 @@ impl App {
                                         self.update(Message::SetSynchronized(!self.config.playback.synchronized))
                                     }
                                     "M" | "m" => self.generate_event_in_selection(
-                                        |app| Some(Message::SetMute(!app.config.playback.muted)),
+                                        |app| Some(Message::SetMute(!app.playback().muted)),
                                         |grid_id, grid| {
                                             Some(PaneEvent::SetMute {
                                                 grid_id,
@@ -894,12 +2394,84 @@ impl App {
                                     }
                                     "N" | "n" if command => self.update(Message::PlaylistReset { force: false }),
                                     "O" | "o" if command => self.update(Message::PlaylistSelect { force: false }),
+                                    "F" | "f" if modifiers.is_empty() => {
+                                        if let Some((grid_id, _)) = self.selection.pair() {
+                                            self.update(Message::Pane {
+                                                event: PaneEvent::ToggleMaximize { grid_id },
+                                            })
+                                        } else if let Some(grid_id) = self.grids.maximized() {
+                                            self.update(Message::Pane {
+                                                event: PaneEvent::ToggleMaximize { grid_id },
+                                            })
+                                        } else {
+                                            Task::none()
+                                        }
+                                    }
                                     "R" | "r" => self.generate_event_in_selection(
                                         |_| Some(Message::Refresh),
                                         |grid_id, _| Some(PaneEvent::Refresh { grid_id }),
                                         |_| Some(player::Event::Refresh),
                                     ),
+                                    "0" | "1" | "2" | "3" | "4" | "5" | "6" | "7" | "8" | "9"
+                                        if modifiers.is_empty() =>
+                                    {
+                                        let Ok(decile) = c.parse::<u8>() else {
+                                            return Task::none();
+                                        };
+                                        let position = decile as f64 / 10.0;
+
+                                        self.generate_event_in_selection(
+                                            |app| app.can_seek().then_some(Message::SeekRelative(position)),
+                                            |grid_id, grid| {
+                                                grid.can_seek().then_some(PaneEvent::SeekRelative { grid_id, position })
+                                            },
+                                            |player| player.can_seek().then_some(player::Event::SeekRelative(position)),
+                                        )
+                                    }
+                                    "1" | "2" | "3" | "4" | "5" | "6" | "7" | "8" | "9"
+                                        if modifiers == Modifiers::ALT =>
+                                    {
+                                        let Ok(number) = c.parse::<usize>() else {
+                                            return Task::none();
+                                        };
+                                        self.select_grid_at(number - 1);
+                                        Task::none()
+                                    }
+                                    "1" | "2" | "3" | "4" | "5" if command || command_shift => {
+                                        let Ok(index) = c.parse::<usize>() else {
+                                            return Task::none();
+                                        };
+                                        let Some(folder) = self.config.view.pinned_destinations.get(index - 1).cloned()
+                                        else {
+                                            return Task::none();
+                                        };
+                                        let Some((grid_id, Some(player_id))) = self.selection.pair() else {
+                                            return Task::none();
+                                        };
+                                        let Some(media) = self
+                                            .grids
+                                            .get(grid_id)
+                                            .and_then(|grid| grid.player(player_id))
+                                            .and_then(|player| player.media())
+                                            .cloned()
+                                        else {
+                                            return Task::none();
+                                        };
+
+                                        self.update(Message::TransferMedia {
+                                            grid_id,
+                                            player_id,
+                                            media,
+                                            transfer: if command_shift { Transfer::Copy } else { Transfer::Move },
+                                            folder,
+                                        })
+                                    }
                                     "S" | "s" if command => self.update(Message::PlaylistSave),
+                                    "S" | "s" if modifiers.is_empty() => self.generate_event_in_selection(
+                                        |_| None,
+                                        |_, _| None,
+                                        |_| Some(player::Event::Stop),
+                                    ),
                                     "S" | "s" if command_shift => self.update(Message::PlaylistSaveAs),
                                     _ => Task::none(),
                                 }
@@ -936,25 +2508,197 @@ impl App {
                                 self.config.playback.image_duration = value;
                             }
                         }
+                        UndoSubject::SeekStepImage => {
+                            if let Ok(value) = self
+                                .text_histories
+                                .seek_step_image
+                                .apply(shortcut)
+                                .parse::<NonZeroUsize>()
+                            {
+                                self.config.playback.seek_step_image = value;
+                            }
+                        }
+                        UndoSubject::SeekStepAudio => {
+                            if let Ok(value) = self
+                                .text_histories
+                                .seek_step_audio
+                                .apply(shortcut)
+                                .parse::<NonZeroUsize>()
+                            {
+                                self.config.playback.seek_step_audio = value;
+                            }
+                        }
+                        UndoSubject::SeekStepVideo => {
+                            if let Ok(value) = self
+                                .text_histories
+                                .seek_step_video
+                                .apply(shortcut)
+                                .parse::<NonZeroUsize>()
+                            {
+                                self.config.playback.seek_step_video = value;
+                            }
+                        }
                         UndoSubject::Source { .. } => {}
+                        UndoSubject::FallbackSource => {}
                         UndoSubject::OrientationLimit => {}
+                        UndoSubject::GridImageDuration => {}
+                        UndoSubject::GridClipLength => {}
+                        UndoSubject::GridName => {}
+                    }
+                }
+
+                self.save_config();
+                Task::none()
+            }
+            Message::OpenUrl(url) => Self::open_url(url),
+            Message::OpenUrlAndCloseModal(url) => {
+                self.close_modal();
+                Self::open_url(url)
+            }
+            Message::Refresh => match self.config.view.refresh_button {
+                config::RefreshButton::Shuffle => self.update(Message::Shuffle),
+                config::RefreshButton::Rescan => self.update(Message::FindMedia),
+                config::RefreshButton::Both => {
+                    // Shuffle once the rescan is done, so that new media can be picked too.
+                    let task = self.update(Message::FindMedia);
+                    if media::is_scanning() {
+                        self.shuffle_after_scan = true;
+                        task
+                    } else {
+                        Task::batch([task, self.update(Message::Shuffle)])
+                    }
+                }
+            },
+            Message::Shuffle => {
+                self.refresh(media::RefreshContext::Manual);
+                Task::none()
+            }
+            Message::SetPause(flag) => {
+                self.set_paused(flag);
+                Task::none()
+            }
+            Message::SetLayoutLocked(locked) => {
+                self.layout_locked = locked;
+                self.mark_playlist_dirty();
+                Task::none()
+            }
+            Message::SetSoundtrack { path } => {
+                let playback = self.playback();
+                self.soundtrack = path.map(|path| {
+                    // Keep it relative so that the playlist can be moved along with its media.
+                    let path = media::path_in_playlist(path, self.playlist_path.as_ref());
+                    Soundtrack::new(path, self.playlist_path.as_ref(), &playback)
+                });
+                self.sync_soundtrack_mute();
+                self.mark_playlist_dirty();
+                Task::none()
+            }
+            Message::SetSoundtrackMuted(muted) => {
+                let playback = self.playback();
+                if let Some(soundtrack) = self.soundtrack.as_mut() {
+                    soundtrack.set_muted(muted, &playback);
+                }
+                Task::none()
+            }
+            Message::ImportFolderGrids { path } => {
+                let mut folders: Vec<_> = match path.read_dir() {
+                    Ok(entries) => entries
+                        .filter_map(|entry| entry.ok())
+                        .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_dir()))
+                        .map(|entry| StrictPath::from(entry.path()))
+                        .collect(),
+                    Err(e) => {
+                        log::error!("Unable to read subfolders: {path:?} | {e:?}");
+                        vec![]
                     }
+                };
+
+                if folders.is_empty() {
+                    self.show_error(Error::NoSubfoldersFound(path));
+                    return Task::none();
+                }
+
+                folders.sort_by_key(|folder| folder.render());
+                let truncated = folders.len() > MAX_FOLDER_GRIDS;
+                folders.truncate(MAX_FOLDER_GRIDS);
+
+                self.show_modal(Modal::ConfirmFolderGrids { folders, truncated });
+                Task::none()
+            }
+            Message::CreateFolderGrids { folders } => {
+                self.close_modal();
+
+                if folders.is_empty() {
+                    return Task::none();
                 }
 
-                self.save_config();
-                Task::none()
+                self.grids = pane_grid::State::with_configuration(Self::folder_grids_layout(
+                    &folders,
+                    pane_grid::Axis::Vertical,
+                ));
+                self.selection = Selection::default();
+                self.layout_locked = false;
+                self.mark_playlist_dirty();
+
+                let context = media::RefreshContext::Edit;
+                self.refresh(context);
+                Self::find_media(
+                    self.all_sources(),
+                    context,
+                    self.playlist_path.clone(),
+                    self.config.playback.follow_symlinks,
+                    self.config.playback.ignore_marker(),
+                    self.config.playback.max_file_size_mb,
+                )
             }
-            Message::OpenUrl(url) => Self::open_url(url),
-            Message::OpenUrlAndCloseModal(url) => {
-                self.close_modal();
-                Self::open_url(url)
+            Message::RandomizeLayout => {
+                self.randomize_layout();
+                self.selection = Selection::default();
+                self.mark_playlist_dirty();
+
+                let context = media::RefreshContext::Edit;
+                self.refresh(context);
+                Self::find_media(
+                    self.all_sources(),
+                    context,
+                    self.playlist_path.clone(),
+                    self.config.playback.follow_symlinks,
+                    self.config.playback.ignore_marker(),
+                    self.config.playback.max_file_size_mb,
+                )
             }
-            Message::Refresh => {
-                self.refresh(media::RefreshContext::Manual);
+            Message::EvenOutLayout => {
+                let mut ratios = vec![];
+                Self::even_layout_ratios(self.grids.layout(), &mut ratios);
+                for (split, ratio) in ratios {
+                    self.grids.resize(split, ratio);
+                }
+                self.mark_playlist_dirty();
                 Task::none()
             }
-            Message::SetPause(flag) => {
-                self.set_paused(flag);
+            #[cfg(feature = "media-controls")]
+            Message::MediaControls(event) => {
+                use crate::gui::media_controls::Event;
+
+                match event {
+                    Event::Ready(sender) => {
+                        self.media_controls = Some(sender.clone());
+                        self.now_playing = None;
+                        Self::attach_media_controls(sender)
+                    }
+                    Event::Play => self.update(Message::SetPause(false)),
+                    Event::Pause | Event::Stop => self.update(Message::SetPause(true)),
+                    Event::Toggle => self.update(Message::SetPause(self.all_paused() == Some(false))),
+                    Event::Next => self.update(Message::Shuffle),
+                    Event::Previous => self.update(Message::SeekRelative(0.0)),
+                    Event::Quit => self.update(Message::Exit { force: false }),
+                }
+            }
+            Message::SetCategoryPause { category, paused } => {
+                for (_grid_id, grid) in self.grids.iter_mut() {
+                    grid.synchronize_players(None, category, player::Event::SetPause(paused), &self.config.playback);
+                }
+                self.update_playback();
                 Task::none()
             }
             Message::SetMute(flag) => {
@@ -982,6 +2726,17 @@ impl App {
 
                 Task::none()
             }
+            Message::SeekRelative(position) => {
+                for (_grid_id, grid) in self.grids.iter_mut() {
+                    grid.update_all_players(
+                        player::Event::SeekRelative(position),
+                        &mut self.media,
+                        &self.config.playback,
+                    );
+                }
+
+                Task::none()
+            }
             Message::Step(step) => {
                 for (_grid_id, grid) in self.grids.iter_mut() {
                     grid.update_all_players(player::Event::Step(step), &mut self.media, &self.config.playback);
@@ -997,6 +2752,35 @@ impl App {
                     return Task::none();
                 };
 
+                match event {
+                    player::Event::MouseEnter => {
+                        let hovered = grid.player(player_id).is_some_and(|player| player.is_hovered());
+                        if !hovered && !self.config.view.overlay_hover_delay().is_zero() {
+                            // Moving within the player shouldn't restart the delay.
+                            if self
+                                .pending_hover
+                                .is_none_or(|(pending_grid_id, pending_player_id, _since)| {
+                                    (pending_grid_id, pending_player_id) != (grid_id, player_id)
+                                })
+                            {
+                                self.pending_hover = Some((grid_id, player_id, Instant::now()));
+                            }
+                            return Task::none();
+                        }
+                    }
+                    player::Event::MouseExit => {
+                        if self
+                            .pending_hover
+                            .is_some_and(|(pending_grid_id, pending_player_id, _since)| {
+                                (pending_grid_id, pending_player_id) == (grid_id, player_id)
+                            })
+                        {
+                            self.pending_hover = None;
+                        }
+                    }
+                    _ => {}
+                }
+
                 if let Some(update) = grid.update(
                     grid::Event::Player { player_id, event },
                     &mut self.media,
@@ -1004,6 +2788,8 @@ impl App {
                 ) {
                     self.handle_grid_update(update, grid_id);
                 }
+                self.sync_mirrored_grids();
+                self.sync_on_screen_media();
                 Task::none()
             }
             Message::Modal { event } => {
@@ -1013,24 +2799,81 @@ impl App {
                             modal::Update::SavedGridSettings { grid_id, settings } => {
                                 let context = media::RefreshContext::Edit;
                                 self.modals.pop();
-                                let sources = settings.sources.clone();
-                                if let Some(grid) = self.grids.get_mut(grid_id) {
-                                    match grid.set_settings(settings) {
-                                        Change::Same => {}
-                                        Change::Different => {
-                                            self.playlist_dirty = true;
+                                let sources = settings.all_sources();
+                                self.apply_grid_settings(grid_id, settings);
+                                self.refresh(context);
+                                return Self::find_media(
+                                    sources,
+                                    context,
+                                    self.playlist_path.clone(),
+                                    self.config.playback.follow_symlinks,
+                                    self.config.playback.ignore_marker(),
+                                    self.config.playback.max_file_size_mb,
+                                );
+                            }
+                            modal::Update::ConfirmApplyGridSettingsToAll { grid_id, settings } => {
+                                let changes = self
+                                    .grids
+                                    .layout()
+                                    .panes()
+                                    .into_iter()
+                                    .filter_map(|id| {
+                                        let grid = self.grids.get(id)?;
+                                        if id == grid_id {
+                                            Some((id, settings.clone()))
+                                        } else {
+                                            Some((id, grid.settings().with_layout_of(&settings)))
                                         }
-                                    }
+                                    })
+                                    .collect();
+                                self.show_modal(Modal::ConfirmApplyGridSettingsToAll { grid_id, changes });
+                            }
+                            modal::Update::SavedAllGridSettings { grid_id, changes } => {
+                                let context = media::RefreshContext::Edit;
+                                // Close both the confirmation and the grid settings underneath it.
+                                self.modals.pop();
+                                self.modals.pop();
+                                let sources = changes
+                                    .iter()
+                                    .find(|(id, _)| *id == grid_id)
+                                    .map(|(_, settings)| settings.all_sources())
+                                    .unwrap_or_default();
+                                for (id, settings) in changes {
+                                    self.apply_grid_settings(id, settings);
                                 }
                                 self.refresh(context);
-                                return Self::find_media(sources, context, self.playlist_path.clone());
+                                return Self::find_media(
+                                    sources,
+                                    context,
+                                    self.playlist_path.clone(),
+                                    self.config.playback.follow_symlinks,
+                                    self.config.playback.ignore_marker(),
+                                    self.config.playback.max_file_size_mb,
+                                );
                             }
                             modal::Update::PlayMedia { grid_id, media } => {
                                 if let Some(grid) = self.grids.get_mut(grid_id) {
                                     grid.add_player_with_media(media, &mut self.media, &self.config.playback);
-                                    self.playlist_dirty = true;
+                                    self.mark_playlist_dirty();
+                                    self.sync_on_screen_media();
                                 }
                             }
+                            modal::Update::TransferMedia {
+                                grid_id,
+                                player_id,
+                                media,
+                                transfer,
+                                folder,
+                            } => {
+                                self.modals.pop();
+                                return self.update(Message::TransferMedia {
+                                    grid_id,
+                                    player_id,
+                                    media,
+                                    transfer,
+                                    folder,
+                                });
+                            }
                             modal::Update::Task(task) => {
                                 return task;
                             }
@@ -1047,20 +2890,107 @@ impl App {
                 self.all_sources(),
                 media::RefreshContext::Automatic,
                 self.playlist_path.clone(),
+                self.config.playback.follow_symlinks,
+                self.config.playback.ignore_marker(),
+                self.config.playback.max_file_size_mb,
             ),
+            Message::MediaScanFinished => {
+                if media::finish_scan() && self.shuffle_after_scan {
+                    self.shuffle_after_scan = false;
+                    return self.update(Message::Shuffle);
+                }
+                Task::none()
+            }
             Message::MediaScanned(scans) => {
                 let mut tasks = vec![];
+                let mut contexts = BTreeSet::new();
                 for scan in scans {
                     match scan {
-                        media::Scan::Found { source, media, context } => {
-                            self.media.insert(source, media);
-                            self.refresh(context);
+                        media::Scan::Found {
+                            source,
+                            media,
+                            size,
+                            context,
+                        } => {
+                            if !self.config.playback.collapse_duplicates {
+                                self.media.insert(source, media);
+                            } else if let Some(original) = self.media.original_of(&media).cloned() {
+                                self.media.insert(source, original);
+                            } else {
+                                let candidates = self.media.duplicate_candidates(&media, size);
+                                if !candidates.is_empty() {
+                                    // Reading the files could take a while, so do it in the background.
+                                    tasks.push(Self::find_media_one(media::Scan::CompareContent {
+                                        hashes: self.known_content_hashes(&media, &candidates),
+                                        source,
+                                        media,
+                                        size,
+                                        candidates,
+                                        context,
+                                    }));
+                                    continue;
+                                }
+                                self.media.insert_unique(source, media, size);
+                            }
+                            contexts.insert(context);
+                        }
+                        media::Scan::Unique {
+                            source,
+                            media,
+                            size,
+                            compared,
+                            hashes,
+                            context,
+                        } => {
+                            self.remember_content_hashes(hashes);
+
+                            // Other media with the same content may have been accepted while this was being compared,
+                            // so we have to check against anything new before accepting this too.
+                            let unchecked: Vec<_> = self
+                                .media
+                                .duplicate_candidates(&media, size)
+                                .into_iter()
+                                .filter(|candidate| !compared.contains(candidate))
+                                .collect();
+                            if !unchecked.is_empty() {
+                                tasks.push(Self::find_media_one(media::Scan::CompareContent {
+                                    hashes: self.known_content_hashes(&media, &unchecked),
+                                    source,
+                                    media,
+                                    size,
+                                    candidates: unchecked,
+                                    context,
+                                }));
+                                continue;
+                            }
+
+                            self.media.insert_unique(source, media, size);
+                            contexts.insert(context);
+                        }
+                        media::Scan::Duplicate {
+                            source,
+                            media,
+                            original,
+                            hashes,
+                            context,
+                        } => {
+                            self.remember_content_hashes(hashes);
+                            self.media.insert_duplicate(source, media, original);
+                            contexts.insert(context);
+                        }
+                        media::Scan::TooLarge { source, path } => {
+                            self.media.insert_too_large(source, path);
                         }
                         scan => {
                             tasks.push(Self::find_media_one(scan));
                         }
                     }
                 }
+                // Refresh once per batch rather than once per item,
+                // since a large source may send many batches in a row.
+                for context in contexts {
+                    self.refresh(context);
+                }
                 Task::batch(tasks)
             }
             Message::FileDragDrop(path) => {
@@ -1090,24 +3020,58 @@ impl App {
                         }
                         Some(_) => Task::none(),
                         None => {
-                            if self.grids.len() == 1 {
-                                let (grid_id, grid) = self.grids.iter().last().unwrap();
-
-                                let settings = grid.settings().with_source(media::Source::new_path(path));
-
-                                self.show_modal(Modal::new_grid_settings(*grid_id, settings));
-                                Task::batch([
-                                    iced::window::oldest().and_then(iced::window::gain_focus),
-                                    modal::scroll_down(),
-                                ])
+                            // Multiple files arrive back to back,
+                            // so only the first one needs to look up the players.
+                            self.dropped_files.push(path);
+                            if self.dropped_files.len() == 1 {
+                                self.find_drop_targets()
                             } else {
-                                self.dragged_files.insert(path);
-                                iced::window::oldest().and_then(iced::window::gain_focus)
+                                Task::none()
                             }
                         }
                     }
                 }
             }
+            Message::FileDropTargets(targets) => {
+                let paths: Vec<_> = self.dropped_files.drain(..).collect();
+                let focus = iced::window::oldest().and_then(iced::window::gain_focus);
+
+                let target = self
+                    .cursor_position
+                    .and_then(|position| {
+                        targets.iter().find_map(|(grid_id, player_id, bounds)| {
+                            bounds
+                                .is_some_and(|bounds| bounds.contains(position))
+                                .then_some((*grid_id, *player_id))
+                        })
+                    })
+                    .or_else(|| self.hovered_player());
+
+                let (task, leftover) = match target {
+                    Some((grid_id, player_id)) => self.drop_paths_on_player(grid_id, player_id, paths),
+                    None => (Task::none(), paths),
+                };
+
+                if leftover.is_empty() {
+                    Task::batch([focus, task])
+                } else if self.grids.len() == 1 {
+                    let (grid_id, grid) = self.grids.iter().last().unwrap();
+
+                    let settings = grid
+                        .settings()
+                        .with_sources(leftover.into_iter().map(media::Source::new_path).collect());
+
+                    self.show_modal(Modal::new_grid_settings(*grid_id, settings));
+                    Task::batch([focus, task, modal::scroll_down()])
+                } else {
+                    self.dragged_files.extend(leftover);
+                    Task::batch([focus, task])
+                }
+            }
+            Message::CursorMoved(position) => {
+                self.cursor_position = Some(position);
+                Task::none()
+            }
             Message::FileDragDropGridSelected(grid_id) => {
                 let Some(grid) = self.grids.get(grid_id) else {
                     return Task::none();
@@ -1121,15 +3085,51 @@ impl App {
                 modal::scroll_down()
             }
             Message::WindowFocused => {
+                self.window_focused = true;
                 for (_grid_id, grid) in self.grids.iter_mut() {
                     grid.update_all_players(player::Event::WindowFocused, &mut self.media, &self.config.playback);
                 }
-                Task::none()
+                self.check_minimized()
             }
             Message::WindowUnfocused => {
+                self.window_focused = false;
                 for (_grid_id, grid) in self.grids.iter_mut() {
                     grid.update_all_players(player::Event::WindowUnfocused, &mut self.media, &self.config.playback);
                 }
+                self.check_minimized()
+            }
+            Message::WindowResized(size) => {
+                self.window_size = Some(size);
+                self.check_minimized()
+            }
+            Message::MouseActivity => {
+                self.last_mouse_activity = Instant::now();
+                self.chrome_hidden = false;
+                Task::none()
+            }
+            Message::WindowMinimized => {
+                if self.config.playback.mute_on_minimize && !self.muted_for_minimize {
+                    self.muted_for_minimize = true;
+                    self.apply_muted(true);
+                }
+                self.update(Message::WindowHidden)
+            }
+            Message::WindowHidden => {
+                if self.config.playback.pause_when_hidden && !self.paused_for_hide {
+                    self.paused_for_hide = true;
+                    self.apply_paused(true);
+                }
+                Task::none()
+            }
+            Message::WindowRestored => {
+                if self.muted_for_minimize {
+                    self.muted_for_minimize = false;
+                    self.apply_muted(self.playback().muted);
+                }
+                if self.paused_for_hide {
+                    self.paused_for_hide = false;
+                    self.apply_paused(self.playback().paused);
+                }
                 Task::none()
             }
             Message::Pane { event } => {
@@ -1139,7 +3139,7 @@ impl App {
                             self.dragging_pane = true;
                         }
                         pane_grid::DragEvent::Dropped { pane, target } => {
-                            self.playlist_dirty = true;
+                            self.mark_playlist_dirty();
                             self.dragging_pane = false;
                             self.grids.drop(pane, target);
                         }
@@ -1148,22 +3148,43 @@ impl App {
                         }
                     },
                     PaneEvent::Resize(event) => {
-                        self.playlist_dirty = true;
+                        self.mark_playlist_dirty();
                         self.grids.resize(event.split, event.ratio);
                     }
                     PaneEvent::Split { grid_id, axis } => {
                         let idle = self.grids.get(grid_id).is_some_and(|grid| grid.is_idle());
                         let settings = grid::Settings::default();
                         if let Some((grid_id, _split)) = self.grids.split(axis, grid_id, Grid::new(&settings)) {
-                            self.playlist_dirty = true;
+                            self.mark_playlist_dirty();
                             if !idle {
                                 self.show_modal(Modal::new_grid_settings(grid_id, settings));
                             }
                         }
                     }
+                    PaneEvent::Duplicate { grid_id } => {
+                        let Some(settings) = self.grids.get(grid_id).map(|grid| grid.settings()) else {
+                            return Task::none();
+                        };
+                        let axis = pane_grid::Axis::Vertical;
+                        if let Some((grid_id, _split)) = self.grids.split(axis, grid_id, Grid::new(&settings)) {
+                            self.mark_playlist_dirty();
+                            self.sync_soundtrack_mute();
+                            if let Some(grid) = self.grids.get_mut(grid_id) {
+                                grid.refresh(&mut self.media, &self.config.playback, media::RefreshContext::Edit);
+                            }
+                            self.sync_on_screen_media();
+                            self.update_playback();
+                        }
+                    }
                     PaneEvent::Close { grid_id } => {
-                        self.playlist_dirty = true;
+                        self.mark_playlist_dirty();
                         self.grids.close(grid_id);
+                        for (_, grid) in self.grids.iter_mut() {
+                            if grid.mirror_of() == Some(grid_id) {
+                                grid.set_mirror_of(None);
+                            }
+                        }
+                        self.sync_on_screen_media();
                         self.update_playback();
                         self.selection.clear();
                     }
@@ -1174,7 +3195,8 @@ impl App {
 
                         match grid.add_player(&mut self.media, &self.config.playback) {
                             Ok(_) => {
-                                self.playlist_dirty = true;
+                                self.mark_playlist_dirty();
+                                self.sync_on_screen_media();
                             }
                             Err(e) => match e {
                                 grid::Error::NoMediaAvailable => {
@@ -1219,6 +3241,27 @@ impl App {
                             self.update_playback();
                         }
                     }
+                    PaneEvent::SoloAudio { grid_id } => {
+                        let soloed = self.solo_audio.as_ref().map(|(soloed, _)| *soloed);
+                        self.unsolo_audio();
+
+                        if soloed != Some(grid_id) {
+                            let mut before = HashMap::new();
+                            for (id, grid) in self.grids.iter_mut() {
+                                if let Some(muted) = grid.all_muted() {
+                                    before.insert(*id, muted);
+                                }
+                                grid.update_all_players(
+                                    player::Event::SetMute(*id != grid_id),
+                                    &mut self.media,
+                                    &self.config.playback,
+                                );
+                            }
+                            self.solo_audio = Some((grid_id, before));
+                        }
+
+                        self.update_playback();
+                    }
                     PaneEvent::SetPause { grid_id, paused } => {
                         if let Some(grid) = self.grids.get_mut(grid_id) {
                             grid.update_all_players(
@@ -1245,6 +3288,17 @@ impl App {
                             }
                         }
                     }
+                    PaneEvent::SeekRelative { grid_id, position } => {
+                        let event = player::Event::SeekRelative(position);
+
+                        if let Some(grid) = self.grids.get_mut(grid_id) {
+                            grid.update_all_players(event.clone(), &mut self.media, &self.config.playback);
+
+                            for category in grid.categories() {
+                                self.synchronize_players(grid_id, category, event.clone());
+                            }
+                        }
+                    }
                     PaneEvent::Step { grid_id, step } => {
                         let event = player::Event::Step(step);
 
@@ -1260,6 +3314,45 @@ impl App {
                         if let Some(grid) = self.grids.get_mut(grid_id) {
                             grid.update_all_players(player::Event::Refresh, &mut self.media, &self.config.playback);
                         }
+                        self.sync_on_screen_media();
+                    }
+                    PaneEvent::FreezeSources { grid_id } => {
+                        self.viewing_pane_controls = None;
+                        if let Some(grid) = self.grids.get_mut(grid_id) {
+                            match grid.freeze_sources(&mut self.media, self.playlist_path.as_ref()) {
+                                Change::Same => {}
+                                Change::Different => {
+                                    self.mark_playlist_dirty();
+                                }
+                            }
+                            grid.update_media_total(&self.media);
+                        }
+                    }
+                    PaneEvent::UnfreezeSources { grid_id } => {
+                        self.viewing_pane_controls = None;
+                        if let Some(sources) = self.grids.get_mut(grid_id).and_then(|grid| grid.unfreeze_sources()) {
+                            self.mark_playlist_dirty();
+                            return Self::find_media(
+                                sources,
+                                media::RefreshContext::Edit,
+                                self.playlist_path.clone(),
+                                self.config.playback.follow_symlinks,
+                                self.config.playback.ignore_marker(),
+                                self.config.playback.max_file_size_mb,
+                            );
+                        }
+                    }
+                    PaneEvent::ToggleMaximize { grid_id } => {
+                        self.viewing_pane_controls = None;
+                        if self.grids.maximized() == Some(grid_id) {
+                            self.grids.restore();
+                            self.reload_unloaded_media();
+                        } else if self.grids.len() > 1 {
+                            self.grids.maximize(grid_id);
+                            if self.selection.pair().is_some_and(|(selected, _)| selected != grid_id) {
+                                self.selection.clear();
+                            }
+                        }
                     }
                 }
                 Task::none()
@@ -1273,9 +3366,19 @@ impl App {
                 self.close_modal();
                 let (grids, _grid_id) = pane_grid::State::new(Grid::new(&grid::Settings::default()));
                 self.grids = grids;
+                self.layout_locked = false;
+                self.rotation = None;
+                self.soundtrack = None;
+                self.playlist_settings = None;
+                self.session_paused = None;
+                self.session_muted = None;
+                self.solo_audio = None;
                 self.playlist_dirty = false;
                 self.playlist_path = None;
                 self.media.clear();
+                self.media.clear_blacklist();
+                self.unloaded_media.clear();
+                self.save_status();
 
                 Task::none()
             }
@@ -1302,6 +3405,14 @@ impl App {
                     )
                 })
             }
+            Message::PlaylistLoadRecent { path } => {
+                if self.playlist_dirty && self.config.view.confirm_discard_playlist {
+                    self.show_modal(Modal::ConfirmLoadPlaylist { path: Some(path) });
+                    Task::none()
+                } else {
+                    self.update(Message::PlaylistLoad { path })
+                }
+            }
             Message::PlaylistLoad { path } => {
                 self.modals.clear();
 
@@ -1309,11 +3420,38 @@ impl App {
                     Ok(playlist) => {
                         self.playlist_dirty = false;
                         self.playlist_path = Some(path.clone());
+                        self.cache.add_recent_playlist(path.clone());
+                        self.save_cache();
+                        self.save_status();
 
                         let context = media::RefreshContext::Playlist;
-                        self.grids = Self::load_playlist(playlist);
+                        self.media.clear_blacklist();
+                        self.unloaded_media.clear();
+                        let window = playlist.window;
+                        self.layout_locked = playlist.layout_locked;
+                        self.playlist_settings = playlist.settings;
+                        self.session_paused = self.playlist_settings.and_then(|settings| settings.paused);
+                        self.session_muted = self.playlist_settings.and_then(|settings| settings.muted);
+                        let playback = self.playback();
+                        self.soundtrack = playlist
+                            .soundtrack
+                            .clone()
+                            .map(|soundtrack| Soundtrack::new(soundtrack, Some(&path), &playback));
+                        self.solo_audio = None;
+                        (self.grids, self.selection) = Self::load_playlist(playlist);
                         self.refresh(context);
-                        Self::find_media(self.all_sources(), context, self.playlist_path.clone())
+                        Task::batch([
+                            Self::apply_playlist_settings(self.playlist_settings),
+                            Self::find_media(
+                                self.all_sources(),
+                                context,
+                                self.playlist_path.clone(),
+                                self.config.playback.follow_symlinks,
+                                self.config.playback.ignore_marker(),
+                                self.config.playback.max_file_size_mb,
+                            ),
+                            Self::resize_window_for_playlist(window),
+                        ])
                     }
                     Err(e) => {
                         self.show_error(e);
@@ -1353,11 +3491,14 @@ impl App {
             }),
             Message::PlaylistSavedAs { path } => {
                 self.playlist_path = Some(path.clone());
+                self.save_status();
 
                 let playlist = self.build_playlist();
                 match playlist.save_to(&path) {
                     Ok(_) => {
                         self.playlist_dirty = false;
+                        self.cache.add_recent_playlist(path.clone());
+                        self.save_cache();
                         Self::find_media(
                             self.all_sources()
                                 .into_iter()
@@ -1365,6 +3506,9 @@ impl App {
                                 .collect(),
                             media::RefreshContext::Edit,
                             self.playlist_path.clone(),
+                            self.config.playback.follow_symlinks,
+                            self.config.playback.ignore_marker(),
+                            self.config.playback.max_file_size_mb,
                         )
                     }
                     Err(e) => {
@@ -1373,10 +3517,93 @@ impl App {
                     }
                 }
             }
+            Message::SetRotating(rotating) => {
+                if rotating {
+                    self.rotate_playlist(None)
+                } else {
+                    self.rotation = None;
+                    Task::none()
+                }
+            }
+            Message::RotatePlaylist => match self.rotation {
+                Some(index) => self.rotate_playlist(Some(index)),
+                None => Task::none(),
+            },
             Message::ShowMenu { show } => {
                 self.viewing_menu = show.unwrap_or(!self.viewing_menu);
                 Task::none()
             }
+            Message::ReportIssue => {
+                let diagnostics = crate::metadata::Diagnostics::gather(&self.config);
+                Task::batch([
+                    iced::clipboard::write(diagnostics.text()),
+                    Self::open_url(diagnostics.issue_url()),
+                ])
+            }
+            Message::DeleteToTrash {
+                grid_id,
+                player_id,
+                media,
+            } => {
+                self.close_modal();
+
+                if let Err(e) = media.path().move_to_trash() {
+                    log::error!("Unable to move file to trash: {:?} | {e:?}", media.path());
+                    self.show_error(Error::UnableToMoveToTrash {
+                        path: media.path().clone(),
+                        why: e.to_string(),
+                    });
+                    return Task::none();
+                }
+
+                self.media.remove(&media);
+                self.update_media_totals();
+                self.move_on_from_media(grid_id, player_id, &media);
+                Task::none()
+            }
+            Message::TransferMedia {
+                grid_id,
+                player_id,
+                media,
+                transfer,
+                folder,
+            } => {
+                let source = media.path();
+                let target = folder.available_child(
+                    &source.file_stem().unwrap_or_default(),
+                    source.file_extension().as_deref(),
+                );
+
+                let result = match transfer {
+                    Transfer::Move => source.move_across_devices(&target),
+                    Transfer::Copy => source.copy_to(&target).map(|_| ()),
+                };
+
+                if let Err(e) = result {
+                    log::error!("Unable to {transfer:?} file: {source:?} -> {target:?} | {e:?}");
+                    self.show_error(match transfer {
+                        Transfer::Move => Error::UnableToMoveFile {
+                            path: source.clone(),
+                            why: e.to_string(),
+                        },
+                        Transfer::Copy => Error::UnableToCopyFile {
+                            path: source.clone(),
+                            why: e.to_string(),
+                        },
+                    });
+                    return Task::none();
+                }
+
+                self.config.add_recent_destination(folder);
+                self.save_config();
+
+                if transfer == Transfer::Move {
+                    self.media.remove(&media);
+                    self.update_media_totals();
+                }
+                self.move_on_from_media(grid_id, player_id, &media);
+                Task::none()
+            }
             Message::Menu { message } => {
                 self.viewing_menu = false;
                 self.update(*message)
@@ -1392,31 +3619,92 @@ impl App {
                 iced::Event::Window(iced::window::Event::FileDropped(path)) => {
                     Some(Message::FileDragDrop(StrictPath::from(path)))
                 }
+                iced::Event::Mouse(iced::mouse::Event::CursorMoved { position }) => {
+                    Some(Message::CursorMoved(position))
+                }
                 iced::Event::Window(iced::window::Event::Focused) => Some(Message::WindowFocused),
                 iced::Event::Window(iced::window::Event::Unfocused) => Some(Message::WindowUnfocused),
+                iced::Event::Window(iced::window::Event::Opened { size, .. }) => Some(Message::WindowResized(size)),
+                iced::Event::Window(iced::window::Event::Resized(size)) => Some(Message::WindowResized(size)),
                 _ => None,
             }),
-            iced::time::every(Duration::from_millis(100)).map(Message::Tick),
-            iced::time::every(Duration::from_secs(60 * 10)).map(|_| Message::FindMedia),
+            iced::time::every(self.tick_interval()).map(Message::Tick),
         ];
 
+        if self.config.view.autohide_after.is_some() {
+            subscriptions.push(iced::event::listen_with(|event, _status, _window| match event {
+                iced::Event::Mouse(
+                    iced::mouse::Event::CursorMoved { .. }
+                    | iced::mouse::Event::ButtonPressed(_)
+                    | iced::mouse::Event::WheelScrolled { .. },
+                ) => Some(Message::MouseActivity),
+                _ => None,
+            }));
+        }
+
+        if self.config.playback.auto_rescan {
+            let minutes = self.config.playback.auto_rescan_minutes.get() as u64;
+            subscriptions.push(iced::time::every(Duration::from_secs(60 * minutes)).map(|_| Message::FindMedia));
+        }
+
         #[cfg(feature = "audio")]
         subscriptions.push(iced::time::every(Duration::from_millis(1000)).map(|_| Message::CheckAudio));
 
+        #[cfg(feature = "battery")]
+        if self.config.playback.pause_on_low_battery.is_some() {
+            subscriptions.push(iced::time::every(Duration::from_secs(60)).map(|_| Message::CheckBattery));
+        }
+
         if !self.pending_save.is_empty() {
             subscriptions.push(iced::time::every(Duration::from_millis(200)).map(|_| Message::Save));
         }
 
-        if self.config.release.check {
+        if let Some(entry) = self
+            .rotation
+            .and_then(|index| self.config.rotation.playlists.get(index))
+        {
+            subscriptions.push(iced::time::every(entry.duration()).map(|_| Message::RotatePlaylist));
+        }
+
+        if let Some(interval) = self.playlist_settings.and_then(|settings| settings.shuffle_interval()) {
+            subscriptions.push(iced::time::every(interval).map(|_| Message::Shuffle));
+        }
+
+        if self.config.release.should_check() {
             subscriptions.push(iced::time::every(Duration::from_secs(60 * 60 * 24)).map(|_| Message::CheckAppRelease));
         }
 
+        #[cfg(feature = "media-controls")]
+        subscriptions.push(iced::Subscription::run(crate::gui::media_controls::connect).map(Message::MediaControls));
+
+        #[cfg(feature = "http-api")]
+        if self.config.api.enabled {
+            subscriptions.push(iced::Subscription::run_with(
+                (
+                    self.config.api.host.clone(),
+                    self.config.api.port,
+                    self.config.api.token().map(|token| token.to_string()),
+                ),
+                crate::gui::api::serve,
+            ));
+        }
+
         iced::Subscription::batch(subscriptions)
     }
 
     pub fn view(&self) -> Element {
+        let playback = self.playback();
         let dragging_file = !self.dragged_files.is_empty();
         let obscured = !self.modals.is_empty();
+        let view_options = player::ViewOptions {
+            click_to_pause: self.config.playback.click_to_pause,
+            min_tile_px: self.config.view.min_tile_px,
+            tile_padding: self.config.view.tile_padding(),
+            show_media_date: self.config.view.show_media_date,
+            show_info: self.config.view.show_info,
+            show_progress_bars: self.config.view.show_progress_bars,
+            kiosk: self.kiosk,
+        };
 
         Responsive::new(move |viewport| {
             let left_controls = DropDown::new(
@@ -1430,6 +3718,24 @@ impl App {
                                 .on_press(Message::menu(Message::PlaylistSelect { force: false }))
                                 .padding(4),
                         )
+                        .push(Column::with_children(self.cache.recent_playlists.iter().map(|path| {
+                            button::menu(Icon::File, path.file_stem().unwrap_or_else(|| path.render()))
+                                .on_press(Message::menu(Message::PlaylistLoadRecent { path: path.clone() }))
+                                .tooltip(path.render())
+                                .padding(4)
+                                .into()
+                        })))
+                        .push((!self.config.rotation.playlists.is_empty()).then(|| {
+                            if self.rotation.is_some() {
+                                button::menu(Icon::Stop, lang::action::stop_playlist_rotation())
+                                    .on_press(Message::menu(Message::SetRotating(false)))
+                                    .padding(4)
+                            } else {
+                                button::menu(Icon::Loop, lang::action::start_playlist_rotation())
+                                    .on_press(Message::menu(Message::SetRotating(true)))
+                                    .padding(4)
+                            }
+                        }))
                         .push(
                             button::menu(Icon::Save, lang::action::save_playlist())
                                 .on_press(Message::menu(Message::PlaylistSave))
@@ -1447,6 +3753,46 @@ impl App {
                                 .enabled(self.playlist_dirty || self.playlist_path.is_some())
                                 .padding(4),
                         )
+                        .push(cfg!(feature = "audio").then(|| {
+                            button::menu(Icon::FileOpen, lang::action::choose_soundtrack())
+                                .on_press(Message::menu(Message::BrowseFile(BrowseFileSubject::Soundtrack)))
+                                .padding(4)
+                        }))
+                        .push(
+                            button::menu(Icon::FolderOpen, lang::action::create_grid_per_subfolder())
+                                .on_press(Message::menu(Message::BrowseDir(BrowseSubject::FolderGrids)))
+                                .enabled(!self.layout_locked)
+                                .padding(4),
+                        )
+                        .push(
+                            button::menu(Icon::ViewQuilt, lang::action::even_out_layout())
+                                .on_press(Message::menu(Message::EvenOutLayout))
+                                .enabled(!self.layout_locked && self.grids.len() > 1)
+                                .padding(4),
+                        )
+                        .push(
+                            button::menu(Icon::Shuffle, lang::action::randomize_layout())
+                                .on_press(Message::menu(Message::RandomizeLayout))
+                                .enabled(!self.layout_locked)
+                                .padding(4),
+                        )
+                        .push(
+                            button::menu(Icon::Settings, lang::action::open_config_folder())
+                                .on_press(Message::menu(Message::OpenDir {
+                                    path: Config::path().popped(),
+                                }))
+                                .padding(4),
+                        )
+                        .push(
+                            button::menu(Icon::Description, lang::action::open_log_folder())
+                                .on_press(Message::menu(Message::OpenDir { path: app_dir() }))
+                                .padding(4),
+                        )
+                        .push(
+                            button::menu(Icon::BugReport, lang::action::report_issue())
+                                .on_press(Message::menu(Message::ReportIssue))
+                                .padding(4),
+                        )
                         .push(STEAM_DECK.then(|| {
                             button::menu(Icon::LogOut, lang::action::exit_app())
                                 .on_press(Message::menu(Message::Exit { force: false }))
@@ -1460,12 +3806,23 @@ impl App {
             )
             .on_dismiss(Message::ShowMenu { show: Some(false) });
 
-            let right_controls = Row::new().push(
-                button::icon(Icon::Settings)
-                    .on_press(Message::ShowSettings)
-                    .obscured(obscured)
-                    .tooltip_below(lang::thing::settings()),
-            );
+            let right_controls = Row::new()
+                .push(
+                    button::icon(if self.layout_locked { Icon::Lock } else { Icon::LockOpen })
+                        .on_press(Message::SetLayoutLocked(!self.layout_locked))
+                        .obscured(obscured)
+                        .tooltip_below(if self.layout_locked {
+                            lang::action::unlock_layout()
+                        } else {
+                            lang::action::lock_layout()
+                        }),
+                )
+                .push(
+                    button::icon(Icon::Settings)
+                        .on_press(Message::ShowSettings)
+                        .obscured(obscured)
+                        .tooltip_below(lang::thing::settings()),
+                );
 
             let center_controls = Container::new(
                 Row::new()
@@ -1483,33 +3840,45 @@ impl App {
                             lang::action::synchronize()
                         }),
                     )
+                    .push(self.config.playback.synchronized.then(|| {
+                        Row::with_children(player::Category::media().into_iter().filter_map(|category| {
+                            let paused = self.category_paused(category)?;
+                            Some(
+                                Row::new()
+                                    .align_y(iced::Alignment::Center)
+                                    .push(category.icon().small_control())
+                                    .push(
+                                        button::icon(if paused { Icon::Play } else { Icon::Pause })
+                                            .on_press(Message::SetCategoryPause {
+                                                category,
+                                                paused: !paused,
+                                            })
+                                            .obscured(obscured)
+                                            .tooltip_below(category.toggle_pause_label(paused)),
+                                    )
+                                    .into(),
+                            )
+                        }))
+                    }))
                     .push(
-                        button::icon(if self.config.playback.muted {
-                            Icon::Mute
-                        } else {
-                            Icon::VolumeHigh
-                        })
-                        .on_press(Message::SetMute(!self.config.playback.muted))
-                        .obscured(obscured)
-                        .tooltip_below(if self.config.playback.muted {
-                            lang::action::unmute()
-                        } else {
-                            lang::action::mute()
-                        }),
+                        button::icon(if playback.muted { Icon::Mute } else { Icon::VolumeHigh })
+                            .on_press(Message::SetMute(!playback.muted))
+                            .obscured(obscured)
+                            .tooltip_below(if playback.muted {
+                                lang::action::unmute()
+                            } else {
+                                lang::action::mute()
+                            }),
                     )
                     .push(
-                        button::icon(if self.config.playback.paused {
-                            Icon::Play
-                        } else {
-                            Icon::Pause
-                        })
-                        .on_press(Message::SetPause(!self.config.playback.paused))
-                        .obscured(obscured)
-                        .tooltip_below(if self.config.playback.paused {
-                            lang::action::play()
-                        } else {
-                            lang::action::pause()
-                        }),
+                        button::icon(if playback.paused { Icon::Play } else { Icon::Pause })
+                            .on_press(Message::SetPause(!playback.paused))
+                            .obscured(obscured)
+                            .tooltip_below(if playback.paused {
+                                lang::action::play()
+                            } else {
+                                lang::action::pause()
+                            }),
                     )
                     .push(
                         button::icon(Icon::TimerRefresh)
@@ -1518,47 +3887,79 @@ impl App {
                             .obscured(obscured)
                             .tooltip_below(lang::action::jump_position()),
                     )
-                    .push(
-                        button::icon(Icon::Refresh)
-                            .on_press(Message::Refresh)
-                            .enabled(!self.all_idle())
-                            .obscured(obscured)
-                            .tooltip_below(lang::action::shuffle()),
-                    ),
+                    .push({
+                        let refresh = self.config.view.refresh_button;
+                        button::icon(match refresh {
+                            config::RefreshButton::Shuffle => Icon::Shuffle,
+                            config::RefreshButton::Rescan | config::RefreshButton::Both => Icon::Refresh,
+                        })
+                        .on_press(Message::Refresh)
+                        .enabled(refresh == config::RefreshButton::Rescan || !self.all_idle())
+                        .obscured(obscured)
+                        .tooltip_below(refresh.to_string())
+                    }),
             )
             .class(style::Container::Player { selected: false });
 
             let controls = Stack::new()
-                .push(Container::new(left_controls).align_left(Length::Fill))
-                .push(Container::new(right_controls).align_right(Length::Fill))
+                .push((!self.kiosk).then(|| Container::new(left_controls).align_left(Length::Fill)))
+                .push((!self.kiosk).then(|| Container::new(right_controls).align_right(Length::Fill)))
                 .push(Container::new(center_controls).center(Length::Fill));
 
-            let grids = PaneGrid::new(&self.grids, |grid_id, grid, _maximized| {
+            let mut grids = PaneGrid::new(&self.grids, |grid_id, grid, maximized| {
                 let selected = self.selection.is_grid_only_selected(grid_id);
-                pane_grid::Content::new(
+                let soloed = self.solo_audio.as_ref().is_some_and(|(soloed, _)| *soloed == grid_id);
+                let content = pane_grid::Content::new(
                     Container::new(grid.view(
                         grid_id,
                         selected,
                         self.selection.player_for_grid(grid_id),
                         obscured,
                         dragging_file,
+                        self.config.view.show_no_media_notice && grid.is_without_media(&self.media),
+                        grid.total_too_large(&self.media),
+                        view_options,
+                        &self.media,
                     ))
                     .padding(5)
                     .class(style::Container::PlayerGroup { selected }),
-                )
-                .title_bar({
-                    let mut bar = pane_grid::TitleBar::new(" ")
-                        .class(style::Container::PlayerGroupTitle)
-                        .controls(pane_grid::Controls::dynamic(
-                            grid.controls(grid_id, obscured, self.grids.len() > 1),
+                );
+
+                if self.chrome_hidden {
+                    return content;
+                }
+
+                content.title_bar({
+                    let mut bar = pane_grid::TitleBar::new(
+                        mouse_area(
+                            Row::new()
+                                .spacing(10)
+                                .push(text(grid.name().unwrap_or(" ")))
+                                .push(grid.media_counter().map(text)),
+                        )
+                        .on_double_click(Message::Pane {
+                            event: PaneEvent::ToggleMaximize { grid_id },
+                        }),
+                    )
+                    .class(style::Container::PlayerGroupTitle);
+
+                    if !self.kiosk {
+                        bar = bar.controls(pane_grid::Controls::dynamic(
+                            grid.controls(grid_id, obscured, self.grids.len() > 1, maximized, soloed),
                             DropDown::new(
                                 button::mini_icon(Icon::MoreVert)
                                     .on_press(Message::Pane {
                                         event: PaneEvent::ShowControls { grid_id },
                                     })
                                     .obscured(obscured),
-                                Container::new(grid.controls(grid_id, obscured, self.grids.len() > 1))
-                                    .class(style::Container::PlayerGroupControls),
+                                Container::new(grid.controls(
+                                    grid_id,
+                                    obscured,
+                                    self.grids.len() > 1,
+                                    maximized,
+                                    soloed,
+                                ))
+                                .class(style::Container::PlayerGroupControls),
                                 self.viewing_pane_controls.is_some_and(|x| x == grid_id),
                             )
                             .on_dismiss(Message::Pane {
@@ -1566,23 +3967,61 @@ impl App {
                             }),
                         ));
 
-                    if grid.is_idle() {
-                        bar = bar.always_show_controls();
+                        if grid.is_idle() {
+                            bar = bar.always_show_controls();
+                        }
                     }
 
                     bar
                 })
             })
-            .spacing(5)
-            .on_drag(|event| Message::Pane {
-                event: PaneEvent::Drag(event),
-            })
-            .on_resize(5, |event| Message::Pane {
-                event: PaneEvent::Resize(event),
-            });
+            .spacing(5);
+
+            if !self.kiosk && !self.layout_locked {
+                grids = grids
+                    .on_drag(|event| Message::Pane {
+                        event: PaneEvent::Drag(event),
+                    })
+                    .on_resize(5, |event| Message::Pane {
+                        event: PaneEvent::Resize(event),
+                    });
+            }
 
-            let content =
-                Container::new(Column::new().spacing(5).push(controls).push(grids)).class(style::Container::Primary);
+            let background: Option<Element> = match &self.config.view.app_background {
+                config::AppBackground::Theme => None,
+                config::AppBackground::Color(_) => self.config.view.app_background.color().map(|color| {
+                    Container::new(Space::new())
+                        .width(Length::Fill)
+                        .height(Length::Fill)
+                        .class(style::Container::Backdrop { color })
+                        .into()
+                }),
+                config::AppBackground::Image(_) => self.app_background.as_ref().map(|handle| {
+                    iced::widget::Image::new(handle.clone())
+                        .width(Length::Fill)
+                        .height(Length::Fill)
+                        .content_fit(iced::ContentFit::Cover)
+                        .into()
+                }),
+            };
+
+            let content = Container::new(
+                Column::new()
+                    .spacing(5)
+                    .push((!self.chrome_hidden).then_some(controls))
+                    .push(
+                        self.soundtrack
+                            .as_ref()
+                            .filter(|_| !self.chrome_hidden && !self.kiosk)
+                            .map(|soundtrack| soundtrack.view(obscured)),
+                    )
+                    .push(grids),
+            )
+            .class(if background.is_some() {
+                style::Container::Wrapper
+            } else {
+                style::Container::Primary
+            });
 
             let stack = Stack::new()
                 .width(Length::Fill)
@@ -1600,14 +4039,24 @@ impl App {
                             .grid_id()
                             .and_then(|grid_id| self.grids.get(grid_id).map(|grid| grid.active_media()))
                             .unwrap_or_default(),
+                        modal
+                            .grid_id()
+                            .map(|grid_id| self.mirror_options(grid_id))
+                            .unwrap_or_default(),
                     )
                 }));
 
-            Container::new(stack)
-                .width(Length::Fill)
-                .height(Length::Fill)
-                .padding(5.0)
-                .into()
+            let mut content = mouse_area(
+                Container::new(stack)
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .padding(5.0),
+            );
+            if self.chrome_hidden {
+                content = content.interaction(iced::mouse::Interaction::Hidden);
+            }
+
+            Stack::new().push(background).push(content).into()
         })
         .into()
     }
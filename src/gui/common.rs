@@ -17,7 +17,7 @@ use crate::{
     },
     media,
     prelude::StrictPath,
-    resource::config,
+    resource::{config, playlist::Playlist},
 };
 
 const ERROR_ICON: text_input::Icon<iced::Font> = text_input::Icon {
@@ -31,6 +31,9 @@ const ERROR_ICON: text_input::Icon<iced::Font> = text_input::Icon {
 #[derive(Clone, Debug, Default)]
 pub struct Flags {
     pub sources: Vec<media::Source>,
+    /// A playlist that didn't come from a file, such as from stdin.
+    pub playlist: Option<Playlist>,
+    pub kiosk: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -42,12 +45,17 @@ pub enum Message {
     Tick(Instant),
     #[cfg(feature = "audio")]
     CheckAudio,
+    #[cfg(feature = "battery")]
+    CheckBattery,
+    #[cfg(feature = "battery")]
+    BatteryChecked(bool),
     Save,
     CloseModal,
     Config {
         event: config::Event,
     },
     CheckAppRelease,
+    #[cfg_attr(not(feature = "update-check"), allow(unused))]
     AppReleaseChecked(Result<crate::metadata::Release, String>),
     BrowseDir(BrowseSubject),
     BrowseFile(BrowseFileSubject),
@@ -68,13 +76,35 @@ pub enum Message {
     OpenUrl(String),
     OpenUrlAndCloseModal(String),
     Refresh,
+    /// Show new media in all players.
+    Shuffle,
     SetPause(bool),
+    SetCategoryPause {
+        category: player::Category,
+        paused: bool,
+    },
     SetMute(bool),
     SetVolume {
         volume: f32,
     },
     SetSynchronized(bool),
+    SetLayoutLocked(bool),
+    SetSoundtrack {
+        path: Option<StrictPath>,
+    },
+    SetSoundtrackMuted(bool),
+    ImportFolderGrids {
+        path: StrictPath,
+    },
+    CreateFolderGrids {
+        folders: Vec<StrictPath>,
+    },
+    EvenOutLayout,
+    RandomizeLayout,
+    SetRotating(bool),
+    RotatePlaylist,
     SeekRandom,
+    SeekRelative(f64),
     Step(Step),
     Player {
         grid_id: grid::Id,
@@ -87,10 +117,18 @@ pub enum Message {
     ShowSettings,
     FindMedia,
     MediaScanned(Vec<media::Scan>),
+    MediaScanFinished,
     FileDragDrop(StrictPath),
     FileDragDropGridSelected(grid::Id),
+    FileDropTargets(Vec<(grid::Id, player::Id, Option<iced::Rectangle>)>),
+    CursorMoved(iced::Point),
     WindowFocused,
     WindowUnfocused,
+    WindowResized(iced::Size),
+    MouseActivity,
+    WindowMinimized,
+    WindowHidden,
+    WindowRestored,
     Pane {
         event: PaneEvent,
     },
@@ -103,6 +141,9 @@ pub enum Message {
     PlaylistLoad {
         path: StrictPath,
     },
+    PlaylistLoadRecent {
+        path: StrictPath,
+    },
     PlaylistSave,
     PlaylistSaveAs,
     PlaylistSavedAs {
@@ -111,12 +152,136 @@ pub enum Message {
     ShowMenu {
         show: Option<bool>,
     },
+    ReportIssue,
+    DeleteToTrash {
+        grid_id: grid::Id,
+        player_id: player::Id,
+        media: media::Media,
+    },
+    TransferMedia {
+        grid_id: grid::Id,
+        player_id: player::Id,
+        media: media::Media,
+        transfer: Transfer,
+        folder: StrictPath,
+    },
     Menu {
         message: Box<Self>,
     },
+    #[cfg(feature = "media-controls")]
+    MediaControls(crate::gui::media_controls::Event),
 }
 
 impl Message {
+    /// Whether this could change the layout or settings,
+    /// which is not allowed in kiosk mode.
+    pub fn is_editing(&self) -> bool {
+        match self {
+            Self::Ignore => false,
+            Self::Exit { .. } => false,
+            Self::Tick(_) => false,
+            #[cfg(feature = "audio")]
+            Self::CheckAudio => false,
+            #[cfg(feature = "battery")]
+            Self::CheckBattery => false,
+            #[cfg(feature = "battery")]
+            Self::BatteryChecked(_) => false,
+            Self::Save => false,
+            Self::CloseModal => false,
+            Self::Config { .. } => false,
+            Self::CheckAppRelease => false,
+            Self::AppReleaseChecked(_) => false,
+            Self::BrowseDir(_) => false,
+            Self::BrowseFile(_) => false,
+            Self::OpenDir { .. } => false,
+            Self::OpenFile { .. } => false,
+            Self::OpenPathFailure { .. } => false,
+            Self::OpenUrlFailure { .. } => false,
+            Self::KeyboardEvent(_) => false,
+            Self::UndoRedo(..) => false,
+            Self::OpenUrl(_) => false,
+            Self::OpenUrlAndCloseModal(_) => false,
+            Self::Refresh => false,
+            Self::Shuffle => false,
+            #[cfg(feature = "media-controls")]
+            Self::MediaControls(_) => false,
+            Self::SetPause(_) => false,
+            Self::SetCategoryPause { .. } => false,
+            Self::SetMute(_) => false,
+            Self::SetVolume { .. } => false,
+            Self::SetSynchronized(_) => false,
+            Self::SetLayoutLocked(_) => true,
+            Self::SetSoundtrack { .. } => true,
+            Self::SetSoundtrackMuted(_) => false,
+            Self::ImportFolderGrids { .. } => true,
+            Self::CreateFolderGrids { .. } => true,
+            Self::EvenOutLayout => true,
+            Self::RandomizeLayout => true,
+            Self::SetRotating(_) => true,
+            Self::RotatePlaylist => false,
+            Self::SeekRandom => false,
+            Self::SeekRelative(_) => false,
+            Self::Step(_) => false,
+            Self::Player { event, .. } => matches!(
+                event,
+                player::Event::Close
+                    | player::Event::DeleteToTrash
+                    | player::Event::Blacklist
+                    | player::Event::Transfer(_)
+            ),
+            Self::Modal { .. } => false,
+            Self::ShowSettings => true,
+            Self::FindMedia => false,
+            Self::MediaScanned(_) => false,
+            Self::MediaScanFinished => false,
+            Self::FileDragDrop(_) => true,
+            Self::FileDragDropGridSelected(_) => true,
+            Self::FileDropTargets(_) => true,
+            Self::CursorMoved(_) => false,
+            Self::WindowFocused => false,
+            Self::WindowUnfocused => false,
+            Self::WindowResized(_) => false,
+            Self::MouseActivity => false,
+            Self::WindowMinimized => false,
+            Self::WindowHidden => false,
+            Self::WindowRestored => false,
+            Self::Pane { event } => match event {
+                PaneEvent::Drag(_) => true,
+                PaneEvent::Resize(_) => true,
+                PaneEvent::Split { .. } => true,
+                PaneEvent::Duplicate { .. } => true,
+                PaneEvent::Close { .. } => true,
+                PaneEvent::AddPlayer { .. } => true,
+                PaneEvent::ShowSettings { .. } => true,
+                PaneEvent::ShowMedia { .. } => true,
+                PaneEvent::ShowControls { .. } => true,
+                PaneEvent::CloseControls => false,
+                PaneEvent::SetMute { .. } => false,
+                PaneEvent::SoloAudio { .. } => false,
+                PaneEvent::SetPause { .. } => false,
+                PaneEvent::SeekRandom { .. } => false,
+                PaneEvent::SeekRelative { .. } => false,
+                PaneEvent::Step { .. } => false,
+                PaneEvent::Refresh { .. } => false,
+                PaneEvent::ToggleMaximize { .. } => false,
+                PaneEvent::FreezeSources { .. } => true,
+                PaneEvent::UnfreezeSources { .. } => true,
+            },
+            Self::PlaylistReset { .. } => true,
+            Self::PlaylistSelect { .. } => true,
+            Self::PlaylistLoad { .. } => false,
+            Self::PlaylistLoadRecent { .. } => true,
+            Self::PlaylistSave => true,
+            Self::PlaylistSaveAs => true,
+            Self::PlaylistSavedAs { .. } => true,
+            Self::ShowMenu { .. } => true,
+            Self::ReportIssue => false,
+            Self::DeleteToTrash { .. } => true,
+            Self::TransferMedia { .. } => true,
+            Self::Menu { .. } => true,
+        }
+    }
+
     pub fn browsed_dir(subject: BrowseSubject, choice: Option<std::path::PathBuf>) -> Self {
         match choice {
             Some(path) => match subject {
@@ -125,6 +290,19 @@ impl Message {
                         action: EditAction::Change(index, crate::path::render_pathbuf(&path)),
                     },
                 },
+                BrowseSubject::FallbackSource => Self::Modal {
+                    event: modal::Event::EditedFallbackSource {
+                        raw: crate::path::render_pathbuf(&path),
+                    },
+                },
+                BrowseSubject::TransferDestination => Self::Modal {
+                    event: modal::Event::ChoseTransferDestination {
+                        folder: StrictPath::from(path),
+                    },
+                },
+                BrowseSubject::FolderGrids => Self::ImportFolderGrids {
+                    path: StrictPath::from(path),
+                },
             },
             None => Self::Ignore,
         }
@@ -149,6 +327,9 @@ impl Message {
                         }
                     }
                 }
+                BrowseFileSubject::Soundtrack => Self::SetSoundtrack {
+                    path: Some(StrictPath::from(path)),
+                },
             },
             None => Self::Ignore,
         }
@@ -194,30 +375,55 @@ impl EditDirection {
     }
 }
 
+/// Whether to move or copy a file to another folder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transfer {
+    Move,
+    Copy,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BrowseSubject {
     Source { index: usize },
+    FallbackSource,
+    TransferDestination,
+    FolderGrids,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BrowseFileSubject {
     Source { index: usize },
     Playlist { save: bool },
+    Soundtrack,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum UndoSubject {
     ImageDuration,
+    SeekStepImage,
+    SeekStepAudio,
+    SeekStepVideo,
     Source { index: usize },
+    FallbackSource,
     OrientationLimit,
+    GridImageDuration,
+    GridClipLength,
+    GridName,
 }
 
 impl UndoSubject {
     pub fn view_with<'a>(self, histories: &TextHistories) -> Element<'a> {
         match self {
             Self::ImageDuration => self.view(&histories.image_duration.current()),
+            Self::SeekStepImage => self.view(&histories.seek_step_image.current()),
+            Self::SeekStepAudio => self.view(&histories.seek_step_audio.current()),
+            Self::SeekStepVideo => self.view(&histories.seek_step_video.current()),
             Self::Source { .. } => self.view(""),
+            Self::FallbackSource => self.view(""),
             Self::OrientationLimit { .. } => self.view(""),
+            Self::GridImageDuration => self.view(""),
+            Self::GridClipLength => self.view(""),
+            Self::GridName => self.view(""),
         }
     }
 
@@ -226,28 +432,63 @@ impl UndoSubject {
             UndoSubject::ImageDuration => Box::new(move |value| Message::Config {
                 event: config::Event::ImageDurationRaw(value),
             }),
+            UndoSubject::SeekStepImage => Box::new(move |value| Message::Config {
+                event: config::Event::SeekStepImageRaw(value),
+            }),
+            UndoSubject::SeekStepAudio => Box::new(move |value| Message::Config {
+                event: config::Event::SeekStepAudioRaw(value),
+            }),
+            UndoSubject::SeekStepVideo => Box::new(move |value| Message::Config {
+                event: config::Event::SeekStepVideoRaw(value),
+            }),
             UndoSubject::Source { index } => Box::new(move |value| Message::Modal {
                 event: modal::Event::EditedSource {
                     action: EditAction::Change(index, value),
                 },
             }),
+            UndoSubject::FallbackSource => Box::new(move |value| Message::Modal {
+                event: modal::Event::EditedFallbackSource { raw: value },
+            }),
             UndoSubject::OrientationLimit => Box::new(move |value| Message::Modal {
                 event: modal::Event::EditedGridOrientationLimit { raw_limit: value },
             }),
+            UndoSubject::GridImageDuration => Box::new(move |value| Message::Modal {
+                event: modal::Event::EditedGridImageDuration { raw_duration: value },
+            }),
+            UndoSubject::GridClipLength => Box::new(move |value| Message::Modal {
+                event: modal::Event::EditedGridClipLength { raw_length: value },
+            }),
+            UndoSubject::GridName => Box::new(move |value| Message::Modal {
+                event: modal::Event::EditedGridName { name: value },
+            }),
         };
 
         let placeholder = "";
 
         let icon = match self {
             UndoSubject::ImageDuration => (current.parse::<NonZeroUsize>().is_err()).then_some(ERROR_ICON),
+            UndoSubject::SeekStepImage => (current.parse::<NonZeroUsize>().is_err()).then_some(ERROR_ICON),
+            UndoSubject::SeekStepAudio => (current.parse::<NonZeroUsize>().is_err()).then_some(ERROR_ICON),
+            UndoSubject::SeekStepVideo => (current.parse::<NonZeroUsize>().is_err()).then_some(ERROR_ICON),
             UndoSubject::Source { .. } => (!path_appears_valid(current)).then_some(ERROR_ICON),
+            UndoSubject::FallbackSource => (!path_appears_valid(current)).then_some(ERROR_ICON),
             UndoSubject::OrientationLimit => (current.parse::<NonZeroUsize>().is_err()).then_some(ERROR_ICON),
+            UndoSubject::GridImageDuration => (current.parse::<NonZeroUsize>().is_err()).then_some(ERROR_ICON),
+            UndoSubject::GridClipLength => (current.parse::<NonZeroUsize>().is_err()).then_some(ERROR_ICON),
+            UndoSubject::GridName => None,
         };
 
         let width = match self {
             UndoSubject::ImageDuration => Length::Fixed(80.0),
+            UndoSubject::SeekStepImage => Length::Fixed(80.0),
+            UndoSubject::SeekStepAudio => Length::Fixed(80.0),
+            UndoSubject::SeekStepVideo => Length::Fixed(80.0),
             UndoSubject::Source { .. } => Length::Fill,
+            UndoSubject::FallbackSource => Length::Fill,
             UndoSubject::OrientationLimit => Length::Fixed(80.0),
+            UndoSubject::GridImageDuration => Length::Fixed(80.0),
+            UndoSubject::GridClipLength => Length::Fixed(80.0),
+            UndoSubject::GridName => Length::Fill,
         };
 
         Undoable::new(
@@ -279,6 +520,7 @@ pub enum PaneEvent {
     Drag(pane_grid::DragEvent),
     Resize(pane_grid::ResizeEvent),
     Split { grid_id: grid::Id, axis: pane_grid::Axis },
+    Duplicate { grid_id: grid::Id },
     Close { grid_id: grid::Id },
     AddPlayer { grid_id: grid::Id },
     ShowSettings { grid_id: grid::Id },
@@ -286,10 +528,15 @@ pub enum PaneEvent {
     ShowControls { grid_id: grid::Id },
     CloseControls,
     SetMute { grid_id: grid::Id, muted: bool },
+    SoloAudio { grid_id: grid::Id },
     SetPause { grid_id: grid::Id, paused: bool },
     SeekRandom { grid_id: grid::Id },
+    SeekRelative { grid_id: grid::Id, position: f64 },
     Step { grid_id: grid::Id, step: Step },
     Refresh { grid_id: grid::Id },
+    ToggleMaximize { grid_id: grid::Id },
+    FreezeSources { grid_id: grid::Id },
+    UnfreezeSources { grid_id: grid::Id },
 }
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -299,6 +546,13 @@ pub struct Selection {
 }
 
 impl Selection {
+    pub fn new(grid: grid::Id, player: Option<player::Id>) -> Self {
+        Self {
+            grid: Some(grid),
+            player,
+        }
+    }
+
     pub fn is_any_selected(&self) -> bool {
         self.grid.is_some() || self.player.is_some()
     }
@@ -1,4 +1,4 @@
-use std::{collections::HashSet, time::Duration};
+use std::{collections::HashSet, num::NonZeroUsize, time::Duration};
 
 use iced::{
     alignment, padding,
@@ -9,23 +9,30 @@ use iced::{
 use crate::{
     gui::{
         button,
-        common::{Message, PaneEvent, Step},
+        common::{Message, PaneEvent, Step, Transfer},
         icon::Icon,
-        player::{self, Player},
+        player::{self, Player, ViewOptions},
         style,
-        widget::{Column, Container, Element, Row, Stack},
+        widget::{text, Column, Container, Element, Row, Stack},
     },
     lang,
     media::{self, Media},
-    prelude::Change,
+    prelude::{Change, StrictPath},
     resource::{
         config::Playback,
-        playlist::{ContentFit, Orientation, OrientationLimit},
+        playlist::{AspectOverride, ContentFit, DisplayFilter, FilterMethod, OnEnd, Orientation, OrientationLimit},
     },
 };
 
 pub type Id = pane_grid::Pane;
 
+/// Clip length to suggest when first enabling the setting, in seconds.
+pub const DEFAULT_CLIP_LENGTH: usize = 30;
+
+/// Opacity percentages allowed for a grid's media.
+pub const MIN_OPACITY: u8 = 10;
+pub const MAX_OPACITY: u8 = 100;
+
 #[derive(Debug)]
 pub enum Error {
     NoMediaAvailable,
@@ -41,22 +48,92 @@ pub enum Event {
 
 #[derive(Debug, Clone)]
 pub enum Update {
-    PauseChanged { category: player::Category, paused: bool },
+    PauseChanged {
+        category: player::Category,
+        paused: bool,
+    },
     MuteChanged,
-    RelativePositionChanged { category: player::Category, position: f64 },
-    Step { category: player::Category, step: Step },
+    RelativePositionChanged {
+        category: player::Category,
+        position: f64,
+    },
+    Step {
+        category: player::Category,
+        step: Step,
+    },
+    PlayerStopped,
     PlayerClosed,
+    DeleteToTrash {
+        player_id: player::Id,
+        media: Media,
+    },
+    Blacklist {
+        player_id: player::Id,
+        media: Media,
+    },
+    Transfer {
+        player_id: player::Id,
+        media: Media,
+        transfer: Transfer,
+    },
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Settings {
+    pub name: Option<String>,
     pub sources: Vec<media::Source>,
+    /// Where to find media for tiles that the main sources can't fill.
+    pub fallback_source: Option<media::Source>,
     pub content_fit: ContentFit,
+    pub filter_method: FilterMethod,
     pub orientation: Orientation,
     pub orientation_limit: OrientationLimit,
+    pub aspect_override: AspectOverride,
+    pub image_duration: Option<NonZeroUsize>,
+    /// Play a random part of audio and videos for this many seconds.
+    pub clip_length: Option<NonZeroUsize>,
+    pub filter: DisplayFilter,
+    pub on_end: Option<OnEnd>,
+    pub show_counter: bool,
+    pub allow_duplicates_on_screen: bool,
+    pub shuffle_on_focus: bool,
+    pub cycle_in_order: bool,
+    pub opacity: u8,
+    /// Another grid whose media this grid shows instead of choosing its own.
+    pub mirror_of: Option<Id>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            name: None,
+            sources: vec![],
+            fallback_source: None,
+            content_fit: Default::default(),
+            filter_method: Default::default(),
+            orientation: Default::default(),
+            orientation_limit: Default::default(),
+            aspect_override: Default::default(),
+            image_duration: None,
+            clip_length: None,
+            filter: Default::default(),
+            on_end: None,
+            show_counter: false,
+            allow_duplicates_on_screen: true,
+            shuffle_on_focus: false,
+            cycle_in_order: false,
+            opacity: 100,
+            mirror_of: None,
+        }
+    }
 }
 
 impl Settings {
+    /// Set the name, treating a blank name as unset.
+    pub fn set_name(&mut self, name: String) {
+        self.name = (!name.trim().is_empty()).then_some(name);
+    }
+
     pub fn with_source(mut self, source: media::Source) -> Self {
         self.sources.push(source);
         self
@@ -66,15 +143,122 @@ impl Settings {
         self.sources.extend(sources);
         self
     }
+
+    /// All of the sources that need to be scanned, including the fallback.
+    pub fn all_sources(&self) -> Vec<media::Source> {
+        self.sources.iter().chain(&self.fallback_source).cloned().collect()
+    }
+
+    /// Take the layout from other settings,
+    /// but keep anything specific to this grid (like the sources).
+    pub fn with_layout_of(&self, other: &Settings) -> Self {
+        Self {
+            name: self.name.clone(),
+            sources: self.sources.clone(),
+            fallback_source: self.fallback_source.clone(),
+            mirror_of: self.mirror_of,
+            ..other.clone()
+        }
+    }
+}
+
+/// Another grid that can be chosen for a grid to mirror.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MirrorOption {
+    pub grid_id: Id,
+    pub label: String,
+}
+
+impl ToString for MirrorOption {
+    fn to_string(&self) -> String {
+        self.label.clone()
+    }
+}
+
+/// A bundle of layout settings for common use cases.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Preset {
+    /// Many small, cropped players that switch images quickly.
+    ContactSheet,
+    /// A single player that shows the whole media.
+    SingleFocus,
+    /// Rows of widescreen players that fill their space.
+    VideoWall,
+}
+
+impl Preset {
+    pub const ALL: &'static [Self] = &[Self::ContactSheet, Self::SingleFocus, Self::VideoWall];
+
+    /// Apply the preset on top of some existing settings,
+    /// keeping anything that the preset doesn't cover (like the sources).
+    pub fn settings(&self, base: &Settings) -> Settings {
+        match self {
+            Self::ContactSheet => Settings {
+                content_fit: ContentFit::Crop,
+                orientation: Orientation::Horizontal,
+                orientation_limit: OrientationLimit::Fixed(NonZeroUsize::new(6).unwrap()),
+                aspect_override: AspectOverride::Original,
+                image_duration: Some(NonZeroUsize::new(3).unwrap()),
+                ..base.clone()
+            },
+            Self::SingleFocus => Settings {
+                content_fit: ContentFit::Scale,
+                orientation: Orientation::Horizontal,
+                orientation_limit: OrientationLimit::Automatic,
+                aspect_override: AspectOverride::Original,
+                image_duration: None,
+                ..base.clone()
+            },
+            Self::VideoWall => Settings {
+                content_fit: ContentFit::Crop,
+                orientation: Orientation::Horizontal,
+                orientation_limit: OrientationLimit::Fixed(NonZeroUsize::new(3).unwrap()),
+                aspect_override: AspectOverride::Widescreen,
+                image_duration: None,
+                ..base.clone()
+            },
+        }
+    }
+}
+
+impl ToString for Preset {
+    fn to_string(&self) -> String {
+        match self {
+            Self::ContactSheet => lang::state::contact_sheet(),
+            Self::SingleFocus => lang::state::single_focus(),
+            Self::VideoWall => lang::state::video_wall(),
+        }
+    }
 }
 
 #[derive(Default)]
 pub struct Grid {
+    name: Option<String>,
     sources: Vec<media::Source>,
+    fallback_source: Option<media::Source>,
     players: Vec<Player>,
     content_fit: ContentFit,
+    filter_method: FilterMethod,
     orientation: Orientation,
     orientation_limit: OrientationLimit,
+    aspect_override: AspectOverride,
+    image_duration: Option<NonZeroUsize>,
+    clip_length: Option<NonZeroUsize>,
+    filter: DisplayFilter,
+    on_end: Option<OnEnd>,
+    show_counter: bool,
+    allow_duplicates_on_screen: bool,
+    shuffle_on_focus: bool,
+    cycle_in_order: bool,
+    opacity: u8,
+    mirror_of: Option<Id>,
+    /// The sources from before globs were frozen into a file list,
+    /// so that the change can be undone.
+    sources_before_freeze: Option<Vec<media::Source>>,
+    /// How much media is available from the sources, as of the last `update_media_total`.
+    media_total: usize,
+    /// Keep every player muted, such as while a soundtrack is playing.
+    force_muted: bool,
 }
 
 impl Grid {
@@ -86,28 +270,72 @@ impl Grid {
         };
 
         Self {
+            name: settings.name.clone(),
             sources: settings.sources.clone(),
+            fallback_source: settings.fallback_source.clone(),
             players,
             content_fit: settings.content_fit,
+            filter_method: settings.filter_method,
             orientation: settings.orientation,
             orientation_limit: settings.orientation_limit,
+            aspect_override: settings.aspect_override,
+            image_duration: settings.image_duration,
+            clip_length: settings.clip_length,
+            filter: settings.filter,
+            on_end: settings.on_end,
+            show_counter: settings.show_counter,
+            allow_duplicates_on_screen: settings.allow_duplicates_on_screen,
+            shuffle_on_focus: settings.shuffle_on_focus,
+            cycle_in_order: settings.cycle_in_order,
+            opacity: settings.opacity,
+            mirror_of: settings.mirror_of,
+            sources_before_freeze: None,
+            media_total: 0,
+            force_muted: false,
         }
     }
 
     pub fn new_with_players(settings: &Settings, players: usize) -> Self {
         Self {
+            name: settings.name.clone(),
             sources: settings.sources.clone(),
+            fallback_source: settings.fallback_source.clone(),
             players: std::iter::repeat_with(Player::default).take(players).collect(),
             content_fit: settings.content_fit,
+            filter_method: settings.filter_method,
             orientation: settings.orientation,
             orientation_limit: settings.orientation_limit,
+            aspect_override: settings.aspect_override,
+            image_duration: settings.image_duration,
+            clip_length: settings.clip_length,
+            filter: settings.filter,
+            on_end: settings.on_end,
+            show_counter: settings.show_counter,
+            allow_duplicates_on_screen: settings.allow_duplicates_on_screen,
+            shuffle_on_focus: settings.shuffle_on_focus,
+            cycle_in_order: settings.cycle_in_order,
+            opacity: settings.opacity,
+            mirror_of: settings.mirror_of,
+            sources_before_freeze: None,
+            media_total: 0,
+            force_muted: false,
         }
     }
 
     fn playback(&self, playback: &Playback) -> Playback {
-        playback
+        let playback = playback
             .with_paused_maybe(self.all_paused())
             .with_muted_maybe(self.all_muted())
+            .with_image_duration_maybe(self.image_duration)
+            .with_clip_length(self.clip_length)
+            .with_display_filter(self.filter)
+            .with_on_end_maybe(self.on_end);
+
+        if self.force_muted {
+            playback.with_muted(true)
+        } else {
+            playback
+        }
     }
 
     pub fn is_idle(&self) -> bool {
@@ -122,7 +350,7 @@ impl Grid {
             .iter_mut()
             .enumerate()
             .rev()
-            .map(|(index, player)| (index, player.tick(elapsed)))
+            .map(|(index, player)| (index, player.tick(elapsed, &playback)))
             .collect();
 
         for (index, update) in updates {
@@ -132,23 +360,54 @@ impl Grid {
                     player::Update::MuteChanged => {}
                     player::Update::RelativePositionChanged(_) => {}
                     player::Update::Step { .. } => {}
-                    player::Update::EndOfStream => {
-                        let media = collection.one_new(&self.sources, self.active_media());
-                        let player = &mut self.players[index];
+                    player::Update::EndOfStream if self.mirror_of.is_some() => {}
+                    player::Update::EndOfStream => match playback.on_end {
+                        OnEnd::SwapRandom => {
+                            let current = self.players[index].media().cloned();
+                            let media = collection.one_next(
+                                &self.sources,
+                                self.fallback_source.as_ref(),
+                                current.as_ref(),
+                                self.active_media(),
+                                !self.allow_duplicates_on_screen,
+                                self.cycle_in_order,
+                            );
+                            let player = &mut self.players[index];
 
-                        match media {
-                            Some(media) => {
-                                if player.swap_media(&media, &playback).is_err() {
-                                    collection.mark_error(&media);
+                            match media {
+                                Some(media) => {
+                                    if player.swap_media(&media, &playback).is_err() {
+                                        collection.mark_error(&media);
+                                    }
+                                }
+                                None => {
+                                    player.restart();
                                 }
-                            }
-                            None => {
-                                player.restart();
                             }
                         }
-                    }
+                        OnEnd::Loop => {
+                            self.players[index].restart();
+                        }
+                        OnEnd::Stop => {
+                            self.players[index].go_idle();
+                        }
+                    },
                     player::Update::Refresh => {}
+                    player::Update::Stop => {}
+                    player::Update::DeleteToTrash(_) => {}
+                    player::Update::Blacklist(_) => {}
+                    player::Update::Transfer { .. } => {}
                     player::Update::Close => {}
+                    player::Update::Retry => {
+                        let player = &mut self.players[index];
+                        let media = player.media().cloned();
+
+                        if player.retry(&playback).is_ok() {
+                            if let Some(media) = media {
+                                collection.clear_error(&media);
+                            }
+                        }
+                    }
                 }
             }
         }
@@ -184,6 +443,23 @@ impl Grid {
         relevant.then_some(true)
     }
 
+    pub fn category_paused(&self, category: player::Category) -> Option<bool> {
+        let mut relevant = false;
+        for player in self.players.iter().filter(|player| player.category() == category) {
+            match player.is_paused() {
+                Some(true) => {
+                    relevant = true;
+                }
+                Some(false) => {
+                    return Some(false);
+                }
+                None => {}
+            }
+        }
+
+        relevant.then_some(true)
+    }
+
     pub fn all_muted(&self) -> Option<bool> {
         let mut relevant = false;
         for player in &self.players {
@@ -205,12 +481,34 @@ impl Grid {
         self.players.iter().any(|player| player.can_jump())
     }
 
+    pub fn can_seek(&self) -> bool {
+        self.players.iter().any(|player| player.can_seek())
+    }
+
+    pub fn is_progressing(&self) -> bool {
+        self.players.iter().any(|player| player.is_progressing())
+    }
+
     pub fn settings(&self) -> Settings {
         Settings {
+            name: self.name.clone(),
             sources: self.sources.clone(),
+            fallback_source: self.fallback_source.clone(),
             content_fit: self.content_fit,
+            filter_method: self.filter_method,
             orientation: self.orientation,
             orientation_limit: self.orientation_limit,
+            aspect_override: self.aspect_override,
+            image_duration: self.image_duration,
+            clip_length: self.clip_length,
+            filter: self.filter,
+            on_end: self.on_end,
+            show_counter: self.show_counter,
+            allow_duplicates_on_screen: self.allow_duplicates_on_screen,
+            shuffle_on_focus: self.shuffle_on_focus,
+            cycle_in_order: self.cycle_in_order,
+            opacity: self.opacity,
+            mirror_of: self.mirror_of,
         }
     }
 
@@ -225,24 +523,226 @@ impl Grid {
         }
 
         let Settings {
+            name,
             sources,
+            fallback_source,
             content_fit,
+            filter_method,
             orientation,
             orientation_limit,
+            aspect_override,
+            image_duration,
+            clip_length,
+            filter,
+            on_end,
+            show_counter,
+            allow_duplicates_on_screen,
+            shuffle_on_focus,
+            cycle_in_order,
+            opacity,
+            mirror_of,
         } = settings;
 
+        if self.sources != sources {
+            self.sources_before_freeze = None;
+        }
+
+        self.name = name;
         self.sources = sources;
+        self.fallback_source = fallback_source;
         self.content_fit = content_fit;
+        self.filter_method = filter_method;
         self.orientation = orientation;
         self.orientation_limit = orientation_limit;
+        self.aspect_override = aspect_override;
+        self.image_duration = image_duration;
+        self.clip_length = clip_length;
+        self.filter = filter;
+        self.on_end = on_end;
+        self.show_counter = show_counter;
+        self.allow_duplicates_on_screen = allow_duplicates_on_screen;
+        self.shuffle_on_focus = shuffle_on_focus;
+        self.cycle_in_order = cycle_in_order;
+        self.opacity = opacity;
+        self.mirror_of = mirror_of;
 
         Change::Different
     }
 
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn mirror_of(&self) -> Option<Id> {
+        self.mirror_of
+    }
+
+    pub fn set_mirror_of(&mut self, mirror_of: Option<Id>) {
+        self.mirror_of = mirror_of;
+    }
+
+    /// While forced, the players stay muted even if something tries to unmute them.
+    /// Once released, they go back to following `playback`.
+    pub fn set_force_muted(&mut self, forced: bool, collection: &mut media::Collection, playback: &Playback) {
+        if self.force_muted == forced {
+            return;
+        }
+
+        self.force_muted = forced;
+        self.update_all_players(
+            player::Event::SetMute(forced || playback.muted),
+            collection,
+            &playback.with_muted(forced || playback.muted),
+        );
+    }
+
+    /// The media in each player, in order.
+    pub fn media_list(&self) -> Vec<Option<Media>> {
+        self.players.iter().map(|player| player.media().cloned()).collect()
+    }
+
+    /// Show the same media as another grid's `media_list`,
+    /// adding or removing players to match.
+    pub fn mirror(&mut self, media_list: &[Option<Media>], collection: &mut media::Collection, playback: &Playback) {
+        let playback = self.playback(playback);
+
+        self.players.truncate(media_list.len());
+
+        for (index, media) in media_list.iter().enumerate() {
+            if index >= self.players.len() {
+                self.players.push(Player::default());
+            } else if self.players[index].media() == media.as_ref() {
+                continue;
+            }
+
+            let player = &mut self.players[index];
+            match media {
+                Some(media) => {
+                    if player.swap_media(media, &playback).is_err() {
+                        collection.mark_error(media);
+                    }
+                }
+                None => {
+                    player.go_idle();
+                }
+            }
+        }
+    }
+
+    pub fn can_freeze_sources(&self) -> bool {
+        self.sources
+            .iter()
+            .any(|source| source.kind() == media::SourceKind::Glob)
+    }
+
+    pub fn can_unfreeze_sources(&self) -> bool {
+        self.sources_before_freeze.is_some()
+    }
+
+    /// Replace each glob source with path sources for the files that it currently matches.
+    /// The new sources are registered in the collection right away,
+    /// so that the players can keep their current media.
+    /// When there's a playlist, the paths are relative to it,
+    /// so that the playlist can be moved along with its media.
+    #[must_use]
+    pub fn freeze_sources(&mut self, collection: &mut media::Collection, playlist: Option<&StrictPath>) -> Change {
+        if !self.can_freeze_sources() {
+            return Change::Same;
+        }
+
+        let mut frozen = vec![];
+        let mut seen = HashSet::new();
+        for source in &self.sources {
+            match source {
+                media::Source::Path { .. } => {
+                    if seen.insert(source.clone()) {
+                        frozen.push(source.clone());
+                    }
+                }
+                media::Source::Glob { .. } => {
+                    let matched: Vec<_> = collection
+                        .all_for_sources(std::slice::from_ref(source))
+                        .into_iter()
+                        .cloned()
+                        .collect();
+
+                    for media in matched {
+                        let path = media::Source::new_path_in_playlist(media.path().clone(), playlist);
+                        collection.insert(path.clone(), media);
+                        if seen.insert(path.clone()) {
+                            frozen.push(path);
+                        }
+                    }
+                }
+            }
+        }
+
+        self.sources_before_freeze = Some(std::mem::replace(&mut self.sources, frozen));
+        Change::Different
+    }
+
+    /// Undo `freeze_sources`, returning the restored sources so that they can be scanned again.
+    pub fn unfreeze_sources(&mut self) -> Option<Vec<media::Source>> {
+        let sources = self.sources_before_freeze.take()?;
+        self.sources = sources.clone();
+        Some(sources)
+    }
+
     pub fn sources(&self) -> &[media::Source] {
         &self.sources
     }
 
+    pub fn fallback_source(&self) -> Option<&media::Source> {
+        self.fallback_source.as_ref()
+    }
+
+    /// All of the sources that need to be scanned, including the fallback.
+    pub fn all_sources(&self) -> Vec<media::Source> {
+        self.sources.iter().chain(&self.fallback_source).cloned().collect()
+    }
+
+    /// Whether the grid has sources, but none of them have produced any media.
+    pub fn is_without_media(&self, collection: &media::Collection) -> bool {
+        self.mirror_of.is_none()
+            && !self.sources.is_empty()
+            && self.players.iter().all(|player| player.media().is_none())
+            && collection.all_for_sources(&self.sources).is_empty()
+    }
+
+    /// How many files in this grid's sources were skipped for exceeding the size limit.
+    pub fn total_too_large(&self, collection: &media::Collection) -> usize {
+        collection.total_too_large(&self.sources)
+    }
+
+    /// Summary of how many players are active out of the available media,
+    /// if enabled for the title bar.
+    /// Count the available media again after the collection or sources have changed,
+    /// so that `media_counter` doesn't need to do it on every view.
+    pub fn update_media_total(&mut self, collection: &media::Collection) {
+        self.media_total = if self.show_counter {
+            collection.all_for_sources(&self.sources).len()
+        } else {
+            0
+        };
+    }
+
+    pub fn media_counter(&self) -> Option<String> {
+        if !self.show_counter || self.sources.is_empty() {
+            return None;
+        }
+
+        let active = self.players.iter().filter(|player| player.media().is_some()).count();
+        Some(lang::tell::active_players_of_total_media(active, self.media_total))
+    }
+
+    pub fn allows_duplicates_on_screen(&self) -> bool {
+        self.allow_duplicates_on_screen
+    }
+
+    pub fn shuffles_on_focus(&self) -> bool {
+        self.shuffle_on_focus
+    }
+
     pub fn active_media(&self) -> HashSet<&Media> {
         self.players.iter().filter_map(|x| x.media()).collect()
     }
@@ -255,6 +755,10 @@ impl Grid {
         self.players.len()
     }
 
+    pub fn active_players(&self) -> usize {
+        self.players.iter().filter(|player| player.media().is_some()).count()
+    }
+
     pub fn player_ids(&self) -> Vec<player::Id> {
         self.players
             .iter()
@@ -263,9 +767,31 @@ impl Grid {
             .collect()
     }
 
+    /// Load each player's current media again from scratch,
+    /// such as when the display filter changes.
+    pub fn reload_players(&mut self, collection: &mut media::Collection, playback: &Playback) {
+        let playback = self.playback(playback);
+
+        for player in self.players.iter_mut() {
+            let Some(media) = player.media().cloned() else {
+                continue;
+            };
+
+            if player.swap_media(&media, &playback).is_err() {
+                collection.mark_error(&media);
+            }
+        }
+    }
+
     pub fn refresh(&mut self, collection: &mut media::Collection, playback: &Playback, context: media::RefreshContext) {
+        // Mirrors get their media from the other grid instead.
+        if self.mirror_of.is_some() {
+            return;
+        }
+
         let playback = self.playback(playback);
         let mut active: HashSet<_> = self.active_media().into_iter().cloned().collect();
+        let all_sources = self.all_sources();
         let force = match context {
             media::RefreshContext::Launch => false,
             media::RefreshContext::Edit => false,
@@ -282,7 +808,7 @@ impl Grid {
             let old_media = player.media();
             let refresh = force
                 || old_media
-                    .map(|old_media| collection.is_outdated(old_media, &self.sources))
+                    .map(|old_media| collection.is_outdated(old_media, &all_sources))
                     .unwrap_or(true)
                 || player.is_error();
 
@@ -291,7 +817,14 @@ impl Grid {
                     active.remove(old_media);
                 }
 
-                match collection.one_new(&self.sources, active.iter().collect()) {
+                match collection.one_next(
+                    &self.sources,
+                    self.fallback_source.as_ref(),
+                    old_media,
+                    active.iter().collect(),
+                    !self.allow_duplicates_on_screen,
+                    self.cycle_in_order,
+                ) {
                     Some(new_media) => {
                         if player.swap_media(&new_media, &playback).is_err() {
                             collection.mark_error(&new_media);
@@ -307,9 +840,16 @@ impl Grid {
     }
 
     pub fn add_player(&mut self, collection: &mut media::Collection, playback: &Playback) -> Result<(), Error> {
-        let playback = self.playback(playback);
-
-        let Some(media) = collection.one_new(&self.sources, self.active_media()) else {
+        let playback = self.playback(playback).for_new_player();
+
+        let Some(media) = collection.one_next(
+            &self.sources,
+            self.fallback_source.as_ref(),
+            self.players.last().and_then(|player| player.media()),
+            self.active_media(),
+            !self.allow_duplicates_on_screen,
+            self.cycle_in_order,
+        ) else {
             return Err(Error::NoMediaAvailable);
         };
 
@@ -327,7 +867,7 @@ impl Grid {
     }
 
     pub fn add_player_with_media(&mut self, media: Media, collection: &mut media::Collection, playback: &Playback) {
-        let playback = self.playback(playback);
+        let playback = self.playback(playback).for_new_player();
 
         match Player::new(&media, &playback) {
             Ok(player) => {
@@ -340,6 +880,48 @@ impl Grid {
         }
     }
 
+    /// Approximate memory used by loaded images, in bytes.
+    pub fn estimated_memory(&self) -> usize {
+        self.players.iter().map(|player| player.estimated_memory()).sum()
+    }
+
+    /// Free a player's media without removing the player,
+    /// returning the media and the approximate number of bytes freed.
+    pub fn unload_player(&mut self, player_id: player::Id) -> Option<(Media, usize)> {
+        let player = self.players.get_mut(player_id.0)?;
+        let media = player.media().cloned()?;
+        let freed = player.estimated_memory();
+
+        player.go_idle();
+        Some((media, freed))
+    }
+
+    /// Replace what a specific player is showing.
+    pub fn swap_player_media(
+        &mut self,
+        player_id: player::Id,
+        media: &Media,
+        collection: &mut media::Collection,
+        playback: &Playback,
+    ) {
+        let playback = self.playback(playback);
+
+        let Some(player) = self.players.get_mut(player_id.0) else {
+            return;
+        };
+
+        if player.swap_media(media, &playback).is_err() {
+            collection.mark_error(media);
+        }
+    }
+
+    pub fn hovered_player(&self) -> Option<player::Id> {
+        self.players
+            .iter()
+            .position(|player| player.is_hovered())
+            .map(player::Id)
+    }
+
     pub fn player(&self, player_id: player::Id) -> Option<&Player> {
         self.players.get(player_id.0)
     }
@@ -362,6 +944,10 @@ impl Grid {
 
         match event {
             Event::Player { player_id, event } => {
+                if self.force_muted && matches!(event, player::Event::SetMute(false)) {
+                    return None;
+                }
+
                 let active_media: HashSet<_> = self.active_media().into_iter().cloned().collect();
                 let player = self.players.get_mut(player_id.0)?;
                 let category = player.category();
@@ -391,26 +977,53 @@ impl Grid {
                             self.synchronize_players(Some(player_id), category, player::Event::Step(step), &playback);
                             Some(Update::Step { category, step })
                         }
-                        player::Update::EndOfStream => {
-                            let media = collection.one_new(&self.sources, active_media.iter().collect());
-
-                            match media {
-                                Some(media) => {
-                                    if player.swap_media(&media, &playback).is_err() {
-                                        collection.mark_error(&media);
+                        player::Update::EndOfStream if self.mirror_of.is_some() => None,
+                        player::Update::EndOfStream => match playback.on_end {
+                            OnEnd::SwapRandom => {
+                                let current = player.media().cloned();
+                                let media = collection.one_next(
+                                    &self.sources,
+                                    self.fallback_source.as_ref(),
+                                    current.as_ref(),
+                                    active_media.iter().collect(),
+                                    !self.allow_duplicates_on_screen,
+                                    self.cycle_in_order,
+                                );
+
+                                match media {
+                                    Some(media) => {
+                                        if player.swap_media(&media, &playback).is_err() {
+                                            collection.mark_error(&media);
+                                        }
+                                    }
+                                    None => {
+                                        player.restart();
                                     }
                                 }
-                                None => {
-                                    player.restart();
-                                }
-                            }
 
-                            None
-                        }
+                                None
+                            }
+                            OnEnd::Loop => {
+                                player.restart();
+                                None
+                            }
+                            OnEnd::Stop => {
+                                player.go_idle();
+                                Some(Update::PlayerStopped)
+                            }
+                        },
                         player::Update::Refresh => {
                             let failed = player.is_error();
 
-                            let media = collection.one_new(&self.sources, active_media.iter().collect());
+                            let current = player.media().cloned();
+                            let media = collection.one_next(
+                                &self.sources,
+                                self.fallback_source.as_ref(),
+                                current.as_ref(),
+                                active_media.iter().collect(),
+                                !self.allow_duplicates_on_screen,
+                                self.cycle_in_order,
+                            );
 
                             match media {
                                 Some(media) => {
@@ -430,10 +1043,29 @@ impl Grid {
 
                             None
                         }
+                        player::Update::Stop => Some(Update::PlayerStopped),
+                        player::Update::DeleteToTrash(media) => Some(Update::DeleteToTrash { player_id, media }),
+                        player::Update::Blacklist(media) => Some(Update::Blacklist { player_id, media }),
+                        player::Update::Transfer { media, transfer } => Some(Update::Transfer {
+                            player_id,
+                            media,
+                            transfer,
+                        }),
                         player::Update::Close => {
                             self.remove(player_id);
                             Some(Update::PlayerClosed)
                         }
+                        player::Update::Retry => {
+                            let media = player.media().cloned();
+
+                            if player.retry(&playback).is_ok() {
+                                if let Some(media) = media {
+                                    collection.clear_error(&media);
+                                }
+                            }
+
+                            None
+                        }
                     },
                     None => None,
                 }
@@ -493,8 +1125,13 @@ impl Grid {
         selected_player: Option<player::Id>,
         obscured: bool,
         dragging_file: bool,
+        no_media: bool,
+        too_large: usize,
+        options: ViewOptions,
+        collection: &media::Collection,
     ) -> Element {
         let obscured = obscured || dragging_file;
+        let all_sources = if options.show_info { self.all_sources() } else { vec![] };
 
         let mut row = Row::new().spacing(5);
         let mut column = Column::new().spacing(5);
@@ -507,14 +1144,28 @@ impl Grid {
         for (i, player) in self.players.iter().enumerate() {
             let player_id = player::Id(i);
             let selected_player = selected_player == Some(player_id);
+            let source = options
+                .show_info
+                .then(|| {
+                    player
+                        .media()
+                        .and_then(|media| collection.source_of(media, &all_sources))
+                })
+                .flatten()
+                .map(|source| source.raw().to_string());
             let new = Container::new(player.view(
                 grid_id,
                 player_id,
                 selected || selected_player,
                 obscured,
                 self.content_fit,
+                self.filter_method,
+                self.aspect_override,
+                self.opacity,
+                source,
+                options,
             ))
-            .padding(5)
+            .padding(options.tile_padding)
             .class(style::Container::Player {
                 selected: selected_player,
             });
@@ -549,7 +1200,25 @@ impl Grid {
             Orientation::Vertical => Container::new(row.push(column)),
         };
 
-        if self.players.is_empty() {
+        if no_media {
+            body = Container::new(
+                Column::new()
+                    .spacing(10)
+                    .align_x(alignment::Horizontal::Center)
+                    .push(text(lang::tell::no_media_found_in_sources()))
+                    .push((too_large > 0).then(|| text(lang::tell::skipped_files_over_size_limit(too_large))))
+                    .push((!options.kiosk).then(|| {
+                        button::primary(lang::action::edit_sources())
+                            .on_press(Message::Pane {
+                                event: PaneEvent::ShowSettings { grid_id },
+                            })
+                            .obscured(obscured)
+                    })),
+            )
+            .center(Length::Fill)
+            .padding(5)
+            .class(style::Container::Player { selected: false });
+        } else if self.players.is_empty() {
             body = Container::new("")
                 .width(Length::Fill)
                 .height(Length::Fill)
@@ -577,12 +1246,21 @@ impl Grid {
             .into()
     }
 
-    pub fn controls(&self, grid_id: Id, obscured: bool, has_siblings: bool) -> Element<'_> {
+    pub fn controls(
+        &self,
+        grid_id: Id,
+        obscured: bool,
+        has_siblings: bool,
+        maximized: bool,
+        soloed: bool,
+    ) -> Element<'_> {
         let show_player_controls = has_siblings && !self.is_idle();
+        // The soundtrack takes over the audio, so there's nothing to mute or solo.
+        let show_audio_controls = show_player_controls && !self.force_muted;
 
         Row::new()
             .align_y(alignment::Vertical::Center)
-            .push(self.all_muted().filter(|_| show_player_controls).map(|all_muted| {
+            .push(self.all_muted().filter(|_| show_audio_controls).map(|all_muted| {
                 button::mini_icon(if all_muted { Icon::Mute } else { Icon::VolumeHigh })
                     .on_press(Message::Pane {
                         event: PaneEvent::SetMute {
@@ -597,6 +1275,18 @@ impl Grid {
                         lang::action::mute()
                     })
             }))
+            .push(self.all_muted().filter(|_| show_audio_controls).map(|_| {
+                button::mini_icon(Icon::Headphones)
+                    .on_press(Message::Pane {
+                        event: PaneEvent::SoloAudio { grid_id },
+                    })
+                    .obscured(obscured)
+                    .tooltip(if soloed {
+                        lang::action::unsolo_audio()
+                    } else {
+                        lang::action::solo_audio()
+                    })
+            }))
             .push(self.all_paused().filter(|_| show_player_controls).map(|all_paused| {
                 button::mini_icon(if all_paused { Icon::Play } else { Icon::Pause })
                     .on_press(Message::Pane {
@@ -633,6 +1323,22 @@ impl Grid {
                     .height(10)
                     .padding(padding::left(5).right(5))
             }))
+            .push(has_siblings.then(|| {
+                button::mini_icon(if maximized {
+                    Icon::FullscreenExit
+                } else {
+                    Icon::Fullscreen
+                })
+                .on_press(Message::Pane {
+                    event: PaneEvent::ToggleMaximize { grid_id },
+                })
+                .obscured(obscured)
+                .tooltip(if maximized {
+                    lang::action::restore_layout()
+                } else {
+                    lang::action::maximize()
+                })
+            }))
             .push(
                 button::mini_icon(Icon::SplitVertical)
                     .on_press(Message::Pane {
@@ -655,6 +1361,14 @@ impl Grid {
                     .obscured(obscured)
                     .tooltip(lang::action::split_horizontally()),
             )
+            .push(
+                button::mini_icon(Icon::ContentCopy)
+                    .on_press(Message::Pane {
+                        event: PaneEvent::Duplicate { grid_id },
+                    })
+                    .obscured(obscured)
+                    .tooltip(lang::action::duplicate()),
+            )
             .push(
                 button::mini_icon(Icon::Add)
                     .on_press(Message::Pane {
@@ -672,6 +1386,22 @@ impl Grid {
                     .obscured(obscured)
                     .tooltip(lang::thing::media()),
             )
+            .push(self.can_freeze_sources().then(|| {
+                button::mini_icon(Icon::Freeze)
+                    .on_press(Message::Pane {
+                        event: PaneEvent::FreezeSources { grid_id },
+                    })
+                    .obscured(obscured)
+                    .tooltip(lang::action::freeze_globs())
+            }))
+            .push(self.can_unfreeze_sources().then(|| {
+                button::mini_icon(Icon::Undo)
+                    .on_press(Message::Pane {
+                        event: PaneEvent::UnfreezeSources { grid_id },
+                    })
+                    .obscured(obscured)
+                    .tooltip(lang::action::restore_globs())
+            }))
             .push(
                 button::mini_icon(Icon::Settings)
                     .on_press(Message::Pane {
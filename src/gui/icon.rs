@@ -8,14 +8,28 @@ use crate::gui::{
 pub enum Icon {
     Add,
     ArrowDownward,
+    AspectRatio,
     ArrowUpward,
+    BugReport,
     Close,
+    ContentCopy,
+    Delete,
+    Description,
+    DriveFileMove,
     Error,
     File,
+    FileCopy,
     FileOpen,
     FolderOpen,
+    Fullscreen,
+    FullscreenExit,
+    Freeze,
+    Headphones,
+    HourglassEmpty,
     Image,
     Link,
+    Lock,
+    LockOpen,
     LogOut,
     Loop,
     Menu,
@@ -31,6 +45,7 @@ pub enum Icon {
     Play,
     PlaylistAdd,
     PlaylistRemove,
+    PushPin,
     Refresh,
     Save,
     SaveAs,
@@ -38,8 +53,12 @@ pub enum Icon {
     Shuffle,
     SplitHorizontal,
     SplitVertical,
+    Stop,
     TimerRefresh,
+    Undo,
     Unlink,
+    ViewQuilt,
+    VisibilityOff,
     VolumeHigh,
 }
 
@@ -49,13 +68,27 @@ impl Icon {
             Self::Add => '\u{E145}',
             Self::ArrowDownward => '\u{E5DB}',
             Self::ArrowUpward => '\u{E5D8}',
+            Self::AspectRatio => '\u{e85b}',
+            Self::BugReport => '\u{e868}',
             Self::Close => '\u{e14c}',
+            Self::ContentCopy => '\u{e14d}',
+            Self::Delete => '\u{e872}',
+            Self::Description => '\u{e873}',
+            Self::DriveFileMove => '\u{e675}',
             Self::Error => '\u{e000}',
             Self::File => '\u{e24d}',
+            Self::FileCopy => '\u{e173}',
             Self::FileOpen => '\u{eaf3}',
             Self::FolderOpen => '\u{E2C8}',
+            Self::Fullscreen => '\u{e5d0}',
+            Self::FullscreenExit => '\u{e5d1}',
+            Self::Freeze => '\u{eb3b}',
+            Self::Headphones => '\u{e310}',
+            Self::HourglassEmpty => '\u{e88b}',
             Self::Image => '\u{e3f4}',
             Self::Link => '\u{e157}',
+            Self::Lock => '\u{e897}',
+            Self::LockOpen => '\u{e898}',
             Self::LogOut => '\u{e9ba}',
             Self::Loop => '\u{e040}',
             Self::Menu => '\u{e5d2}',
@@ -71,6 +104,7 @@ impl Icon {
             Self::Play => '\u{e037}',
             Self::PlaylistAdd => '\u{e03b}',
             Self::PlaylistRemove => '\u{eb80}',
+            Self::PushPin => '\u{f10d}',
             Self::Refresh => '\u{E5D5}',
             Self::Save => '\u{e161}',
             Self::SaveAs => '\u{eb60}',
@@ -78,8 +112,12 @@ impl Icon {
             Self::Shuffle => '\u{e043}',
             Self::SplitHorizontal => '\u{e8d4}',
             Self::SplitVertical => '\u{e8d5}',
+            Self::Stop => '\u{e047}',
             Self::TimerRefresh => '\u{e889}',
+            Self::Undo => '\u{e166}',
             Self::Unlink => '\u{e16f}',
+            Self::ViewQuilt => '\u{e8f1}',
+            Self::VisibilityOff => '\u{e8f5}',
             Self::VolumeHigh => '\u{e050}',
         }
     }
@@ -0,0 +1,157 @@
+//! Integration with the OS's media controls,
+//! like MPRIS on Linux and the System Media Transport Controls on Windows.
+//! This lets media keys and the system's "now playing" widget control the application.
+
+use std::{sync::mpsc as std_mpsc, time::Duration};
+
+use iced::futures::{
+    channel::mpsc::{self, UnboundedSender},
+    SinkExt, Stream, StreamExt,
+};
+use souvlaki::{MediaControlEvent, MediaControls, MediaMetadata, MediaPlayback, PlatformConfig};
+
+use crate::lang;
+
+const DBUS_NAME: &str = "madamiru";
+
+#[derive(Debug, Clone)]
+pub enum Event {
+    /// The integration is ready to receive commands.
+    Ready(std_mpsc::Sender<Command>),
+    Play,
+    Pause,
+    Toggle,
+    Next,
+    Previous,
+    Stop,
+    Quit,
+}
+
+#[derive(Debug, Clone)]
+pub enum Command {
+    /// Start listening for events from the OS.
+    /// On Windows, this requires the raw handle of the main window.
+    Attach {
+        hwnd: Option<usize>,
+    },
+    Update(NowPlaying),
+}
+
+/// Information about the current media to show in the OS.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NowPlaying {
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub cover_url: Option<String>,
+    pub duration: Option<Duration>,
+    /// This is `None` when nothing is playing.
+    pub paused: Option<bool>,
+}
+
+pub fn connect() -> impl Stream<Item = Event> {
+    iced::stream::channel(10, |mut output| async move {
+        let (command_sender, command_receiver) = std_mpsc::channel();
+        let (event_sender, mut event_receiver) = mpsc::unbounded();
+
+        let _ = output.send(Event::Ready(command_sender)).await;
+
+        let handle = tokio::task::spawn_blocking(move || {
+            // The controls must stay on this thread, since they aren't `Send` on all platforms.
+            let mut controls = None;
+
+            while let Ok(command) = command_receiver.recv() {
+                match command {
+                    Command::Attach { hwnd } => {
+                        controls = attach(hwnd, event_sender.clone());
+                    }
+                    Command::Update(now_playing) => {
+                        if let Some(controls) = controls.as_mut() {
+                            publish(controls, &now_playing);
+                        }
+                    }
+                }
+            }
+        });
+
+        while let Some(event) = event_receiver.next().await {
+            let _ = output.send(event).await;
+        }
+
+        if let Err(e) = handle.await {
+            log::error!("Failed to join task for media controls: {e:?}");
+        }
+    })
+}
+
+fn attach(hwnd: Option<usize>, sender: UnboundedSender<Event>) -> Option<MediaControls> {
+    let display_name = lang::app_name();
+    let config = PlatformConfig {
+        display_name: &display_name,
+        dbus_name: DBUS_NAME,
+        hwnd: hwnd.map(|hwnd| hwnd as *mut std::ffi::c_void),
+    };
+
+    let mut controls = match MediaControls::new(config) {
+        Ok(controls) => controls,
+        Err(e) => {
+            log::warn!("Unable to initialize media controls: {e:?}");
+            return None;
+        }
+    };
+
+    let attached = controls.attach(move |event| {
+        let event = match event {
+            MediaControlEvent::Play => Event::Play,
+            MediaControlEvent::Pause => Event::Pause,
+            MediaControlEvent::Toggle => Event::Toggle,
+            MediaControlEvent::Next => Event::Next,
+            MediaControlEvent::Previous => Event::Previous,
+            MediaControlEvent::Stop => Event::Stop,
+            MediaControlEvent::Quit => Event::Quit,
+            _ => return,
+        };
+        let _ = sender.unbounded_send(event);
+    });
+
+    if let Err(e) = attached {
+        log::warn!("Unable to attach media controls: {e:?}");
+        return None;
+    }
+
+    log::info!("Media controls attached");
+    Some(controls)
+}
+
+fn publish(controls: &mut MediaControls, now_playing: &NowPlaying) {
+    let metadata = MediaMetadata {
+        title: now_playing.title.as_deref(),
+        artist: now_playing.artist.as_deref(),
+        album: now_playing.album.as_deref(),
+        cover_url: now_playing.cover_url.as_deref(),
+        duration: now_playing.duration,
+    };
+    if let Err(e) = controls.set_metadata(metadata) {
+        log::warn!("Unable to update media controls metadata: {e:?}");
+    }
+
+    let playback = match now_playing.paused {
+        None => MediaPlayback::Stopped,
+        Some(true) => MediaPlayback::Paused { progress: None },
+        Some(false) => MediaPlayback::Playing { progress: None },
+    };
+    if let Err(e) = controls.set_playback(playback) {
+        log::warn!("Unable to update media controls playback: {e:?}");
+    }
+}
+
+/// Find the raw handle of the main window, which the Windows integration needs.
+#[cfg(target_os = "windows")]
+pub fn window_handle(window: &dyn iced::window::Window) -> Option<usize> {
+    use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+
+    match window.window_handle().ok()?.as_raw() {
+        RawWindowHandle::Win32(handle) => Some(handle.hwnd.get() as usize),
+        _ => None,
+    }
+}
@@ -12,9 +12,10 @@ use itertools::Itertools;
 use crate::{
     gui::{
         button,
-        common::{BrowseFileSubject, BrowseSubject, EditAction, Message, UndoSubject},
+        common::{BrowseFileSubject, BrowseSubject, EditAction, Message, Transfer, UndoSubject},
         grid,
         icon::Icon,
+        player,
         shortcuts::{Shortcut, TextHistories, TextHistory},
         style,
         widget::{checkbox, pick_list, text, Column, Container, Element, Row, Scrollable, Space, Stack},
@@ -24,7 +25,10 @@ use crate::{
     path::StrictPath,
     prelude::Error,
     resource::{
-        config::{self, Config, Theme},
+        config::{
+            self, ClickToPause, Config, LogLevel, Playback, RefreshButton, SpaceTarget, Theme, UpdateRate,
+            MAX_PINNED_DESTINATIONS,
+        },
         playlist,
     },
 };
@@ -43,13 +47,35 @@ pub fn scroll_down() -> Task<Message> {
 pub enum Event {
     EditedSource { action: EditAction },
     EditedSourceKind { index: usize, kind: media::SourceKind },
+    EditedFallbackSourceEnabled { enabled: bool },
+    EditedFallbackSourceKind { kind: media::SourceKind },
+    EditedFallbackSource { raw: String },
     SelectedGridTab { tab: GridTab },
+    AppliedGridPreset { preset: grid::Preset },
     EditedGridContentFit { content_fit: playlist::ContentFit },
+    EditedGridFilterMethod { filter_method: playlist::FilterMethod },
+    EditedGridAspectOverride { aspect_override: playlist::AspectOverride },
+    EditedGridFilter { filter: playlist::DisplayFilter },
+    EditedGridOnEndKind { custom: bool },
+    EditedGridOnEnd { on_end: playlist::OnEnd },
     EditedGridOrientation { orientation: playlist::Orientation },
     EditedGridOrientationLimitKind { fixed: bool },
     EditedGridOrientationLimit { raw_limit: String },
+    EditedGridImageDurationKind { custom: bool },
+    EditedGridImageDuration { raw_duration: String },
+    EditedGridClipLengthKind { custom: bool },
+    EditedGridClipLength { raw_length: String },
+    EditedGridName { name: String },
+    EditedGridShowCounter { show_counter: bool },
+    EditedGridAllowDuplicatesOnScreen { allow: bool },
+    EditedGridShuffleOnFocus { shuffle: bool },
+    EditedGridCycleInOrder { cycle: bool },
+    EditedGridOpacity { opacity: u8 },
+    EditedGridMirrorOf { grid_id: Option<grid::Id> },
+    AppliedGridSettingsToAll,
     Save,
     PlayMedia(Media),
+    ChoseTransferDestination { folder: StrictPath },
 }
 
 pub enum Update {
@@ -57,10 +83,25 @@ pub enum Update {
         grid_id: grid::Id,
         settings: grid::Settings,
     },
+    ConfirmApplyGridSettingsToAll {
+        grid_id: grid::Id,
+        settings: grid::Settings,
+    },
+    SavedAllGridSettings {
+        grid_id: grid::Id,
+        changes: Vec<(grid::Id, grid::Settings)>,
+    },
     PlayMedia {
         grid_id: grid::Id,
         media: Media,
     },
+    TransferMedia {
+        grid_id: grid::Id,
+        player_id: player::Id,
+        media: Media,
+        transfer: Transfer,
+        folder: StrictPath,
+    },
     Task(Task<Message>),
 }
 
@@ -98,6 +139,28 @@ pub enum Modal {
     ConfirmDiscardPlaylist {
         exit: bool,
     },
+    ConfirmExitWhilePlaying,
+    ConfirmFolderGrids {
+        folders: Vec<StrictPath>,
+        /// Whether there were more folders than we can make grids for.
+        truncated: bool,
+    },
+    ConfirmApplyGridSettingsToAll {
+        /// The grid whose settings are being applied.
+        grid_id: grid::Id,
+        changes: Vec<(grid::Id, grid::Settings)>,
+    },
+    ConfirmDeleteToTrash {
+        grid_id: grid::Id,
+        player_id: player::Id,
+        media: Media,
+    },
+    Transfer {
+        grid_id: grid::Id,
+        player_id: player::Id,
+        media: Media,
+        transfer: Transfer,
+    },
 }
 
 impl Modal {
@@ -113,12 +176,31 @@ impl Modal {
             }
         }
 
+        if let Some(source) = &settings.fallback_source {
+            histories.fallback_source = TextHistory::raw(source.raw());
+        }
+
         let raw_limit = match settings.orientation_limit {
             playlist::OrientationLimit::Automatic => playlist::OrientationLimit::DEFAULT_FIXED.to_string(),
             playlist::OrientationLimit::Fixed(limit) => limit.to_string(),
         };
         histories.orientation_limit.push(&raw_limit);
 
+        let raw_duration = settings
+            .image_duration
+            .unwrap_or(Playback::default().image_duration)
+            .to_string();
+        histories.image_duration.push(&raw_duration);
+
+        let raw_clip_length = settings
+            .clip_length
+            .map(|length| length.get())
+            .unwrap_or(grid::DEFAULT_CLIP_LENGTH)
+            .to_string();
+        histories.clip_length.push(&raw_clip_length);
+
+        histories.name.push(settings.name.as_deref().unwrap_or_default());
+
         Self::GridSettings {
             grid_id,
             tab: GridTab::default(),
@@ -137,16 +219,27 @@ impl Modal {
             Self::AppUpdate { .. } => None,
             Self::ConfirmLoadPlaylist { .. } => None,
             Self::ConfirmDiscardPlaylist { .. } => None,
+            Self::ConfirmExitWhilePlaying => None,
+            Self::ConfirmFolderGrids { .. } => None,
+            Self::ConfirmApplyGridSettingsToAll { .. } => None,
+            Self::ConfirmDeleteToTrash { .. } => None,
+            Self::Transfer { grid_id, .. } => Some(*grid_id),
         }
     }
 
     pub fn variant(&self) -> ModalVariant {
         match self {
-            Self::Error { .. } | Self::Errors { .. } | Self::GridMedia { .. } => ModalVariant::Info,
+            Self::Error { .. } | Self::Errors { .. } | Self::GridMedia { .. } | Self::Transfer { .. } => {
+                ModalVariant::Info
+            }
             Self::GridSettings { .. }
             | Self::AppUpdate { .. }
             | Self::ConfirmLoadPlaylist { .. }
-            | Self::ConfirmDiscardPlaylist { .. } => ModalVariant::Confirm,
+            | Self::ConfirmDiscardPlaylist { .. }
+            | Self::ConfirmExitWhilePlaying
+            | Self::ConfirmFolderGrids { .. }
+            | Self::ConfirmApplyGridSettingsToAll { .. }
+            | Self::ConfirmDeleteToTrash { .. } => ModalVariant::Confirm,
             Self::Settings => ModalVariant::Editor,
         }
     }
@@ -167,6 +260,11 @@ impl Modal {
             Self::AppUpdate { .. } => None,
             Self::ConfirmLoadPlaylist { .. } => None,
             Self::ConfirmDiscardPlaylist { .. } => None,
+            Self::ConfirmExitWhilePlaying => None,
+            Self::ConfirmFolderGrids { .. } => None,
+            Self::ConfirmApplyGridSettingsToAll { .. } => None,
+            Self::ConfirmDeleteToTrash { .. } => None,
+            Self::Transfer { .. } => None,
         }
     }
 
@@ -189,6 +287,21 @@ impl Modal {
                     Some(Message::PlaylistReset { force: true })
                 }
             }
+            Self::ConfirmExitWhilePlaying => Some(Message::Exit { force: true }),
+            Self::ConfirmFolderGrids { folders, .. } => Some(Message::CreateFolderGrids {
+                folders: folders.clone(),
+            }),
+            Self::ConfirmApplyGridSettingsToAll { .. } => Some(Message::Modal { event: Event::Save }),
+            Self::ConfirmDeleteToTrash {
+                grid_id,
+                player_id,
+                media,
+            } => Some(Message::DeleteToTrash {
+                grid_id: *grid_id,
+                player_id: *player_id,
+                media: media.clone(),
+            }),
+            Self::Transfer { .. } => Some(Message::CloseModal),
         }
     }
 
@@ -200,6 +313,7 @@ impl Modal {
         playlist: Option<&StrictPath>,
         collection: &media::Collection,
         active_media: HashSet<&Media>,
+        mirror_options: Vec<grid::MirrorOption>,
     ) -> Option<Column> {
         let mut col = Column::new().spacing(15).padding(padding::right(10));
 
@@ -234,7 +348,14 @@ impl Modal {
                                             }
                                         })),
                                 )
-                                .push(
+                                .push(checkbox(
+                                    lang::action::use_high_contrast_selection(),
+                                    config.view.selection.high_contrast,
+                                    |value| Message::Config {
+                                        event: config::Event::HighContrastSelection(value),
+                                    },
+                                ))
+                                .push(cfg!(feature = "update-check").then(|| {
                                     Row::new()
                                         .align_y(Alignment::Center)
                                         .spacing(20)
@@ -250,8 +371,8 @@ impl Modal {
                                                 .on_press(Message::OpenUrl(RELEASE_URL.to_string()))
                                                 .tooltip(lang::action::view_releases())
                                                 .padding([0, 10]),
-                                        ),
-                                )
+                                        )
+                                }))
                                 .push(checkbox(
                                     lang::action::pause_when_window_loses_focus(),
                                     config.playback.pause_on_unfocus,
@@ -259,12 +380,175 @@ impl Modal {
                                         event: config::Event::PauseWhenWindowLosesFocus(value),
                                     },
                                 ))
+                                .push(checkbox(
+                                    lang::action::mute_when_window_is_minimized(),
+                                    config.playback.mute_on_minimize,
+                                    |value| Message::Config {
+                                        event: config::Event::MuteWhenWindowIsMinimized(value),
+                                    },
+                                ))
+                                .push(checkbox(
+                                    lang::action::pause_when_window_is_hidden(),
+                                    config.playback.pause_when_hidden,
+                                    |value| Message::Config {
+                                        event: config::Event::PauseWhenWindowIsHidden(value),
+                                    },
+                                ))
+                                .push(cfg!(feature = "keep-awake").then(|| {
+                                    checkbox(
+                                        lang::action::keep_screen_awake_during_playback(),
+                                        config.playback.keep_awake,
+                                        |value| Message::Config {
+                                            event: config::Event::KeepAwake(value),
+                                        },
+                                    )
+                                }))
+                                .push(
+                                    Row::new()
+                                        .align_y(Alignment::Center)
+                                        .spacing(20)
+                                        .push(text(lang::field(&lang::action::toggle_pause_on_click())))
+                                        .push(pick_list(
+                                            ClickToPause::ALL,
+                                            Some(config.playback.click_to_pause),
+                                            |value| Message::Config {
+                                                event: config::Event::ClickToPause(value),
+                                            },
+                                        )),
+                                )
+                                .push(
+                                    Row::new()
+                                        .align_y(Alignment::Center)
+                                        .spacing(20)
+                                        .push(text(lang::field(&lang::action::toggle_pause_with_space())))
+                                        .push(pick_list(SpaceTarget::ALL, Some(config.input.space_target), |value| {
+                                            Message::Config {
+                                                event: config::Event::SpaceTarget(value),
+                                            }
+                                        })),
+                                )
+                                .push(
+                                    Row::new()
+                                        .align_y(Alignment::Center)
+                                        .spacing(20)
+                                        .push(text(lang::field(&lang::action::when_media_ends())))
+                                        .push(pick_list(playlist::OnEnd::ALL, Some(config.playback.on_end), |value| {
+                                            Message::Config {
+                                                event: config::Event::OnEnd(value),
+                                            }
+                                        })),
+                                )
+                                .push(
+                                    Row::new()
+                                        .align_y(Alignment::Center)
+                                        .spacing(20)
+                                        .push(text(lang::field(&lang::thing::refresh_button())))
+                                        .push(pick_list(
+                                            RefreshButton::ALL,
+                                            Some(config.view.refresh_button),
+                                            |value| Message::Config {
+                                                event: config::Event::RefreshButton(value),
+                                            },
+                                        )),
+                                )
+                                .push(
+                                    Row::new()
+                                        .align_y(Alignment::Center)
+                                        .spacing(20)
+                                        .push(text(lang::field(&lang::thing::update_rate())))
+                                        .push(pick_list(UpdateRate::ALL, Some(config.playback.update_rate), |value| {
+                                            Message::Config {
+                                                event: config::Event::UpdateRate(value),
+                                            }
+                                        })),
+                                )
                                 .push(checkbox(
                                     lang::action::confirm_when_discarding_unsaved_playlist(),
                                     config.view.confirm_discard_playlist,
                                     |value| Message::Config {
                                         event: config::Event::ConfirmWhenDiscardingUnsavedPlaylist(value),
                                     },
+                                ))
+                                .push(checkbox(
+                                    lang::action::confirm_when_exiting_while_playing(),
+                                    config.view.confirm_exit_while_playing,
+                                    |value| Message::Config {
+                                        event: config::Event::ConfirmWhenExitingWhilePlaying(value),
+                                    },
+                                ))
+                                .push(checkbox(
+                                    lang::action::save_playlist_automatically(),
+                                    config.view.autosave_playlist,
+                                    |value| Message::Config {
+                                        event: config::Event::AutosavePlaylist(value),
+                                    },
+                                ))
+                                .push(checkbox(
+                                    lang::action::save_window_size_in_playlist(),
+                                    config.view.save_window_size_in_playlist,
+                                    |value| Message::Config {
+                                        event: config::Event::SaveWindowSizeInPlaylist(value),
+                                    },
+                                ))
+                                .push(checkbox(
+                                    lang::action::restore_last_session_on_launch(),
+                                    config.view.restore_last_session,
+                                    |value| Message::Config {
+                                        event: config::Event::RestoreLastSession(value),
+                                    },
+                                ))
+                                .push(checkbox(
+                                    lang::action::show_notice_when_no_media_found(),
+                                    config.view.show_no_media_notice,
+                                    |value| Message::Config {
+                                        event: config::Event::ShowNoMediaNotice(value),
+                                    },
+                                ))
+                                .push(checkbox(
+                                    lang::action::show_media_date(),
+                                    config.view.show_media_date,
+                                    |value| Message::Config {
+                                        event: config::Event::ShowMediaDate(value),
+                                    },
+                                ))
+                                .push(checkbox(
+                                    lang::action::show_progress_bars(),
+                                    config.view.show_progress_bars,
+                                    |value| Message::Config {
+                                        event: config::Event::ShowProgressBars(value),
+                                    },
+                                ))
+                                .push(
+                                    Row::new()
+                                        .align_y(Alignment::Center)
+                                        .spacing(20)
+                                        .push(text(lang::field(&lang::thing::log_level())))
+                                        .push(pick_list(LogLevel::ALL, Some(config.view.log_level), |value| {
+                                            Message::Config {
+                                                event: config::Event::LogLevel(value),
+                                            }
+                                        })),
+                                )
+                                .push(checkbox(
+                                    lang::action::rescan_sources_automatically(),
+                                    config.playback.auto_rescan,
+                                    |value| Message::Config {
+                                        event: config::Event::AutoRescan(value),
+                                    },
+                                ))
+                                .push(checkbox(
+                                    lang::action::follow_symlinked_folders(),
+                                    config.playback.follow_symlinks,
+                                    |value| Message::Config {
+                                        event: config::Event::FollowSymlinks(value),
+                                    },
+                                ))
+                                .push(checkbox(
+                                    lang::action::collapse_duplicate_files(),
+                                    config.playback.collapse_duplicates,
+                                    |value| Message::Config {
+                                        event: config::Event::CollapseDuplicates(value),
+                                    },
                                 )),
                         )
                         .class(style::Container::Player { selected: false }),
@@ -272,48 +556,106 @@ impl Modal {
                     .push(text(lang::field(&lang::thing::audio())))
                     .push(
                         Container::new(
-                            Column::new().spacing(10).padding(10).push(
-                                Row::new()
-                                    .spacing(10)
-                                    .align_y(alignment::Vertical::Center)
-                                    .push(
-                                        button::icon(if config.playback.muted {
-                                            Icon::Mute
-                                        } else {
-                                            Icon::VolumeHigh
-                                        })
-                                        .on_press(Message::SetMute(!config.playback.muted))
-                                        .tooltip(if config.playback.muted {
-                                            lang::action::unmute()
-                                        } else {
-                                            lang::action::mute()
-                                        }),
-                                    )
-                                    .push(
-                                        iced::widget::slider(0.01..=1.0, config.playback.volume, |volume| {
-                                            Message::SetVolume { volume }
-                                        })
-                                        .step(0.01)
-                                        .width(150),
+                            Column::new()
+                                .spacing(10)
+                                .padding(10)
+                                .push(
+                                    Row::new()
+                                        .spacing(10)
+                                        .align_y(alignment::Vertical::Center)
+                                        .push(
+                                            button::icon(if config.playback.muted {
+                                                Icon::Mute
+                                            } else {
+                                                Icon::VolumeHigh
+                                            })
+                                            .on_press(Message::SetMute(!config.playback.muted))
+                                            .tooltip(
+                                                if config.playback.muted {
+                                                    lang::action::unmute()
+                                                } else {
+                                                    lang::action::mute()
+                                                },
+                                            ),
+                                        )
+                                        .push(
+                                            iced::widget::slider(0.01..=1.0, config.playback.volume, |volume| {
+                                                Message::SetVolume { volume }
+                                            })
+                                            .step(0.01)
+                                            .width(150),
+                                        )
+                                        .push(
+                                            text(format!("{:.0}%", config.playback.volume * 100.0))
+                                                .width(50)
+                                                .align_x(alignment::Horizontal::Center),
+                                        ),
+                                )
+                                .push(checkbox(
+                                    lang::action::mute_new_players(),
+                                    config.playback.mute_new_players,
+                                    |value| Message::Config {
+                                        event: config::Event::MuteNewPlayers(value),
+                                    },
+                                ))
+                                .push(checkbox(
+                                    lang::action::normalize_audio_volume(),
+                                    config.playback.normalize_audio,
+                                    |value| Message::Config {
+                                        event: config::Event::NormalizeAudio(value),
+                                    },
+                                ))
+                                .push(cfg!(feature = "video").then(|| {
+                                    checkbox(
+                                        lang::action::load_subtitles_automatically(),
+                                        config.playback.load_subtitles,
+                                        |value| Message::Config {
+                                            event: config::Event::LoadSubtitles(value),
+                                        },
                                     )
-                                    .push(
-                                        text(format!("{:.0}%", config.playback.volume * 100.0))
-                                            .width(50)
-                                            .align_x(alignment::Horizontal::Center),
-                                    ),
-                            ),
+                                }))
+                                .push(
+                                    Row::new()
+                                        .align_y(Alignment::Center)
+                                        .spacing(20)
+                                        .push(text(lang::field(&lang::action::step_this_many_seconds())))
+                                        .push(UndoSubject::SeekStepAudio.view_with(histories)),
+                                ),
                         )
                         .class(style::Container::Player { selected: false }),
                     )
                     .push(text(lang::field(&lang::thing::image())))
+                    .push(
+                        Container::new(
+                            Column::new()
+                                .spacing(10)
+                                .padding(10)
+                                .push(
+                                    Row::new()
+                                        .align_y(Alignment::Center)
+                                        .spacing(20)
+                                        .push(text(lang::field(&lang::action::play_for_this_many_seconds())))
+                                        .push(UndoSubject::ImageDuration.view_with(histories)),
+                                )
+                                .push(
+                                    Row::new()
+                                        .align_y(Alignment::Center)
+                                        .spacing(20)
+                                        .push(text(lang::field(&lang::action::step_this_many_seconds())))
+                                        .push(UndoSubject::SeekStepImage.view_with(histories)),
+                                ),
+                        )
+                        .class(style::Container::Player { selected: false }),
+                    )
+                    .push(text(lang::field(&lang::thing::video())))
                     .push(
                         Container::new(
                             Column::new().spacing(10).padding(10).push(
                                 Row::new()
                                     .align_y(Alignment::Center)
                                     .spacing(20)
-                                    .push(text(lang::field(&lang::action::play_for_this_many_seconds())))
-                                    .push(UndoSubject::ImageDuration.view_with(histories)),
+                                    .push(text(lang::field(&lang::action::step_this_many_seconds())))
+                                    .push(UndoSubject::SeekStepVideo.view_with(histories)),
                             ),
                         )
                         .class(style::Container::Player { selected: false }),
@@ -392,11 +734,42 @@ impl Modal {
                     );
                 }
 
-                col = col.push(button::icon(Icon::Add).on_press(Message::Modal {
-                    event: Event::EditedSource {
-                        action: EditAction::Add,
-                    },
-                }));
+                col = col
+                    .push(button::icon(Icon::Add).on_press(Message::Modal {
+                        event: Event::EditedSource {
+                            action: EditAction::Add,
+                        },
+                    }))
+                    .push(
+                        Row::new()
+                            .spacing(20)
+                            .align_y(alignment::Vertical::Center)
+                            .push(checkbox(
+                                lang::field(&lang::thing::fallback_source()),
+                                settings.fallback_source.is_some(),
+                                |enabled| Message::Modal {
+                                    event: Event::EditedFallbackSourceEnabled { enabled },
+                                },
+                            ))
+                            .push(settings.fallback_source.as_ref().map(|source| {
+                                Row::new()
+                                    .spacing(10)
+                                    .align_y(alignment::Vertical::Center)
+                                    .push(pick_list(media::SourceKind::ALL, Some(source.kind()), |kind| {
+                                        Message::Modal {
+                                            event: Event::EditedFallbackSourceKind { kind },
+                                        }
+                                    }))
+                                    .push(UndoSubject::FallbackSource.view(&histories.fallback_source.current()))
+                                    .push(source.path().map(|path| {
+                                        button::choose_folder(
+                                            BrowseSubject::FallbackSource,
+                                            media::fill_placeholders_in_path(path, playlist),
+                                            modifiers,
+                                        )
+                                    }))
+                            })),
+                    );
             }
             Self::GridSettings {
                 tab: GridTab::Layout,
@@ -405,6 +778,30 @@ impl Modal {
                 ..
             } => {
                 col = col
+                    .push(
+                        Row::new()
+                            .align_y(Alignment::Center)
+                            .spacing(20)
+                            .push(text(lang::field(&lang::thing::preset())))
+                            .push(
+                                pick_list(grid::Preset::ALL, None::<grid::Preset>, |preset| Message::Modal {
+                                    event: Event::AppliedGridPreset { preset },
+                                })
+                                .placeholder(lang::action::choose_preset()),
+                            )
+                            .push(
+                                button::bare(lang::action::apply_to_all_grids()).on_press(Message::Modal {
+                                    event: Event::AppliedGridSettingsToAll,
+                                }),
+                            ),
+                    )
+                    .push(
+                        Row::new()
+                            .align_y(Alignment::Center)
+                            .spacing(20)
+                            .push(text(lang::field(&lang::thing::name())))
+                            .push(UndoSubject::GridName.view(&histories.name.current())),
+                    )
                     .push(
                         Row::new()
                             .align_y(Alignment::Center)
@@ -443,7 +840,161 @@ impl Modal {
                                     event: Event::EditedGridContentFit { content_fit },
                                 },
                             )),
-                    );
+                    )
+                    .push(
+                        Row::new()
+                            .align_y(Alignment::Center)
+                            .spacing(20)
+                            .push(text(lang::field(&lang::thing::scaling())))
+                            .push(pick_list(
+                                playlist::FilterMethod::ALL,
+                                Some(settings.filter_method),
+                                |filter_method| Message::Modal {
+                                    event: Event::EditedGridFilterMethod { filter_method },
+                                },
+                            )),
+                    )
+                    .push((!mirror_options.is_empty() || settings.mirror_of.is_some()).then(|| {
+                        let first = mirror_options.first().map(|option| option.grid_id);
+                        let selected = mirror_options
+                            .iter()
+                            .find(|option| Some(option.grid_id) == settings.mirror_of)
+                            .cloned();
+
+                        Row::new()
+                            .align_y(Alignment::Center)
+                            .spacing(20)
+                            .push(checkbox(
+                                lang::action::mirror_another_grid(),
+                                settings.mirror_of.is_some(),
+                                move |mirror| Message::Modal {
+                                    event: Event::EditedGridMirrorOf {
+                                        grid_id: if mirror { first } else { None },
+                                    },
+                                },
+                            ))
+                            .push(settings.mirror_of.is_some().then(|| {
+                                pick_list(mirror_options, selected, |option: grid::MirrorOption| Message::Modal {
+                                    event: Event::EditedGridMirrorOf {
+                                        grid_id: Some(option.grid_id),
+                                    },
+                                })
+                            }))
+                    }))
+                    .push(
+                        Row::new()
+                            .align_y(Alignment::Center)
+                            .spacing(20)
+                            .push(text(lang::field(&lang::thing::aspect_ratio())))
+                            .push(pick_list(
+                                playlist::AspectOverride::ALL,
+                                Some(settings.aspect_override),
+                                |aspect_override| Message::Modal {
+                                    event: Event::EditedGridAspectOverride { aspect_override },
+                                },
+                            )),
+                    )
+                    .push(
+                        Row::new()
+                            .align_y(Alignment::Center)
+                            .spacing(20)
+                            .push(text(lang::field(&lang::thing::filter())))
+                            .push(pick_list(
+                                playlist::DisplayFilter::ALL,
+                                Some(settings.filter),
+                                |filter| Message::Modal {
+                                    event: Event::EditedGridFilter { filter },
+                                },
+                            )),
+                    )
+                    .push(
+                        Row::new()
+                            .align_y(Alignment::Center)
+                            .spacing(20)
+                            .push(checkbox(
+                                lang::field(&lang::action::when_media_ends()),
+                                settings.on_end.is_some(),
+                                |custom| Message::Modal {
+                                    event: Event::EditedGridOnEndKind { custom },
+                                },
+                            ))
+                            .push(settings.on_end.map(|on_end| {
+                                pick_list(playlist::OnEnd::ALL, Some(on_end), |on_end| Message::Modal {
+                                    event: Event::EditedGridOnEnd { on_end },
+                                })
+                            })),
+                    )
+                    .push(
+                        Row::new()
+                            .align_y(Alignment::Center)
+                            .spacing(20)
+                            .push(checkbox(
+                                lang::field(&lang::action::play_images_for_this_many_seconds()),
+                                settings.image_duration.is_some(),
+                                |custom| Message::Modal {
+                                    event: Event::EditedGridImageDurationKind { custom },
+                                },
+                            ))
+                            .push(UndoSubject::GridImageDuration.view(&histories.image_duration.current())),
+                    )
+                    .push(
+                        Row::new()
+                            .align_y(Alignment::Center)
+                            .spacing(20)
+                            .push(checkbox(
+                                lang::field(&lang::action::play_random_clips_of_this_many_seconds()),
+                                settings.clip_length.is_some(),
+                                |custom| Message::Modal {
+                                    event: Event::EditedGridClipLengthKind { custom },
+                                },
+                            ))
+                            .push(UndoSubject::GridClipLength.view(&histories.clip_length.current())),
+                    )
+                    .push(
+                        Row::new()
+                            .align_y(Alignment::Center)
+                            .spacing(20)
+                            .push(text(lang::field(&lang::thing::opacity())))
+                            .push(
+                                iced::widget::slider(
+                                    grid::MIN_OPACITY..=grid::MAX_OPACITY,
+                                    settings.opacity,
+                                    |opacity| Message::Modal {
+                                        event: Event::EditedGridOpacity { opacity },
+                                    },
+                                )
+                                .width(150),
+                            )
+                            .push(text(format!("{}%", settings.opacity))),
+                    )
+                    .push(checkbox(
+                        lang::action::allow_duplicate_media_across_groups(),
+                        settings.allow_duplicates_on_screen,
+                        |allow| Message::Modal {
+                            event: Event::EditedGridAllowDuplicatesOnScreen { allow },
+                        },
+                    ))
+                    .push(checkbox(
+                        lang::action::shuffle_when_selected(),
+                        settings.shuffle_on_focus,
+                        |shuffle| Message::Modal {
+                            event: Event::EditedGridShuffleOnFocus { shuffle },
+                        },
+                    ))
+                    .push(checkbox(
+                        lang::action::cycle_through_media_in_order(),
+                        settings.cycle_in_order,
+                        |cycle| Message::Modal {
+                            event: Event::EditedGridCycleInOrder { cycle },
+                        },
+                    ))
+                    .push(checkbox(
+                        lang::action::show_media_counter(),
+                        settings.show_counter,
+                        |show_counter| Message::Modal {
+                            event: Event::EditedGridShowCounter { show_counter },
+                        },
+                    ));
             }
             Self::GridMedia { sources, .. } => {
                 col = col.spacing(2);
@@ -508,6 +1059,94 @@ impl Modal {
                     lang::ask::discard_changes()
                 )));
             }
+            Self::ConfirmExitWhilePlaying => {
+                col = col.push(text(lang::join!(
+                    lang::tell::media_is_playing(),
+                    lang::ask::exit_anyway()
+                )));
+            }
+            Self::ConfirmFolderGrids { folders, truncated } => {
+                col = col
+                    .push(text(lang::ask::replace_layout_with_folder_grids(folders.len())))
+                    .push(truncated.then(|| text(lang::tell::only_first_folders_will_be_used(folders.len()))));
+            }
+            Self::ConfirmApplyGridSettingsToAll { changes, .. } => {
+                col = col.push(text(lang::ask::apply_layout_to_grids(changes.len())));
+            }
+            Self::ConfirmDeleteToTrash { media, .. } => {
+                col = col
+                    .push(text(lang::ask::move_file_to_trash()))
+                    .push(text(media.path().render()));
+            }
+            Self::Transfer { media, transfer, .. } => {
+                let icon = || match transfer {
+                    Transfer::Move => Icon::DriveFileMove,
+                    Transfer::Copy => Icon::FileCopy,
+                };
+                let prompt = match transfer {
+                    Transfer::Move => lang::ask::move_file_to_folder(),
+                    Transfer::Copy => lang::ask::copy_file_to_folder(),
+                };
+
+                col = col.push(text(prompt)).push(text(media.path().render()));
+
+                let pin = |folder: &StrictPath, pinned: bool| Message::Config {
+                    event: config::Event::PinDestination {
+                        folder: folder.clone(),
+                        pinned,
+                    },
+                };
+                let can_pin = config.view.pinned_destinations.len() < MAX_PINNED_DESTINATIONS;
+
+                let mut folders = Column::new().spacing(2);
+                for (index, folder) in config.view.pinned_destinations.iter().enumerate() {
+                    folders = folders.push(
+                        Row::new()
+                            .spacing(10)
+                            .align_y(Alignment::Center)
+                            .push(button::icon(icon()).on_press(Message::Modal {
+                                event: Event::ChoseTransferDestination { folder: folder.clone() },
+                            }))
+                            .push(
+                                button::icon(Icon::Close)
+                                    .on_press(pin(folder, false))
+                                    .tooltip(lang::action::unpin_from_keyboard_shortcut()),
+                            )
+                            .push(text(format!("{}. {}", index + 1, folder.render()))),
+                    );
+                }
+                for folder in &config.view.recent_destinations {
+                    if config.view.pinned_destinations.iter().any(|x| x.equivalent(folder)) {
+                        continue;
+                    }
+                    folders = folders.push(
+                        Row::new()
+                            .spacing(10)
+                            .align_y(Alignment::Center)
+                            .push(button::icon(icon()).on_press(Message::Modal {
+                                event: Event::ChoseTransferDestination { folder: folder.clone() },
+                            }))
+                            .push(
+                                button::icon(Icon::PushPin)
+                                    .on_press_maybe(can_pin.then(|| pin(folder, true)))
+                                    .tooltip(lang::action::pin_to_keyboard_shortcut()),
+                            )
+                            .push(text(folder.render())),
+                    );
+                }
+                folders = folders.push(
+                    Row::new()
+                        .spacing(10)
+                        .align_y(Alignment::Center)
+                        .push(
+                            button::icon(Icon::FolderOpen)
+                                .on_press(Message::BrowseDir(BrowseSubject::TransferDestination)),
+                        )
+                        .push(text(lang::action::select_folder())),
+                );
+
+                col = col.push(folders);
+            }
         }
 
         Some(col)
@@ -540,6 +1179,7 @@ impl Modal {
         playlist: Option<&StrictPath>,
         collection: &media::Collection,
         active_media: HashSet<&Media>,
+        mirror_options: Vec<grid::MirrorOption>,
     ) -> Container {
         Container::new(
             Column::new()
@@ -548,12 +1188,20 @@ impl Modal {
                 .align_x(Alignment::Center)
                 .push(self.title(config))
                 .push(
-                    self.body(config, histories, modifiers, playlist, collection, active_media)
-                        .map(|body| {
-                            Container::new(Scrollable::new(body.padding([0, 30])).id((*SCROLLABLE).clone()))
-                                .padding(padding::right(5))
-                                .max_height(viewport.height - 300.0)
-                        }),
+                    self.body(
+                        config,
+                        histories,
+                        modifiers,
+                        playlist,
+                        collection,
+                        active_media,
+                        mirror_options,
+                    )
+                    .map(|body| {
+                        Container::new(Scrollable::new(body.padding([0, 30])).id((*SCROLLABLE).clone()))
+                            .padding(padding::right(5))
+                            .max_height(viewport.height - 300.0)
+                    }),
                 )
                 .push(Container::new(self.controls())),
         )
@@ -568,21 +1216,56 @@ impl Modal {
             | Self::Errors { .. }
             | Self::AppUpdate { .. }
             | Self::ConfirmLoadPlaylist { .. }
-            | Self::ConfirmDiscardPlaylist { .. } => false,
+            | Self::ConfirmDiscardPlaylist { .. }
+            | Self::ConfirmExitWhilePlaying
+            | Self::ConfirmFolderGrids { .. }
+            | Self::ConfirmApplyGridSettingsToAll { .. }
+            | Self::ConfirmDeleteToTrash { .. }
+            | Self::Transfer { .. } => false,
             Self::GridSettings {
                 settings, histories, ..
             } => match subject {
                 UndoSubject::ImageDuration => false,
+                UndoSubject::SeekStepImage => false,
+                UndoSubject::SeekStepAudio => false,
+                UndoSubject::SeekStepVideo => false,
                 UndoSubject::Source { index } => {
                     settings.sources[index].reset(histories.sources[index].apply(shortcut));
                     true
                 }
+                UndoSubject::FallbackSource => {
+                    let raw = histories.fallback_source.apply(shortcut);
+                    if let Some(source) = settings.fallback_source.as_mut() {
+                        source.reset(raw);
+                    }
+                    true
+                }
                 UndoSubject::OrientationLimit => {
                     if let Ok(value) = histories.orientation_limit.apply(shortcut).parse::<NonZeroUsize>() {
                         settings.orientation_limit = playlist::OrientationLimit::Fixed(value);
                     }
                     true
                 }
+                UndoSubject::GridImageDuration => {
+                    if let Ok(value) = histories.image_duration.apply(shortcut).parse::<NonZeroUsize>() {
+                        if settings.image_duration.is_some() {
+                            settings.image_duration = Some(value);
+                        }
+                    }
+                    true
+                }
+                UndoSubject::GridClipLength => {
+                    if let Ok(value) = histories.clip_length.apply(shortcut).parse::<NonZeroUsize>() {
+                        if settings.clip_length.is_some() {
+                            settings.clip_length = Some(value);
+                        }
+                    }
+                    true
+                }
+                UndoSubject::GridName => {
+                    settings.set_name(histories.name.apply(shortcut));
+                    true
+                }
             },
         }
     }
@@ -595,7 +1278,17 @@ impl Modal {
             | Self::Errors { .. }
             | Self::AppUpdate { .. }
             | Self::ConfirmLoadPlaylist { .. }
-            | Self::ConfirmDiscardPlaylist { .. } => None,
+            | Self::ConfirmDiscardPlaylist { .. }
+            | Self::ConfirmExitWhilePlaying
+            | Self::ConfirmFolderGrids { .. }
+            | Self::ConfirmDeleteToTrash { .. } => None,
+            Self::ConfirmApplyGridSettingsToAll { grid_id, changes } => match event {
+                Event::Save => Some(Update::SavedAllGridSettings {
+                    grid_id: *grid_id,
+                    changes: changes.clone(),
+                }),
+                _ => None,
+            },
             Self::GridSettings {
                 grid_id,
                 tab,
@@ -630,14 +1323,86 @@ impl Modal {
                     settings.sources[index].set_kind(kind);
                     None
                 }
+                Event::EditedFallbackSourceEnabled { enabled } => {
+                    settings.fallback_source =
+                        enabled.then(|| media::Source::new_path(StrictPath::new(histories.fallback_source.current())));
+                    None
+                }
+                Event::EditedFallbackSourceKind { kind } => {
+                    if let Some(source) = settings.fallback_source.as_mut() {
+                        source.set_kind(kind);
+                    }
+                    None
+                }
+                Event::EditedFallbackSource { raw } => {
+                    histories.fallback_source.push(&raw);
+                    if let Some(source) = settings.fallback_source.as_mut() {
+                        source.reset(raw);
+                    }
+                    None
+                }
                 Event::SelectedGridTab { tab: new_tab } => {
                     *tab = new_tab;
                     None
                 }
+                Event::AppliedGridPreset { preset } => {
+                    *settings = preset.settings(settings);
+                    if let playlist::OrientationLimit::Fixed(limit) = settings.orientation_limit {
+                        histories.orientation_limit.push(&limit.to_string());
+                    }
+                    if let Some(duration) = settings.image_duration {
+                        histories.image_duration.push(&duration.to_string());
+                    }
+                    None
+                }
                 Event::EditedGridContentFit { content_fit } => {
                     settings.content_fit = content_fit;
                     None
                 }
+                Event::EditedGridFilterMethod { filter_method } => {
+                    settings.filter_method = filter_method;
+                    None
+                }
+                Event::EditedGridMirrorOf { grid_id } => {
+                    settings.mirror_of = grid_id;
+                    None
+                }
+                Event::EditedGridAspectOverride { aspect_override } => {
+                    settings.aspect_override = aspect_override;
+                    None
+                }
+                Event::EditedGridFilter { filter } => {
+                    settings.filter = filter;
+                    None
+                }
+                Event::EditedGridOnEndKind { custom } => {
+                    settings.on_end = custom.then(playlist::OnEnd::default);
+                    None
+                }
+                Event::EditedGridOnEnd { on_end } => {
+                    settings.on_end = Some(on_end);
+                    None
+                }
+                Event::EditedGridAllowDuplicatesOnScreen { allow } => {
+                    settings.allow_duplicates_on_screen = allow;
+                    None
+                }
+                Event::EditedGridShuffleOnFocus { shuffle } => {
+                    settings.shuffle_on_focus = shuffle;
+                    None
+                }
+                Event::EditedGridCycleInOrder { cycle } => {
+                    settings.cycle_in_order = cycle;
+                    None
+                }
+                Event::EditedGridShowCounter { show_counter } => {
+                    settings.show_counter = show_counter;
+                    None
+                }
+                Event::EditedGridOpacity { opacity } => {
+                    settings.opacity = opacity;
+                    None
+                }
                 Event::EditedGridOrientation { orientation } => {
                     settings.orientation = orientation;
                     None
@@ -664,12 +1429,78 @@ impl Modal {
                     }
                     None
                 }
+                Event::EditedGridImageDurationKind { custom } => {
+                    if custom {
+                        let duration = histories
+                            .image_duration
+                            .current()
+                            .parse::<NonZeroUsize>()
+                            .unwrap_or(Playback::default().image_duration);
+                        settings.image_duration = Some(duration);
+                    } else {
+                        settings.image_duration = None;
+                    }
+                    None
+                }
+                Event::EditedGridImageDuration { raw_duration } => {
+                    histories.image_duration.push(&raw_duration);
+                    if settings.image_duration.is_some() {
+                        if let Ok(duration) = raw_duration.parse::<NonZeroUsize>() {
+                            settings.image_duration = Some(duration);
+                        }
+                    }
+                    None
+                }
+                Event::EditedGridClipLengthKind { custom } => {
+                    if custom {
+                        let length = histories
+                            .clip_length
+                            .current()
+                            .parse::<NonZeroUsize>()
+                            .ok()
+                            .or(NonZeroUsize::new(grid::DEFAULT_CLIP_LENGTH));
+                        settings.clip_length = length;
+                    } else {
+                        settings.clip_length = None;
+                    }
+                    None
+                }
+                Event::EditedGridClipLength { raw_length } => {
+                    histories.clip_length.push(&raw_length);
+                    if settings.clip_length.is_some() {
+                        if let Ok(length) = raw_length.parse::<NonZeroUsize>() {
+                            settings.clip_length = Some(length);
+                        }
+                    }
+                    None
+                }
+                Event::EditedGridName { name } => {
+                    histories.name.push(&name);
+                    settings.set_name(name);
+                    None
+                }
+                Event::AppliedGridSettingsToAll => {
+                    let mut settings = settings.clone();
+                    settings.sources.retain(|source| !source.is_empty());
+
+                    Some(Update::ConfirmApplyGridSettingsToAll {
+                        grid_id: *grid_id,
+                        settings,
+                    })
+                }
                 Event::Save => {
                     for index in (0..settings.sources.len()).rev() {
                         if settings.sources[index].is_empty() {
                             settings.sources.remove(index);
                         }
                     }
+                    if settings
+                        .fallback_source
+                        .as_ref()
+                        .is_some_and(|source| source.is_empty())
+                    {
+                        settings.fallback_source = None;
+                    }
 
                     Some(Update::SavedGridSettings {
                         grid_id: *grid_id,
@@ -677,6 +1508,7 @@ impl Modal {
                     })
                 }
                 Event::PlayMedia(_) => None,
+                Event::ChoseTransferDestination { .. } => None,
             },
             Self::GridMedia { grid_id, .. } => match event {
                 Event::PlayMedia(media) => Some(Update::PlayMedia {
@@ -685,6 +1517,21 @@ impl Modal {
                 }),
                 _ => None,
             },
+            Self::Transfer {
+                grid_id,
+                player_id,
+                media,
+                transfer,
+            } => match event {
+                Event::ChoseTransferDestination { folder } => Some(Update::TransferMedia {
+                    grid_id: *grid_id,
+                    player_id: *player_id,
+                    media: media.clone(),
+                    transfer: *transfer,
+                    folder,
+                }),
+                _ => None,
+            },
         }
     }
 
@@ -697,6 +1544,7 @@ impl Modal {
         playlist: Option<&StrictPath>,
         collection: &media::Collection,
         active_media: HashSet<&Media>,
+        mirror_options: Vec<grid::MirrorOption>,
     ) -> Element {
         Stack::new()
             .push({
@@ -722,6 +1570,7 @@ impl Modal {
                     playlist,
                     collection,
                     active_media,
+                    mirror_options,
                 )))
                 .center(Length::Fill)
                 .padding([0.0, (100.0 + viewport.width - 640.0).clamp(0.0, 100.0)]),
@@ -759,5 +1608,9 @@ impl GridTab {
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct GridHistories {
     pub sources: Vec<TextHistory>,
+    pub fallback_source: TextHistory,
     pub orientation_limit: TextHistory,
+    pub image_duration: TextHistory,
+    pub clip_length: TextHistory,
+    pub name: TextHistory,
 }
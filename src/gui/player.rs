@@ -1,3 +1,12 @@
+#[cfg(feature = "audio")]
+use std::{collections::HashMap, sync::LazyLock};
+#[cfg(feature = "video")]
+use std::{
+    num::NonZeroU32,
+    sync::atomic::{AtomicBool, Ordering},
+};
+#[cfg(any(feature = "audio", feature = "video"))]
+use std::{num::NonZeroUsize, sync::Mutex};
 use std::{sync::Arc, time::Duration};
 
 use iced::{
@@ -18,7 +27,7 @@ type VideoPipeline = ();
 use crate::{
     gui::{
         button,
-        common::{Message, Step},
+        common::{Message, Step, Transfer},
         grid,
         icon::Icon,
         style,
@@ -28,14 +37,21 @@ use crate::{
     media::Media,
     path::StrictPath,
     prelude::{timestamp_hhmmss, timestamp_mmss},
-    resource::{config::Playback, playlist::ContentFit},
+    resource::{
+        config::{ClickToPause, Playback},
+        playlist::{AspectOverride, ContentFit, DisplayFilter, FilterMethod},
+    },
 };
 
-const IMAGE_STEP: Duration = Duration::from_secs(2);
-#[cfg(feature = "audio")]
-const AUDIO_STEP: Duration = Duration::from_secs(10);
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+const RETRY_DELAY_MAX: Duration = Duration::from_secs(8);
+const RETRY_ATTEMPTS_MAX: usize = 5;
 #[cfg(feature = "video")]
-const VIDEO_STEP: Duration = Duration::from_secs(10);
+const SEEK_PREVIEW_MIN_DURATION: Duration = Duration::from_secs(30);
+#[cfg(feature = "video")]
+const SEEK_PREVIEW_DOWNSCALE: u32 = 8;
+#[cfg(feature = "video")]
+const SEEK_PREVIEW_WIDTH: f32 = 160.0;
 
 fn timestamps<'a>(current: Duration, total: Duration) -> Element<'a> {
     let current = current.as_secs();
@@ -54,26 +70,798 @@ fn timestamps<'a>(current: Duration, total: Duration) -> Element<'a> {
         .into()
 }
 
+/// Decoded frames of an animated image.
+/// Each format has its own decoder, but they're all played the same way,
+/// so other animated formats only need a new variant here.
+pub enum Frames {
+    Gif(gif::Frames),
+    Apng(apng::Frames),
+}
+
+impl Frames {
+    fn view(&self, frame: Frame) -> Element {
+        match self {
+            Self::Gif(frames) => gif(frames)
+                .width(frame.width)
+                .height(frame.height)
+                .content_fit(frame.content_fit)
+                .into(),
+            Self::Apng(frames) => apng(frames)
+                .width(frame.width)
+                .height(frame.height)
+                .content_fit(frame.content_fit)
+                .into(),
+        }
+    }
+}
+
+/// Size and fit of the media within a player.
+#[derive(Debug, Clone, Copy)]
+struct Frame {
+    width: Length,
+    height: Length,
+    content_fit: iced::ContentFit,
+}
+
+impl Frame {
+    fn new(viewport: iced::Size, content_fit: ContentFit, aspect_override: AspectOverride) -> Self {
+        match aspect_override.ratio() {
+            Some(ratio) => {
+                let (width, height) = if viewport.width / viewport.height > ratio {
+                    (viewport.height * ratio, viewport.height)
+                } else {
+                    (viewport.width, viewport.width / ratio)
+                };
+
+                Self {
+                    width: Length::Fixed(width),
+                    height: Length::Fixed(height),
+                    content_fit: iced::ContentFit::Fill,
+                }
+            }
+            None => Self {
+                width: Length::Fill,
+                height: Length::Fill,
+                content_fit: match aspect_override {
+                    AspectOverride::Stretch => iced::ContentFit::Fill,
+                    AspectOverride::Original | AspectOverride::Widescreen | AspectOverride::Standard => {
+                        content_fit.into()
+                    }
+                },
+            },
+        }
+    }
+}
+
+/// Metadata embedded in an audio file, like ID3 tags or Vorbis comments.
+#[cfg(feature = "audio")]
+#[derive(Debug, Clone, Default)]
+struct AudioTags {
+    title: Option<String>,
+    artist: Option<String>,
+    album: Option<String>,
+    cover: Option<iced::widget::image::Handle>,
+    /// ReplayGain track gain, in decibels.
+    replay_gain: Option<f32>,
+}
+
+#[cfg(feature = "audio")]
+impl AudioTags {
+    fn read(source: &StrictPath) -> Self {
+        use symphonia::core::{
+            formats::FormatOptions,
+            io::MediaSourceStream,
+            meta::{MetadataOptions, StandardTagKey, StandardVisualKey},
+            probe::Hint,
+        };
+
+        let Ok(file) = source.as_std_path_buf().and_then(std::fs::File::open) else {
+            return Self::default();
+        };
+        let stream = MediaSourceStream::new(Box::new(file), Default::default());
+
+        let mut hint = Hint::new();
+        if let Some(extension) = source.file_extension() {
+            hint.with_extension(&extension);
+        }
+
+        let mut probed = match symphonia::default::get_probe().format(
+            &hint,
+            stream,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        ) {
+            Ok(probed) => probed,
+            Err(e) => {
+                log::debug!("Unable to read audio tags: {source:?} | {e:?}");
+                return Self::default();
+            }
+        };
+
+        // ID3 tags are found while probing the file,
+        // whereas Vorbis comments and MP4 atoms are part of the container format.
+        let mut revisions = vec![];
+        if let Some(revision) = probed.metadata.get().and_then(|metadata| metadata.current().cloned()) {
+            revisions.push(revision);
+        }
+        if let Some(revision) = probed.format.metadata().current().cloned() {
+            revisions.push(revision);
+        }
+
+        let mut tags = Self::default();
+        for revision in revisions {
+            for tag in revision.tags() {
+                if tag.std_key == Some(StandardTagKey::ReplayGainTrackGain)
+                    || tag.key.eq_ignore_ascii_case("replaygain_track_gain")
+                {
+                    if tags.replay_gain.is_none() {
+                        tags.replay_gain = parse_replay_gain(&tag.value.to_string());
+                    }
+                    continue;
+                }
+
+                let field = match tag.std_key {
+                    Some(StandardTagKey::TrackTitle) => &mut tags.title,
+                    Some(StandardTagKey::Artist) => &mut tags.artist,
+                    Some(StandardTagKey::Album) => &mut tags.album,
+                    _ => continue,
+                };
+
+                let value = tag.value.to_string();
+                if field.is_none() && !value.trim().is_empty() {
+                    *field = Some(value);
+                }
+            }
+
+            if tags.cover.is_none() {
+                let visuals = revision.visuals();
+                let cover = visuals
+                    .iter()
+                    .find(|visual| visual.usage == Some(StandardVisualKey::FrontCover))
+                    .or_else(|| visuals.first());
+
+                if let Some(cover) = cover {
+                    tags.cover = Some(iced::widget::image::Handle::from_bytes(cover.data.to_vec()));
+                }
+            }
+        }
+
+        tags
+    }
+
+    /// Artist and title, falling back to the file name.
+    fn label(&self, media: &Media) -> String {
+        match (&self.artist, &self.title) {
+            (Some(artist), Some(title)) => format!("{artist} - {title}"),
+            (None, Some(title)) => title.clone(),
+            (_, None) => media.path().file_stem().unwrap_or_else(|| media.path().render()),
+        }
+    }
+}
+
+/// When the media was captured, according to its EXIF data,
+/// or else when the file was last modified.
+fn media_date(media: &Media) -> Option<chrono::DateTime<chrono::Local>> {
+    if let Media::Image { path } = media {
+        if let Some(date) = exif_date(path) {
+            return Some(date);
+        }
+    }
+
+    media.path().get_mtime().ok().map(chrono::DateTime::from)
+}
+
+/// The result of `media_date`, which is only looked up when it's first needed,
+/// since that requires reading the file.
+#[derive(Clone, Debug, Default)]
+pub struct MediaDate(std::sync::OnceLock<Option<chrono::DateTime<chrono::Local>>>);
+
+impl MediaDate {
+    fn get(&self, media: &Media) -> Option<&chrono::DateTime<chrono::Local>> {
+        self.0.get_or_init(|| media_date(media)).as_ref()
+    }
+}
+
+fn exif_date(path: &StrictPath) -> Option<chrono::DateTime<chrono::Local>> {
+    let file = path.as_std_path_buf().and_then(std::fs::File::open).ok()?;
+    let exif = exif::Reader::new()
+        .read_from_container(&mut std::io::BufReader::new(file))
+        .ok()?;
+    let field = exif.get_field(exif::Tag::DateTimeOriginal, exif::In::PRIMARY)?;
+    let exif::Value::Ascii(values) = &field.value else {
+        return None;
+    };
+    let date = exif::DateTime::from_ascii(values.first()?).ok()?;
+
+    chrono::NaiveDate::from_ymd_opt(date.year.into(), date.month.into(), date.day.into())?
+        .and_hms_opt(date.hour.into(), date.minute.into(), date.second.into())?
+        .and_local_timezone(chrono::Local)
+        .earliest()
+}
+
+fn media_date_label<'a>(date: Option<&chrono::DateTime<chrono::Local>>) -> Option<Element<'a>> {
+    date.map(|date| text(date.format("%Y-%m-%d %H:%M").to_string()).size(12).into())
+}
+
+/// Parse a ReplayGain value like `-6.48 dB`.
+#[cfg(feature = "audio")]
+fn parse_replay_gain(value: &str) -> Option<f32> {
+    let value = value.trim();
+    let value = value
+        .strip_suffix("dB")
+        .or_else(|| value.strip_suffix("db"))
+        .unwrap_or(value);
+    value.trim().parse().ok()
+}
+
+/// Loudness that normalized audio should be adjusted toward, in dBFS.
+#[cfg(feature = "audio")]
+const NORMALIZED_LOUDNESS: f32 = -18.0;
+
+/// How much of a track to scan when it doesn't have a ReplayGain tag.
+#[cfg(feature = "audio")]
+const NORMALIZATION_SCAN: Duration = Duration::from_secs(30);
+
+#[cfg(feature = "audio")]
+enum Loudness {
+    Scanning,
+    Scanned(Option<f32>),
+}
+
+/// Loudness scans by path, so that each track is only scanned once per session.
+#[cfg(feature = "audio")]
+static LOUDNESS: LazyLock<Mutex<HashMap<StrictPath, Loudness>>> = LazyLock::new(Default::default);
+
+/// A layer to put over media so that it appears dimmer.
+fn dimmer<'a>(opacity: u8) -> Option<Container<'a>> {
+    let opacity = opacity.clamp(grid::MIN_OPACITY, grid::MAX_OPACITY);
+    (opacity < grid::MAX_OPACITY).then(|| {
+        Container::new("").center(Length::Fill).class(style::Container::Dimmer {
+            opacity: f32::from(opacity) / 100.0,
+        })
+    })
+}
+
+/// Approximate memory used by an image once it's decoded for display.
+fn estimated_image_bytes(handle: &iced::widget::image::Handle) -> usize {
+    use iced::widget::image::Handle;
+
+    match handle {
+        Handle::Path(..) => 0,
+        Handle::Bytes(_, bytes) => image::ImageReader::new(std::io::Cursor::new(bytes.as_ref()))
+            .with_guessed_format()
+            .ok()
+            .and_then(|reader| reader.into_dimensions().ok())
+            .map(|(width, height)| width as usize * height as usize * 4)
+            .unwrap_or(bytes.len()),
+        Handle::Rgba { pixels, .. } => pixels.len(),
+    }
+}
+
+/// Approximate memory used by an animation once every frame is decoded for display.
+fn estimated_animation_bytes(media: &Media, bytes: &[u8]) -> usize {
+    let size = match media {
+        Media::Apng { .. } => png::Decoder::new(std::io::Cursor::new(bytes))
+            .read_info()
+            .ok()
+            .map(|reader| {
+                let info = reader.info();
+                let frames = info.animation_control.map(|control| control.num_frames as usize);
+                (info.width as usize, info.height as usize, frames.unwrap_or(1))
+            }),
+        _ => bytes.get(6..10).map(|screen| {
+            let width = u16::from_le_bytes([screen[0], screen[1]]) as usize;
+            let height = u16::from_le_bytes([screen[2], screen[3]]) as usize;
+            (width, height, count_gif_frames(bytes).unwrap_or(1))
+        }),
+    };
+
+    size.map(|(width, height, frames)| width * height * 4 * frames.max(1))
+        .unwrap_or(bytes.len())
+}
+
+/// Count the images in a GIF by walking its blocks, without decoding them.
+fn count_gif_frames(bytes: &[u8]) -> Option<usize> {
+    let color_table_size = |flags: u8| {
+        if flags & 0x80 != 0 {
+            3 * (1 << ((flags & 0x07) + 1))
+        } else {
+            0
+        }
+    };
+    let skip_sub_blocks = |mut index: usize| loop {
+        let length = *bytes.get(index)? as usize;
+        index += 1;
+        if length == 0 {
+            return Some(index);
+        }
+        index += length;
+    };
+
+    // Header and logical screen descriptor.
+    let mut index = 13 + color_table_size(*bytes.get(10)?);
+    let mut frames = 0;
+
+    loop {
+        match *bytes.get(index)? {
+            // Image descriptor, then the LZW minimum code size, then the image data.
+            0x2C => {
+                frames += 1;
+                index += 10 + color_table_size(*bytes.get(index + 9)?) + 1;
+                index = skip_sub_blocks(index)?;
+            }
+            // Extension label, then its data.
+            0x21 => {
+                index = skip_sub_blocks(index + 2)?;
+            }
+            // Trailer.
+            0x3B => return Some(frames),
+            _ => return None,
+        }
+    }
+}
+
+/// A random part of the media to play instead of the whole thing,
+/// when the grid has a clip length.
+#[cfg(any(feature = "audio", feature = "video"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Clip {
+    start: Duration,
+    end: Duration,
+}
+
+#[cfg(any(feature = "audio", feature = "video"))]
+impl Clip {
+    /// Media that isn't longer than the clip length is played in full.
+    fn random(duration: Duration, clip_length: Option<NonZeroUsize>) -> Option<Self> {
+        use rand::Rng;
+
+        let length = Duration::from_secs(clip_length?.get() as u64);
+        if duration <= length {
+            return None;
+        }
+
+        let start = Duration::from_secs_f64(rand::rng().random_range(0.0..(duration - length).as_secs_f64()));
+        Some(Self {
+            start,
+            end: start + length,
+        })
+    }
+}
+
+/// Volume multiplier to bring a track to a consistent loudness.
+/// This uses the ReplayGain tag if available,
+/// or else estimates the loudness from the start of the track.
+/// Returns `None` while the estimate is still in progress.
+#[cfg(feature = "audio")]
+fn audio_gain(source: &StrictPath, tags: &AudioTags, playback: &Playback) -> Option<f32> {
+    if !playback.normalize_audio {
+        return Some(1.0);
+    }
+
+    let decibels = match tags.replay_gain {
+        Some(decibels) => decibels,
+        None => match cached_audio_loudness(source)? {
+            Some(loudness) => NORMALIZED_LOUDNESS - loudness,
+            None => return Some(1.0),
+        },
+    };
+
+    // Avoid extreme adjustments, like boosting a mostly silent track.
+    Some(10f32.powf(decibels / 20.0).clamp(0.1, 4.0))
+}
+
+/// Sink volume for a track, without boosting past full volume,
+/// since that would clip the audio.
+#[cfg(feature = "audio")]
+fn audio_volume(volume: f32, gain: f32) -> f32 {
+    (volume * gain).min(1.0)
+}
+
+/// Decoding part of a track can take a while,
+/// so the scan runs in the background and this returns `None` until it's done.
+#[cfg(feature = "audio")]
+fn cached_audio_loudness(source: &StrictPath) -> Option<Option<f32>> {
+    let mut cache = LOUDNESS.lock().unwrap();
+
+    match cache.get(source) {
+        Some(Loudness::Scanned(loudness)) => Some(*loudness),
+        Some(Loudness::Scanning) => None,
+        None => {
+            cache.insert(source.clone(), Loudness::Scanning);
+
+            let source = source.clone();
+            std::thread::spawn(move || {
+                let loudness = scan_audio_loudness(&source);
+                LOUDNESS.lock().unwrap().insert(source, Loudness::Scanned(loudness));
+            });
+
+            None
+        }
+    }
+}
+
+/// Root mean square loudness of the start of a track, in dBFS.
+#[cfg(feature = "audio")]
+fn scan_audio_loudness(source: &StrictPath) -> Option<f32> {
+    use rodio::Source;
+
+    let file = source.open_buffered().ok()?;
+    let decoder = match rodio::Decoder::new(file) {
+        Ok(decoder) => decoder,
+        Err(e) => {
+            log::debug!("Unable to scan audio loudness: {source:?} | {e:?}");
+            return None;
+        }
+    };
+
+    let limit = decoder.sample_rate() as u64 * decoder.channels() as u64 * NORMALIZATION_SCAN.as_secs();
+    let mut sum = 0.0;
+    let mut count = 0u64;
+    for sample in decoder.take(limit as usize) {
+        let sample = sample as f64 / i16::MAX as f64;
+        sum += sample * sample;
+        count += 1;
+    }
+
+    if count == 0 || sum == 0.0 {
+        return None;
+    }
+
+    let rms = (sum / count as f64).sqrt();
+    Some(20.0 * rms.log10() as f32)
+}
+
+/// Thumbnails at evenly spaced positions in a video,
+/// shown above the seek bar while dragging it.
+#[cfg(feature = "video")]
+#[derive(Default)]
+struct SeekPreviews {
+    /// What to generate, until it's been started.
+    request: Option<(url::Url, DisplayFilter)>,
+    count: usize,
+    interval: Duration,
+    handles: Arc<Mutex<Vec<iced::widget::image::Handle>>>,
+    /// Set when the player moves on, so that the background work can stop early.
+    cancelled: Arc<AtomicBool>,
+}
+
+#[cfg(feature = "video")]
+impl SeekPreviews {
+    fn new(uri: url::Url, duration: Duration, count: usize, filter: DisplayFilter) -> Self {
+        if count == 0 || duration < SEEK_PREVIEW_MIN_DURATION {
+            return Self::default();
+        }
+
+        Self {
+            request: Some((uri, filter)),
+            count,
+            interval: duration / count as u32,
+            ..Default::default()
+        }
+    }
+
+    /// Generate the thumbnails in the background with a separate instance of the video,
+    /// so that the visible player is not interrupted.
+    /// This waits until the player is first hovered,
+    /// so that a wall of videos doesn't run a second pipeline for each one.
+    fn start(&mut self) {
+        let Some((uri, filter)) = self.request.take() else {
+            return;
+        };
+
+        let count = self.count;
+        let interval = self.interval;
+        let output = self.handles.clone();
+        let cancelled = self.cancelled.clone();
+        std::thread::spawn(move || {
+            let mut video = match build_video(&uri, None, filter) {
+                Ok(video) => video,
+                Err(e) => {
+                    log::warn!("Unable to generate seek previews: {uri} | {e:?}");
+                    return;
+                }
+            };
+            mute_video(&mut video, true);
+            video.set_paused(true);
+
+            for i in 0..count {
+                if cancelled.load(Ordering::Relaxed) {
+                    log::debug!("Cancelled seek previews: {uri}");
+                    return;
+                }
+
+                let position = iced_video_player::Position::Time(interval * i as u32);
+                match video.thumbnails(vec![position], NonZeroU32::new(SEEK_PREVIEW_DOWNSCALE).unwrap()) {
+                    Ok(mut generated) => {
+                        if let Ok(mut handles) = output.lock() {
+                            handles.append(&mut generated);
+                        }
+                    }
+                    Err(e) => {
+                        log::warn!("Unable to generate seek previews: {uri} | {e:?}");
+                        return;
+                    }
+                }
+            }
+        });
+    }
+
+    fn nearest(&self, position: Duration) -> Option<iced::widget::image::Handle> {
+        if self.interval.is_zero() {
+            return None;
+        }
+
+        let handles = self.handles.lock().ok()?;
+        let index = (position.as_secs_f64() / self.interval.as_secs_f64()).round() as usize;
+        handles.get(index.min(self.count.saturating_sub(1))).cloned()
+    }
+}
+
+#[cfg(feature = "video")]
+impl Drop for SeekPreviews {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Based on `iced_video_player::Video::new`,
+/// but without a text sink so that the built-in subtitle functionality triggers.
+#[cfg(feature = "video")]
+const DEFAULT_VIDEO_PIPELINE: &str = r#"playbin uri="{uri}" video-sink="videoscale ! videoconvert ! appsink name=iced_video drop=true caps=video/x-raw,format=NV12,pixel-aspect-ratio=1/1""#;
+
+#[cfg(feature = "video")]
+static VIDEO_PIPELINE: Mutex<Option<String>> = Mutex::new(None);
+
+/// Sidecar subtitle files are checked in this order.
+#[cfg(feature = "video")]
+const SUBTITLE_EXTENSIONS: &[&str] = &["srt", "ass", "ssa"];
+
+/// Find a subtitle file next to the video with the same name,
+/// like `movie.srt` for `movie.mp4`.
+#[cfg(feature = "video")]
+fn find_subtitles(video: &StrictPath) -> Option<StrictPath> {
+    let parent = video.parent()?;
+    let stem = video.file_stem()?;
+
+    SUBTITLE_EXTENSIONS
+        .iter()
+        .map(|extension| parent.joined(&format!("{stem}.{extension}")))
+        .find(|path| path.is_file())
+}
+
+/// Converted subtitles are kept in a separate folder for each process,
+/// so that multiple instances of the app don't remove each other's copies.
+fn converted_subtitles_root() -> StrictPath {
+    StrictPath::from(std::env::temp_dir()).joined("madamiru-subtitles")
+}
+
+fn converted_subtitles_dir() -> StrictPath {
+    converted_subtitles_root().joined(&std::process::id().to_string())
+}
+
+/// Remove the subtitles that this process converted.
+pub fn remove_converted_subtitles() {
+    let dir = converted_subtitles_dir();
+    if let Err(e) = dir.remove() {
+        log::warn!("Unable to remove converted subtitles: {dir:?} | {e:?}");
+    }
+}
+
+/// Remove converted subtitles left behind by previous runs that didn't exit cleanly.
+pub fn prune_converted_subtitles() {
+    const MAX_AGE: Duration = Duration::from_secs(60 * 60 * 24);
+
+    let root = converted_subtitles_root();
+    let Ok(entries) = root.read_dir() else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let stale = entry
+            .metadata()
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age > MAX_AGE);
+        if !stale {
+            continue;
+        }
+
+        let path = StrictPath::from(entry.path());
+        if let Err(e) = path.remove() {
+            log::warn!("Unable to remove stale subtitles: {path:?} | {e:?}");
+        }
+    }
+}
+
+/// GStreamer assumes that subtitles are UTF-8,
+/// so other encodings are converted into a temporary copy.
+#[cfg(feature = "video")]
+fn subtitles_uri(path: &StrictPath) -> Option<url::Url> {
+    let bytes = match path.try_read_bytes() {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            log::warn!("Unable to read subtitles: {path:?} | {e:?}");
+            return None;
+        }
+    };
+
+    let mut detector = chardetng::EncodingDetector::new();
+    detector.feed(&bytes, true);
+    let encoding = detector.guess(None, true);
+
+    let readable = if encoding == encoding_rs::UTF_8 {
+        path.clone()
+    } else {
+        log::debug!("Converting subtitles from {}: {path:?}", encoding.name());
+        let (content, _, malformed) = encoding.decode(&bytes);
+        if malformed {
+            log::warn!("Subtitles contain invalid characters for {}: {path:?}", encoding.name());
+        }
+
+        let hash = {
+            use std::hash::{Hash, Hasher};
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            path.hash(&mut hasher);
+            hasher.finish()
+        };
+        let converted =
+            converted_subtitles_dir().joined(&format!("{hash:016x}.{}", path.file_extension().unwrap_or_default()));
+
+        if let Err(e) = converted
+            .create_parent_dir()
+            .and_then(|_| converted.write_with_content(&content))
+        {
+            log::warn!("Unable to convert subtitles: {path:?} -> {converted:?} | {e:?}");
+            return None;
+        }
+        converted
+    };
+
+    url::Url::from_file_path(readable.as_std_path_buf().ok()?).ok()
+}
+
+/// Display filters are inserted right before this part of the pipeline.
+#[cfg(feature = "video")]
+#[realia::dep_since("madamiru", "iced_video_player", "0.6.0")]
+const VIDEO_SINK: &str = "appsink name=iced_video";
+
+/// GStreamer doesn't have a plain color inversion element,
+/// so this does the same thing as `DisplayFilter::apply` in a shader.
+/// The shader is set on the element after parsing,
+/// since it can't be quoted inside of the `video-sink` property.
+#[cfg(feature = "video")]
+#[realia::dep_since("madamiru", "iced_video_player", "0.6.0")]
+const VIDEO_INVERT_ELEMENT: &str = "glupload ! glcolorconvert ! glshader name=madamiru_invert ! gldownload";
+
+#[cfg(feature = "video")]
+#[realia::dep_since("madamiru", "iced_video_player", "0.6.0")]
+const VIDEO_INVERT_NAME: &str = "madamiru_invert";
+
+#[cfg(feature = "video")]
+#[realia::dep_since("madamiru", "iced_video_player", "0.6.0")]
+const VIDEO_INVERT_SHADER: &str = concat!(
+    "#version 100\n",
+    "#ifdef GL_ES\n",
+    "precision mediump float;\n",
+    "#endif\n",
+    "varying vec2 v_texcoord;\n",
+    "uniform sampler2D tex;\n",
+    "void main () {\n",
+    "  vec4 color = texture2D(tex, v_texcoord);\n",
+    "  gl_FragColor = vec4(1.0 - color.rgb, color.a);\n",
+    "}\n",
+);
+
+#[cfg(feature = "video")]
+#[realia::dep_since("madamiru", "iced_video_player", "0.6.0")]
+fn video_filter_element(filter: DisplayFilter) -> Option<&'static str> {
+    match filter {
+        DisplayFilter::None => None,
+        DisplayFilter::Grayscale => Some("videobalance saturation=0.0"),
+        DisplayFilter::Sepia => Some("coloreffects preset=sepia"),
+        DisplayFilter::Invert => {
+            if gstreamer::ElementFactory::find("glshader").is_some() {
+                Some(VIDEO_INVERT_ELEMENT)
+            } else {
+                log::warn!("Unable to invert video colors without GStreamer's OpenGL plugin");
+                None
+            }
+        }
+    }
+}
+
+/// Use a custom pipeline template for all videos loaded after this point.
+/// If the template is invalid, then the built-in one is used instead.
 #[cfg(feature = "video")]
 #[realia::dep_since("madamiru", "iced_video_player", "0.6.0")]
-fn build_video(uri: &url::Url) -> Result<iced_video_player::Video, iced_video_player::Error> {
-    // Based on `iced_video_player::Video::new`,
-    // but without a text sink so that the built-in subtitle functionality triggers.
+pub fn set_video_pipeline(template: Option<&str>) -> Result<(), crate::prelude::Error> {
+    use gstreamer as gst;
+    use gstreamer::prelude::*;
+
+    let validated = match template {
+        None => Ok(None),
+        Some(template) => {
+            if !template.contains("{uri}") {
+                Err("missing `{uri}` placeholder".to_string())
+            } else if !template.contains("name=iced_video") {
+                Err("missing `appsink name=iced_video`".to_string())
+            } else {
+                gst::init()
+                    .map_err(|e| e.to_string())
+                    .and_then(|_| gst::parse::launch(&template.replace("{uri}", "file:///")).map_err(|e| e.to_string()))
+                    .and_then(|pipeline| {
+                        pipeline
+                            .downcast::<VideoPipeline>()
+                            .map_err(|_| "not a `playbin` pipeline".to_string())
+                    })
+                    .map(|_| Some(template.to_string()))
+            }
+        }
+    };
+
+    match validated {
+        Ok(template) => {
+            *VIDEO_PIPELINE.lock().unwrap() = template;
+            Ok(())
+        }
+        Err(why) => {
+            log::warn!("Invalid custom video pipeline, using default: {template:?} | {why}");
+            *VIDEO_PIPELINE.lock().unwrap() = None;
+            Err(crate::prelude::Error::VideoPipelineInvalid { why })
+        }
+    }
+}
+
+#[cfg(feature = "video")]
+#[realia::dep_before("madamiru", "iced_video_player", "0.6.0")]
+pub fn set_video_pipeline(template: Option<&str>) -> Result<(), crate::prelude::Error> {
+    if template.is_some() {
+        log::warn!("Custom video pipeline is not supported with this version of iced_video_player");
+    }
+    Ok(())
+}
 
+#[cfg(feature = "video")]
+#[realia::dep_since("madamiru", "iced_video_player", "0.6.0")]
+fn build_video(
+    uri: &url::Url,
+    subtitles: Option<&url::Url>,
+    filter: DisplayFilter,
+) -> Result<iced_video_player::Video, iced_video_player::Error> {
     use gstreamer as gst;
     use gstreamer_app as gst_app;
     use gstreamer_app::prelude::*;
 
     gst::init()?;
 
-    let pipeline = format!(
-        r#"playbin uri="{}" video-sink="videoscale ! videoconvert ! appsink name=iced_video drop=true caps=video/x-raw,format=NV12,pixel-aspect-ratio=1/1""#,
-        uri.as_str()
-    );
+    let template = VIDEO_PIPELINE
+        .lock()
+        .ok()
+        .and_then(|x| x.clone())
+        .unwrap_or_else(|| DEFAULT_VIDEO_PIPELINE.to_string());
+    let mut pipeline = template.replace("{uri}", uri.as_str());
+    if let Some(element) = video_filter_element(filter) {
+        if pipeline.contains(VIDEO_SINK) {
+            pipeline = pipeline.replacen(VIDEO_SINK, &format!("{element} ! videoconvert ! {VIDEO_SINK}"), 1);
+        } else {
+            log::warn!("Unable to apply display filter to custom video pipeline: {filter:?}");
+        }
+    }
+    log::debug!("Video pipeline: {pipeline}");
+
     let pipeline = gst::parse::launch(pipeline.as_ref())?
         .downcast::<VideoPipeline>()
         .map_err(|_| iced_video_player::Error::Cast)?;
 
+    if let Some(subtitles) = subtitles {
+        // Custom pipelines might not use `playbin`.
+        if pipeline.find_property("suburi").is_some() {
+            pipeline.set_property("suburi", subtitles.as_str());
+        }
+    }
+
     let video_sink: gst::Element = pipeline.property("video-sink");
     let pad = video_sink.pads().first().cloned().unwrap();
     let pad = pad.dynamic_cast::<gst::GhostPad>().unwrap();
@@ -81,12 +869,20 @@ fn build_video(uri: &url::Url) -> Result<iced_video_player::Video, iced_video_pl
     let video_sink = bin.by_name("iced_video").unwrap();
     let video_sink = video_sink.downcast::<gst_app::AppSink>().unwrap();
 
+    if let Some(shader) = bin.by_name(VIDEO_INVERT_NAME) {
+        shader.set_property("fragment", VIDEO_INVERT_SHADER);
+    }
+
     iced_video_player::Video::from_gst_pipeline(pipeline, video_sink, None)
 }
 
 #[cfg(feature = "video")]
 #[realia::dep_before("madamiru", "iced_video_player", "0.6.0")]
-fn build_video(uri: &url::Url) -> Result<iced_video_player::Video, iced_video_player::Error> {
+fn build_video(
+    uri: &url::Url,
+    _subtitles: Option<&url::Url>,
+    _filter: DisplayFilter,
+) -> Result<iced_video_player::Video, iced_video_player::Error> {
     iced_video_player::Video::new(uri)
 }
 
@@ -116,6 +912,29 @@ fn get_video_duration(_pipeline: &VideoPipeline) -> Option<gstreamer::ClockTime>
     None
 }
 
+/// Check the bus for the latest buffering progress, if it has changed.
+/// Other messages are left for the video player to handle.
+#[cfg(feature = "video")]
+#[realia::dep_since("madamiru", "iced_video_player", "0.6.0")]
+fn get_video_buffering(pipeline: &VideoPipeline) -> Option<bool> {
+    use gstreamer::prelude::ElementExt;
+
+    let bus = pipeline.bus()?;
+    let mut buffering = None;
+    while let Some(message) = bus.pop_filtered(&[gstreamer::MessageType::Buffering]) {
+        if let gstreamer::MessageView::Buffering(message) = message.view() {
+            buffering = Some(message.percent() < 100);
+        }
+    }
+    buffering
+}
+
+#[cfg(feature = "video")]
+#[realia::dep_before("madamiru", "iced_video_player", "0.6.0")]
+fn get_video_buffering(_pipeline: &VideoPipeline) -> Option<bool> {
+    None
+}
+
 #[cfg(feature = "video")]
 #[realia::dep_since("madamiru", "iced_video_player", "0.6.0")]
 fn get_video_position(pipeline: &VideoPipeline, _video: &iced_video_player::Video) -> Option<Duration> {
@@ -133,16 +952,11 @@ fn get_video_position(_pipeline: &VideoPipeline, video: &iced_video_player::Vide
 
 #[cfg(feature = "video")]
 #[realia::dep_since("madamiru", "iced_video_player", "0.6.0")]
-fn build_video_player(
-    video: &iced_video_player::Video,
-    grid_id: grid::Id,
-    player_id: Id,
-    content_fit: ContentFit,
-) -> Element {
+fn build_video_player(video: &iced_video_player::Video, grid_id: grid::Id, player_id: Id, frame: Frame) -> Element {
     iced_video_player::VideoPlayer::new(video)
-        .width(Length::Fill)
-        .height(Length::Fill)
-        .content_fit(content_fit.into())
+        .width(frame.width)
+        .height(frame.height)
+        .content_fit(frame.content_fit)
         .on_end_of_stream(Message::Player {
             grid_id,
             player_id,
@@ -158,12 +972,7 @@ fn build_video_player(
 
 #[cfg(feature = "video")]
 #[realia::dep_before("madamiru", "iced_video_player", "0.6.0")]
-fn build_video_player(
-    video: &iced_video_player::Video,
-    grid_id: grid::Id,
-    player_id: Id,
-    _content_fit: ContentFit,
-) -> Element {
+fn build_video_player(video: &iced_video_player::Video, grid_id: grid::Id, player_id: Id, _frame: Frame) -> Element {
     iced_video_player::VideoPlayer::new(video)
         .on_end_of_stream(Message::Player {
             grid_id,
@@ -215,6 +1024,24 @@ fn seek_video(video: &mut iced_video_player::Video, position: Duration) {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Id(pub usize);
 
+/// Lets us look up where a player is on screen, such as to tell which one received a file drop.
+pub fn container_id(grid_id: grid::Id, player_id: Id) -> iced::widget::Id {
+    iced::widget::Id::from(format!("player-{grid_id:?}-{}", player_id.0))
+}
+
+/// Display settings that apply to every player, regardless of its grid.
+#[derive(Debug, Clone, Copy)]
+pub struct ViewOptions {
+    pub click_to_pause: ClickToPause,
+    /// Below this size, a player shows a notice instead of its media.
+    pub min_tile_px: u16,
+    pub tile_padding: f32,
+    pub show_media_date: bool,
+    pub show_info: bool,
+    pub show_progress_bars: bool,
+    pub kiosk: bool,
+}
+
 #[derive(Debug)]
 pub enum Error {
     #[cfg(feature = "audio")]
@@ -229,6 +1056,22 @@ pub enum Error {
 }
 
 impl Error {
+    /// Whether the error might go away on its own,
+    /// like if a network drive is temporarily unavailable.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            #[cfg(feature = "audio")]
+            Self::Audio(_) => false,
+            Self::Image(_) => false,
+            Self::Io(_) => true,
+            Self::Path(_) => false,
+            #[cfg(feature = "video")]
+            Self::Url => false,
+            #[cfg(feature = "video")]
+            Self::Video(_) => false,
+        }
+    }
+
     pub fn message(&self) -> String {
         match self {
             #[cfg(feature = "audio")]
@@ -287,6 +1130,16 @@ impl From<apng::Error> for Error {
     }
 }
 
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panic".to_string()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Event {
     SetPause(bool),
@@ -304,6 +1157,15 @@ pub enum Event {
     MouseEnter,
     MouseExit,
     Refresh,
+    Stop,
+    DeleteToTrash,
+    Transfer(Transfer),
+    /// Stop showing the current media for the rest of the session.
+    Blacklist,
+    /// Switch this player to the next content fit,
+    /// starting from the grid's content fit if there's no override yet.
+    CycleContentFit(ContentFit),
+    PinContentFit(bool),
     Close,
     WindowFocused,
     WindowUnfocused,
@@ -326,7 +1188,15 @@ pub enum Update {
     Step(Step),
     EndOfStream,
     Refresh,
+    Stop,
+    DeleteToTrash(Media),
+    Blacklist(Media),
+    Transfer {
+        media: Media,
+        transfer: Transfer,
+    },
     Close,
+    Retry,
 }
 
 impl Update {
@@ -355,6 +1225,48 @@ pub enum Category {
     Video,
 }
 
+impl Category {
+    /// Categories of actual media, as opposed to `Other`.
+    pub fn media() -> Vec<Self> {
+        #[allow(unused_mut)]
+        let mut out = vec![Self::Image];
+        #[cfg(feature = "audio")]
+        out.push(Self::Audio);
+        #[cfg(feature = "video")]
+        out.push(Self::Video);
+        out
+    }
+
+    pub fn icon(&self) -> Icon {
+        match self {
+            Self::Other => Icon::File,
+            Self::Image => Icon::Image,
+            #[cfg(feature = "audio")]
+            Self::Audio => Icon::Music,
+            #[cfg(feature = "video")]
+            Self::Video => Icon::Movie,
+        }
+    }
+
+    /// Describe toggling the pause state of all media in this category.
+    pub fn toggle_pause_label(&self, paused: bool) -> String {
+        match (self, paused) {
+            (Self::Other, true) => lang::action::play(),
+            (Self::Other, false) => lang::action::pause(),
+            (Self::Image, true) => lang::action::play_all_images(),
+            (Self::Image, false) => lang::action::pause_all_images(),
+            #[cfg(feature = "audio")]
+            (Self::Audio, true) => lang::action::play_all_audio(),
+            #[cfg(feature = "audio")]
+            (Self::Audio, false) => lang::action::pause_all_audio(),
+            #[cfg(feature = "video")]
+            (Self::Video, true) => lang::action::play_all_videos(),
+            #[cfg(feature = "video")]
+            (Self::Video, false) => lang::action::pause_all_videos(),
+        }
+    }
+}
+
 pub enum Player {
     Idle {
         hovered: bool,
@@ -363,10 +1275,18 @@ pub enum Player {
         media: Media,
         message: String,
         hovered: bool,
+        /// How many times we've automatically tried to load the media again.
+        attempts: usize,
+        /// Time until the next automatic attempt, if any.
+        retry_in: Option<Duration>,
     },
     Image {
         media: Media,
+        /// When the media was captured or last modified.
+        date: MediaDate,
         handle: iced::widget::image::Handle,
+        /// Approximate memory used by the decoded image, in bytes.
+        memory: usize,
         position: Duration,
         duration: Duration,
         paused: bool,
@@ -375,9 +1295,14 @@ pub enum Player {
         dragging: bool,
         hovered: bool,
         need_play_on_focus: bool,
+        /// Overrides the grid's content fit for just this player.
+        content_fit: Option<ContentFit>,
+        /// Whether to keep `content_fit` when switching to new media.
+        content_fit_pinned: bool,
     },
     Svg {
         media: Media,
+        date: MediaDate,
         handle: iced::widget::svg::Handle,
         position: Duration,
         duration: Duration,
@@ -387,24 +1312,16 @@ pub enum Player {
         dragging: bool,
         hovered: bool,
         need_play_on_focus: bool,
+        content_fit: Option<ContentFit>,
+        content_fit_pinned: bool,
     },
-    Gif {
-        media: Media,
-        frames: gif::Frames,
-        handle: iced::widget::image::Handle,
-        position: Duration,
-        duration: Duration,
-        paused: bool,
-        muted: bool,
-        looping: bool,
-        dragging: bool,
-        hovered: bool,
-        need_play_on_focus: bool,
-    },
-    Apng {
+    Animation {
         media: Media,
-        frames: apng::Frames,
+        date: MediaDate,
+        frames: Frames,
         handle: iced::widget::image::Handle,
+        /// Approximate memory used by all of the decoded frames, in bytes.
+        memory: usize,
         position: Duration,
         duration: Duration,
         paused: bool,
@@ -413,32 +1330,54 @@ pub enum Player {
         dragging: bool,
         hovered: bool,
         need_play_on_focus: bool,
+        content_fit: Option<ContentFit>,
+        content_fit_pinned: bool,
     },
     #[cfg(feature = "audio")]
     Audio {
         media: Media,
+        date: MediaDate,
         // We must hold the stream for as long as the sink.
         #[allow(unused)]
         stream: rodio::OutputStream,
         sink: rodio::Sink,
+        tags: AudioTags,
+        /// Volume multiplier from audio normalization.
+        gain: f32,
+        /// Whether the loudness is still being scanned,
+        /// in which case `gain` will be updated once it's done.
+        gain_pending: bool,
         duration: Duration,
+        clip: Option<Clip>,
         paused: bool,
         looping: bool,
         dragging: bool,
         hovered: bool,
         need_play_on_focus: bool,
+        content_fit: Option<ContentFit>,
+        content_fit_pinned: bool,
     },
     #[cfg(feature = "video")]
     Video {
         media: Media,
+        date: MediaDate,
         video: iced_video_player::Video,
         pipeline: VideoPipeline,
+        seek_previews: SeekPreviews,
         position: Duration,
         duration: Duration,
+        clip: Option<Clip>,
         paused: bool,
+        /// Whether the pipeline is waiting for more data,
+        /// such as when the file is still being downloaded.
+        buffering: bool,
+        /// A sidecar subtitle file that was loaded along with the video.
+        subtitles: Option<StrictPath>,
         dragging: bool,
         hovered: bool,
         need_play_on_focus: bool,
+        content_fit: Option<ContentFit>,
+        content_fit_pinned: bool,
     },
 }
 
@@ -451,10 +1390,84 @@ impl Default for Player {
 impl Player {
     #[allow(clippy::result_large_err)]
     pub fn new(media: &Media, playback: &Playback) -> Result<Self, Self> {
+        // Decoding is handled by other libraries, so we contain any panics to this one player
+        // instead of letting them take down the whole application.
+        // The panic hook will still log the details.
+        match std::panic::catch_unwind(|| Self::load(media, playback)) {
+            Ok(player) => player,
+            Err(payload) => Err(Self::Error {
+                media: media.clone(),
+                message: panic_message(payload.as_ref()),
+                hovered: false,
+                attempts: 0,
+                retry_in: None,
+            }),
+        }
+    }
+
+    fn error(media: &Media, error: &Error) -> Self {
+        Self::Error {
+            media: media.clone(),
+            message: error.message(),
+            hovered: false,
+            attempts: 0,
+            retry_in: error.is_transient().then_some(RETRY_DELAY),
+        }
+    }
+
+    /// Try loading the media again after a transient error,
+    /// waiting longer after each failed attempt.
+    pub fn retry(&mut self, playback: &Playback) -> Result<(), ()> {
+        let Self::Error {
+            media,
+            attempts,
+            hovered,
+            ..
+        } = self
+        else {
+            return Ok(());
+        };
+
+        let media = media.clone();
+        let attempts = *attempts + 1;
+        let hovered = *hovered;
+
+        let result = match Self::new(&media, playback) {
+            Ok(player) => {
+                *self = player;
+                Ok(())
+            }
+            Err(mut player) => {
+                if let Self::Error {
+                    attempts: new_attempts,
+                    retry_in,
+                    ..
+                } = &mut player
+                {
+                    *new_attempts = attempts;
+                    *retry_in = if retry_in.is_some() && attempts < RETRY_ATTEMPTS_MAX {
+                        Some((RETRY_DELAY * 2u32.pow(attempts as u32)).min(RETRY_DELAY_MAX))
+                    } else {
+                        None
+                    };
+                }
+                *self = player;
+                Err(())
+            }
+        };
+
+        self.set_hovered(hovered);
+        result
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn load(media: &Media, playback: &Playback) -> Result<Self, Self> {
         match media {
-            Media::Image { path } => match Self::load_image(path) {
+            Media::Image { path } => match Self::load_image(path, playback.display_filter) {
                 Ok(handle) => Ok(Self::Image {
                     media: media.clone(),
+                    date: MediaDate::default(),
+                    memory: estimated_image_bytes(&handle),
                     handle,
                     position: Duration::ZERO,
                     duration: Duration::from_secs(playback.image_duration.get() as u64),
@@ -464,16 +1477,15 @@ impl Player {
                     dragging: false,
                     hovered: false,
                     need_play_on_focus: false,
+                    content_fit: None,
+                    content_fit_pinned: false,
                 }),
-                Err(e) => Err(Self::Error {
-                    media: media.clone(),
-                    message: e.message(),
-                    hovered: false,
-                }),
+                Err(e) => Err(Self::error(media, &e)),
             },
             Media::Svg { path } => match Self::load_svg(path) {
                 Ok(handle) => Ok(Self::Svg {
                     media: media.clone(),
+                    date: MediaDate::default(),
                     handle,
                     position: Duration::ZERO,
                     duration: Duration::from_secs(playback.image_duration.get() as u64),
@@ -483,38 +1495,18 @@ impl Player {
                     dragging: false,
                     hovered: false,
                     need_play_on_focus: false,
+                    content_fit: None,
+                    content_fit_pinned: false,
                 }),
-                Err(e) => Err(Self::Error {
-                    media: media.clone(),
-                    message: e.message(),
-                    hovered: false,
-                }),
-            },
-            Media::Gif { path } => match Self::load_gif(path) {
-                Ok((frames, handle)) => Ok(Self::Gif {
-                    media: media.clone(),
-                    frames,
-                    handle,
-                    position: Duration::ZERO,
-                    duration: Duration::from_secs(playback.image_duration.get() as u64),
-                    paused: playback.paused,
-                    muted: playback.muted,
-                    looping: false,
-                    dragging: false,
-                    hovered: false,
-                    need_play_on_focus: false,
-                }),
-                Err(e) => Err(Self::Error {
-                    media: media.clone(),
-                    message: e.message(),
-                    hovered: false,
-                }),
+                Err(e) => Err(Self::error(media, &e)),
             },
-            Media::Apng { path } => match Self::load_apng(path) {
-                Ok((frames, handle)) => Ok(Self::Apng {
+            Media::Gif { path } | Media::Apng { path } => match Self::load_animation(media, path) {
+                Ok((frames, handle, memory)) => Ok(Self::Animation {
                     media: media.clone(),
+                    date: MediaDate::default(),
                     frames,
                     handle,
+                    memory,
                     position: Duration::ZERO,
                     duration: Duration::from_secs(playback.image_duration.get() as u64),
                     paused: playback.paused,
@@ -523,57 +1515,90 @@ impl Player {
                     dragging: false,
                     hovered: false,
                     need_play_on_focus: false,
+                    content_fit: None,
+                    content_fit_pinned: false,
                 }),
-                Err(e) => Err(Self::Error {
-                    media: media.clone(),
-                    message: e.message(),
-                    hovered: false,
-                }),
+                Err(e) => Err(Self::error(media, &e)),
             },
             #[cfg(feature = "audio")]
-            Media::Audio { path } => match Self::load_audio(path, playback, Duration::from_millis(0)) {
-                Ok((stream, sink, duration)) => Ok(Self::Audio {
-                    media: media.clone(),
-                    stream,
-                    sink,
-                    duration,
-                    paused: playback.paused,
-                    looping: false,
-                    dragging: false,
-                    hovered: false,
-                    need_play_on_focus: false,
-                }),
-                Err(e) => Err(Self::Error {
-                    media: media.clone(),
-                    message: e.message(),
-                    hovered: false,
-                }),
-            },
+            Media::Audio { path } => {
+                let tags = AudioTags::read(path);
+                let gain = audio_gain(path, &tags, playback);
+                match Self::load_audio(path, playback, gain.unwrap_or(1.0), Duration::from_millis(0)) {
+                    Ok((stream, sink, duration)) => {
+                        let clip = Clip::random(duration, playback.clip_length);
+                        if let Some(clip) = clip {
+                            let _ = sink.try_seek(clip.start);
+                        }
+
+                        Ok(Self::Audio {
+                            media: media.clone(),
+                            date: MediaDate::default(),
+                            stream,
+                            sink,
+                            tags,
+                            gain: gain.unwrap_or(1.0),
+                            gain_pending: gain.is_none(),
+                            duration,
+                            clip,
+                            paused: playback.paused,
+                            looping: false,
+                            dragging: false,
+                            hovered: false,
+                            need_play_on_focus: false,
+                            content_fit: None,
+                            content_fit_pinned: false,
+                        })
+                    }
+                    Err(e) => Err(Self::error(media, &e)),
+                }
+            }
             #[cfg(feature = "video")]
             Media::Video { path } => match Self::load_video(path, playback) {
-                Ok(video) => Ok(Self::Video {
-                    media: media.clone(),
-                    duration: video.duration(),
-                    pipeline: get_video_pipeline(&video),
-                    video,
-                    position: Duration::ZERO,
-                    paused: playback.paused,
-                    dragging: false,
-                    hovered: false,
-                    need_play_on_focus: false,
-                }),
-                Err(e) => Err(Self::Error {
-                    media: media.clone(),
-                    message: e.message(),
-                    hovered: false,
-                }),
+                Ok((mut video, uri, subtitles)) => {
+                    let clip = Clip::random(video.duration(), playback.clip_length);
+                    if let Some(clip) = clip {
+                        seek_video(&mut video, clip.start);
+                    }
+
+                    Ok(Self::Video {
+                        media: media.clone(),
+                        date: MediaDate::default(),
+                        duration: video.duration(),
+                        pipeline: get_video_pipeline(&video),
+                        seek_previews: SeekPreviews::new(
+                            uri,
+                            video.duration(),
+                            playback.seek_previews,
+                            playback.display_filter,
+                        ),
+                        video,
+                        position: clip.map(|clip| clip.start).unwrap_or(Duration::ZERO),
+                        clip,
+                        paused: playback.paused,
+                        buffering: false,
+                        subtitles,
+                        dragging: false,
+                        hovered: false,
+                        need_play_on_focus: false,
+                        content_fit: None,
+                        content_fit_pinned: false,
+                    })
+                }
+                Err(e) => Err(Self::error(media, &e)),
             },
         }
     }
 
     #[cfg(feature = "video")]
-    fn load_video(source: &StrictPath, playback: &Playback) -> Result<iced_video_player::Video, Error> {
-        let mut video = build_video(&url::Url::from_file_path(source.as_std_path_buf()?).map_err(|_| Error::Url)?)?;
+    fn load_video(
+        source: &StrictPath,
+        playback: &Playback,
+    ) -> Result<(iced_video_player::Video, url::Url, Option<StrictPath>), Error> {
+        let uri = url::Url::from_file_path(source.as_std_path_buf()?).map_err(|_| Error::Url)?;
+        let subtitles = playback.load_subtitles.then(|| find_subtitles(source)).flatten();
+        let subtitles_uri = subtitles.as_ref().and_then(subtitles_uri);
+        let mut video = build_video(&uri, subtitles_uri.as_ref(), playback.display_filter)?;
 
         video.set_paused(playback.paused);
         mute_video(&mut video, playback.muted);
@@ -581,12 +1606,27 @@ impl Player {
             set_video_volume(&mut video, playback.volume);
         }
 
-        Ok(video)
+        Ok((video, uri, subtitles))
     }
 
-    fn load_image(source: &StrictPath) -> Result<iced::widget::image::Handle, Error> {
+    fn load_image(source: &StrictPath, filter: DisplayFilter) -> Result<iced::widget::image::Handle, Error> {
         let bytes = source.try_read_bytes()?;
-        Ok(iced::widget::image::Handle::from_bytes(bytes))
+        if filter == DisplayFilter::None {
+            return Ok(iced::widget::image::Handle::from_bytes(bytes));
+        }
+
+        let mut image = image::load_from_memory(&bytes)
+            .map_err(|e| Error::Image(e.to_string()))?
+            .into_rgba8();
+        for pixel in image.pixels_mut() {
+            pixel.0 = filter.apply(pixel.0);
+        }
+
+        Ok(iced::widget::image::Handle::from_rgba(
+            image.width(),
+            image.height(),
+            image.into_raw(),
+        ))
     }
 
     fn load_svg(source: &StrictPath) -> Result<iced::widget::svg::Handle, Error> {
@@ -594,24 +1634,25 @@ impl Player {
         Ok(iced::widget::svg::Handle::from_memory(bytes))
     }
 
-    fn load_gif(source: &StrictPath) -> Result<(gif::Frames, iced::widget::image::Handle), Error> {
-        let bytes = source.try_read_bytes()?;
-        let frames = gif::Frames::from_bytes(bytes.clone())?;
-        let handle = iced::widget::image::Handle::from_bytes(bytes);
-        Ok((frames, handle))
-    }
-
-    fn load_apng(source: &StrictPath) -> Result<(apng::Frames, iced::widget::image::Handle), Error> {
+    fn load_animation(
+        media: &Media,
+        source: &StrictPath,
+    ) -> Result<(Frames, iced::widget::image::Handle, usize), Error> {
         let bytes = source.try_read_bytes()?;
-        let frames = apng::Frames::from_bytes(bytes.clone())?;
+        let frames = match media {
+            Media::Apng { .. } => Frames::Apng(apng::Frames::from_bytes(bytes.clone())?),
+            _ => Frames::Gif(gif::Frames::from_bytes(bytes.clone())?),
+        };
+        let memory = estimated_animation_bytes(media, &bytes);
         let handle = iced::widget::image::Handle::from_bytes(bytes);
-        Ok((frames, handle))
+        Ok((frames, handle, memory))
     }
 
     #[cfg(feature = "audio")]
     fn load_audio(
         source: &StrictPath,
         playback: &Playback,
+        gain: f32,
         position: Duration,
     ) -> Result<(rodio::OutputStream, rodio::Sink, Duration), Error> {
         use rodio::Source;
@@ -628,7 +1669,7 @@ impl Player {
         if playback.muted {
             sink.set_volume(0.0);
         } else {
-            sink.set_volume(playback.volume);
+            sink.set_volume(audio_volume(playback.volume, gain));
         }
 
         let _ = sink.try_seek(position);
@@ -648,6 +1689,7 @@ impl Player {
     pub fn swap_media(&mut self, media: &Media, playback: &Playback) -> Result<(), ()> {
         let playback = playback.with_muted_maybe(self.is_muted());
         let hovered = self.is_hovered();
+        let (content_fit, pinned) = self.content_fit_override();
 
         let mut error = false;
         *self = match Self::new(media, &playback) {
@@ -659,6 +1701,9 @@ impl Player {
         };
 
         self.set_hovered(hovered);
+        if pinned {
+            self.set_content_fit_override(content_fit, pinned);
+        }
 
         if error {
             Err(())
@@ -683,15 +1728,12 @@ impl Player {
             Self::Svg { position, .. } => {
                 *position = Duration::ZERO;
             }
-            Self::Gif { position, .. } => {
-                *position = Duration::ZERO;
-            }
-            Self::Apng { position, .. } => {
+            Self::Animation { position, .. } => {
                 *position = Duration::ZERO;
             }
             #[cfg(feature = "audio")]
-            Self::Audio { sink, paused, .. } => {
-                let _ = sink.try_seek(Duration::ZERO);
+            Self::Audio { sink, clip, paused, .. } => {
+                let _ = sink.try_seek(clip.map(|clip| clip.start).unwrap_or(Duration::ZERO));
                 *paused = false;
                 sink.play();
             }
@@ -699,10 +1741,11 @@ impl Player {
             Self::Video {
                 video,
                 position,
+                clip,
                 paused,
                 ..
             } => {
-                *position = Duration::ZERO;
+                *position = clip.map(|clip| clip.start).unwrap_or(Duration::ZERO);
                 seek_video(video, *position);
                 *paused = false;
                 video.set_paused(false);
@@ -716,8 +1759,7 @@ impl Player {
             Self::Error { media, .. } => Some(media),
             Self::Image { media, .. } => Some(media),
             Self::Svg { media, .. } => Some(media),
-            Self::Gif { media, .. } => Some(media),
-            Self::Apng { media, .. } => Some(media),
+            Self::Animation { media, .. } => Some(media),
             #[cfg(feature = "audio")]
             Self::Audio { media, .. } => Some(media),
             #[cfg(feature = "video")]
@@ -725,14 +1767,67 @@ impl Player {
         }
     }
 
+    /// Information to show in the OS's media controls.
+    #[cfg(feature = "media-controls")]
+    pub fn now_playing(&self) -> Option<crate::gui::media_controls::NowPlaying> {
+        let media = self.media()?;
+        let path = media.path();
+
+        let mut now_playing = crate::gui::media_controls::NowPlaying {
+            title: path.file_stem(),
+            paused: self.is_paused(),
+            ..Default::default()
+        };
+
+        match self {
+            Self::Idle { .. } | Self::Error { .. } => {}
+            Self::Image { .. } | Self::Svg { .. } | Self::Animation { .. } => {
+                now_playing.cover_url = path
+                    .as_std_path_buf()
+                    .ok()
+                    .and_then(|path| url::Url::from_file_path(path).ok())
+                    .map(|url| url.to_string());
+            }
+            #[cfg(feature = "audio")]
+            Self::Audio { tags, duration, .. } => {
+                if tags.title.is_some() {
+                    now_playing.title = tags.title.clone();
+                }
+                now_playing.artist = tags.artist.clone();
+                now_playing.album = tags.album.clone();
+                now_playing.duration = Some(*duration);
+            }
+            #[cfg(feature = "video")]
+            Self::Video { duration, .. } => {
+                now_playing.duration = Some(*duration);
+            }
+        }
+
+        Some(now_playing)
+    }
+
+    /// Approximate memory used by loaded images, in bytes.
+    pub fn estimated_memory(&self) -> usize {
+        match self {
+            Self::Idle { .. } => 0,
+            Self::Error { .. } => 0,
+            Self::Image { memory, .. } => *memory,
+            Self::Svg { .. } => 0,
+            Self::Animation { memory, .. } => *memory,
+            #[cfg(feature = "audio")]
+            Self::Audio { .. } => 0,
+            #[cfg(feature = "video")]
+            Self::Video { .. } => 0,
+        }
+    }
+
     pub fn category(&self) -> Category {
         match self {
             Self::Idle { .. } => Category::Other,
             Self::Error { .. } => Category::Other,
             Self::Image { .. } => Category::Image,
             Self::Svg { .. } => Category::Image,
-            Self::Gif { .. } => Category::Image,
-            Self::Apng { .. } => Category::Image,
+            Self::Animation { .. } => Category::Image,
             #[cfg(feature = "audio")]
             Self::Audio { .. } => Category::Audio,
             #[cfg(feature = "video")]
@@ -746,8 +1841,7 @@ impl Player {
             Self::Error { .. } => true,
             Self::Image { .. } => false,
             Self::Svg { .. } => false,
-            Self::Gif { .. } => false,
-            Self::Apng { .. } => false,
+            Self::Animation { .. } => false,
             #[cfg(feature = "audio")]
             Self::Audio { .. } => false,
             #[cfg(feature = "video")]
@@ -761,8 +1855,7 @@ impl Player {
             Self::Error { .. } => None,
             Self::Image { paused, .. } => Some(*paused),
             Self::Svg { paused, .. } => Some(*paused),
-            Self::Gif { paused, .. } => Some(*paused),
-            Self::Apng { paused, .. } => Some(*paused),
+            Self::Animation { paused, .. } => Some(*paused),
             #[cfg(feature = "audio")]
             Self::Audio { paused, .. } => Some(*paused),
             #[cfg(feature = "video")]
@@ -776,8 +1869,7 @@ impl Player {
             Self::Error { .. } => None,
             Self::Image { muted, .. } => Some(*muted),
             Self::Svg { muted, .. } => Some(*muted),
-            Self::Gif { muted, .. } => Some(*muted),
-            Self::Apng { muted, .. } => Some(*muted),
+            Self::Animation { muted, .. } => Some(*muted),
             #[cfg(feature = "audio")]
             Self::Audio { sink, .. } => Some(sink.volume() == 0.0),
             #[cfg(feature = "video")]
@@ -791,8 +1883,37 @@ impl Player {
             Self::Error { .. } => false,
             Self::Image { .. } => false,
             Self::Svg { .. } => false,
-            Self::Gif { .. } => false,
-            Self::Apng { .. } => false,
+            Self::Animation { .. } => false,
+            #[cfg(feature = "audio")]
+            Self::Audio { .. } => true,
+            #[cfg(feature = "video")]
+            Self::Video { .. } => true,
+        }
+    }
+
+    /// Whether the player has anything that advances over time,
+    /// so that it needs frequent ticks.
+    pub fn is_progressing(&self) -> bool {
+        match self {
+            Self::Idle { .. } => false,
+            Self::Error { retry_in, .. } => retry_in.is_some(),
+            Self::Image { paused, .. } => !*paused,
+            Self::Svg { paused, .. } => !*paused,
+            Self::Animation { paused, .. } => !*paused,
+            #[cfg(feature = "audio")]
+            Self::Audio { paused, .. } => !*paused,
+            #[cfg(feature = "video")]
+            Self::Video { paused, .. } => !*paused,
+        }
+    }
+
+    pub fn can_seek(&self) -> bool {
+        match self {
+            Self::Idle { .. } => false,
+            Self::Error { .. } => false,
+            Self::Image { .. } => true,
+            Self::Svg { .. } => true,
+            Self::Animation { .. } => true,
             #[cfg(feature = "audio")]
             Self::Audio { .. } => true,
             #[cfg(feature = "video")]
@@ -806,8 +1927,7 @@ impl Player {
             Self::Error { hovered, .. } => *hovered,
             Self::Image { hovered, .. } => *hovered,
             Self::Svg { hovered, .. } => *hovered,
-            Self::Gif { hovered, .. } => *hovered,
-            Self::Apng { hovered, .. } => *hovered,
+            Self::Animation { hovered, .. } => *hovered,
             #[cfg(feature = "audio")]
             Self::Audio { hovered, .. } => *hovered,
             #[cfg(feature = "video")]
@@ -829,10 +1949,7 @@ impl Player {
             Self::Svg { hovered, .. } => {
                 *hovered = flag;
             }
-            Self::Gif { hovered, .. } => {
-                *hovered = flag;
-            }
-            Self::Apng { hovered, .. } => {
+            Self::Animation { hovered, .. } => {
                 *hovered = flag;
             }
             #[cfg(feature = "audio")]
@@ -840,40 +1957,147 @@ impl Player {
                 *hovered = flag;
             }
             #[cfg(feature = "video")]
-            Self::Video { hovered, .. } => {
+            Self::Video {
+                hovered, seek_previews, ..
+            } => {
                 *hovered = flag;
+                if flag {
+                    seek_previews.start();
+                }
             }
         }
     }
 
-    pub fn tick(&mut self, elapsed: Duration) -> Option<Update> {
+    fn date(&self) -> Option<&chrono::DateTime<chrono::Local>> {
         match self {
             Self::Idle { .. } => None,
             Self::Error { .. } => None,
+            Self::Image { media, date, .. } => date.get(media),
+            Self::Svg { media, date, .. } => date.get(media),
+            Self::Animation { media, date, .. } => date.get(media),
+            #[cfg(feature = "audio")]
+            Self::Audio { media, date, .. } => date.get(media),
+            #[cfg(feature = "video")]
+            Self::Video { media, date, .. } => date.get(media),
+        }
+    }
+
+    /// This player's own content fit, if it overrides the grid's,
+    /// and whether it should be kept for new media.
+    fn content_fit_override(&self) -> (Option<ContentFit>, bool) {
+        match self {
+            Self::Idle { .. } => (None, false),
+            Self::Error { .. } => (None, false),
             Self::Image {
-                position,
-                duration,
-                paused,
-                looping,
-                dragging,
+                content_fit,
+                content_fit_pinned,
+                ..
+            } => (*content_fit, *content_fit_pinned),
+            Self::Svg {
+                content_fit,
+                content_fit_pinned,
+                ..
+            } => (*content_fit, *content_fit_pinned),
+            Self::Animation {
+                content_fit,
+                content_fit_pinned,
+                ..
+            } => (*content_fit, *content_fit_pinned),
+            #[cfg(feature = "audio")]
+            Self::Audio {
+                content_fit,
+                content_fit_pinned,
+                ..
+            } => (*content_fit, *content_fit_pinned),
+            #[cfg(feature = "video")]
+            Self::Video {
+                content_fit,
+                content_fit_pinned,
+                ..
+            } => (*content_fit, *content_fit_pinned),
+        }
+    }
+
+    fn set_content_fit_override(&mut self, fit: Option<ContentFit>, pinned: bool) {
+        match self {
+            Self::Idle { .. } => {}
+            Self::Error { .. } => {}
+            Self::Image {
+                content_fit,
+                content_fit_pinned,
                 ..
             } => {
-                if !*paused && !*dragging {
-                    *position += elapsed;
-                }
+                *content_fit = fit;
+                *content_fit_pinned = pinned;
+            }
+            Self::Svg {
+                content_fit,
+                content_fit_pinned,
+                ..
+            } => {
+                *content_fit = fit;
+                *content_fit_pinned = pinned;
+            }
+            Self::Animation {
+                content_fit,
+                content_fit_pinned,
+                ..
+            } => {
+                *content_fit = fit;
+                *content_fit_pinned = pinned;
+            }
+            #[cfg(feature = "audio")]
+            Self::Audio {
+                content_fit,
+                content_fit_pinned,
+                ..
+            } => {
+                *content_fit = fit;
+                *content_fit_pinned = pinned;
+            }
+            #[cfg(feature = "video")]
+            Self::Video {
+                content_fit,
+                content_fit_pinned,
+                ..
+            } => {
+                *content_fit = fit;
+                *content_fit_pinned = pinned;
+            }
+        }
+    }
+
+    fn cycle_content_fit(&mut self, default: ContentFit) {
+        let (current, pinned) = self.content_fit_override();
+        let next = current.unwrap_or(default).next();
+
+        if next == default {
+            self.set_content_fit_override(None, false);
+        } else {
+            self.set_content_fit_override(Some(next), pinned);
+        }
+    }
 
-                if *position >= *duration {
-                    if *looping {
-                        *position = Duration::ZERO;
-                        None
-                    } else {
-                        Some(Update::EndOfStream)
-                    }
+    fn pin_content_fit(&mut self, flag: bool) {
+        let (current, _) = self.content_fit_override();
+        self.set_content_fit_override(current, flag && current.is_some());
+    }
+
+    pub fn tick(&mut self, elapsed: Duration, playback: &Playback) -> Option<Update> {
+        match self {
+            Self::Idle { .. } => None,
+            Self::Error { retry_in, .. } => {
+                let remaining = retry_in.as_mut()?;
+                *remaining = remaining.saturating_sub(elapsed);
+
+                if remaining.is_zero() {
+                    *retry_in = None;
+                    Some(Update::Retry)
                 } else {
                     None
                 }
             }
-            Self::Svg {
+            Self::Image {
                 position,
                 duration,
                 paused,
@@ -896,7 +2120,7 @@ impl Player {
                     None
                 }
             }
-            Self::Gif {
+            Self::Svg {
                 position,
                 duration,
                 paused,
@@ -919,7 +2143,7 @@ impl Player {
                     None
                 }
             }
-            Self::Apng {
+            Self::Animation {
                 position,
                 duration,
                 paused,
@@ -944,11 +2168,30 @@ impl Player {
             }
             #[cfg(feature = "audio")]
             Self::Audio {
+                media,
                 sink,
+                tags,
+                gain,
+                gain_pending,
                 duration,
+                clip,
                 looping,
                 ..
             } => {
+                if *gain_pending {
+                    if let Some(scanned) = audio_gain(media.path(), tags, playback) {
+                        *gain = scanned;
+                        *gain_pending = false;
+                        if sink.volume() != 0.0 {
+                            sink.set_volume(audio_volume(playback.volume, scanned));
+                        }
+                    }
+                }
+
+                if !*looping && clip.is_some_and(|clip| sink.get_pos() >= clip.end) {
+                    return Some(Update::EndOfStream);
+                }
+
                 if sink.get_pos() >= *duration {
                     if *looping {
                         let _ = sink.try_seek(Duration::from_millis(0));
@@ -960,13 +2203,29 @@ impl Player {
                 None
             }
             #[cfg(feature = "video")]
-            Self::Video { pipeline, duration, .. } => {
+            Self::Video {
+                video,
+                pipeline,
+                position,
+                duration,
+                clip,
+                buffering,
+                ..
+            } => {
                 // If the video is still being downloaded/written,
                 // then we want to get the latest total duration.
                 if let Some(clock_time) = get_video_duration(pipeline) {
                     *duration = Duration::from_nanos(clock_time.nseconds());
                 }
 
+                if let Some(flag) = get_video_buffering(pipeline) {
+                    *buffering = flag;
+                }
+
+                if !video.looping() && clip.is_some_and(|clip| *position >= clip.end) {
+                    return Some(Update::EndOfStream);
+                }
+
                 None
             }
         }
@@ -976,40 +2235,154 @@ impl Player {
     pub fn reload_audio(&mut self, playback: &Playback) {
         if let Self::Audio {
             media,
+            date,
             stream: _,
             sink,
+            tags,
+            gain: _,
+            gain_pending: _,
             duration: _,
+            clip,
             paused,
             looping,
             dragging,
             hovered,
             need_play_on_focus,
+            content_fit,
+            content_fit_pinned,
         } = self
         {
             let playback = playback.with_paused(*paused).with_muted(sink.volume() == 0.0);
             let position = sink.get_pos();
+            let gain = audio_gain(media.path(), tags, &playback);
 
-            *self = match Self::load_audio(media.path(), &playback, position) {
+            *self = match Self::load_audio(media.path(), &playback, gain.unwrap_or(1.0), position) {
                 Ok((stream, sink, duration)) => Self::Audio {
                     media: media.clone(),
+                    date: date.clone(),
                     stream,
                     sink,
+                    tags: tags.clone(),
+                    gain: gain.unwrap_or(1.0),
+                    gain_pending: gain.is_none(),
                     duration,
+                    clip: *clip,
                     paused: *paused,
                     looping: *looping,
                     dragging: *dragging,
                     hovered: *hovered,
                     need_play_on_focus: *need_play_on_focus,
+                    content_fit: *content_fit,
+                    content_fit_pinned: *content_fit_pinned,
                 },
-                Err(e) => Self::Error {
-                    media: media.clone(),
-                    message: e.message(),
-                    hovered: false,
-                },
+                Err(e) => Self::error(media, &e),
             };
         }
     }
 
+    /// Controls for overriding the grid's content fit in just this player.
+    fn content_fit_controls(&self, grid_id: grid::Id, player_id: Id, default: ContentFit) -> Element {
+        let (content_fit, pinned) = self.content_fit_override();
+
+        Row::new()
+            .push(
+                button::icon(Icon::AspectRatio)
+                    .on_press(Message::Player {
+                        grid_id,
+                        player_id,
+                        event: Event::CycleContentFit(default),
+                    })
+                    .tooltip(format!(
+                        "{}: {}",
+                        lang::thing::content_fit(),
+                        content_fit.unwrap_or(default).to_string()
+                    )),
+            )
+            .push(content_fit.map(|_| {
+                button::icon(if pinned { Icon::Lock } else { Icon::LockOpen })
+                    .on_press(Message::Player {
+                        grid_id,
+                        player_id,
+                        event: Event::PinContentFit(!pinned),
+                    })
+                    .tooltip(if pinned {
+                        lang::action::reset_content_fit_for_new_media()
+                    } else {
+                        lang::action::keep_content_fit_for_new_media()
+                    })
+            }))
+            .into()
+    }
+
+    /// Buttons to act on the current media, shown in the top right of the overlay.
+    fn action_controls(&self, grid_id: grid::Id, player_id: Id, kiosk: bool) -> Element {
+        Row::new()
+            .push(
+                button::icon(Icon::Refresh)
+                    .on_press(Message::Player {
+                        grid_id,
+                        player_id,
+                        event: Event::Refresh,
+                    })
+                    .tooltip(lang::action::shuffle()),
+            )
+            .push(
+                button::icon(Icon::Stop)
+                    .on_press(Message::Player {
+                        grid_id,
+                        player_id,
+                        event: Event::Stop,
+                    })
+                    .tooltip(lang::action::stop()),
+            )
+            .push((!kiosk).then(|| {
+                button::icon(Icon::VisibilityOff)
+                    .on_press(Message::Player {
+                        grid_id,
+                        player_id,
+                        event: Event::Blacklist,
+                    })
+                    .tooltip(lang::action::hide_for_this_session())
+            }))
+            .push((!kiosk && cfg!(feature = "trash")).then(|| {
+                button::icon(Icon::Delete)
+                    .on_press(Message::Player {
+                        grid_id,
+                        player_id,
+                        event: Event::DeleteToTrash,
+                    })
+                    .tooltip(lang::action::move_to_trash())
+            }))
+            .push((!kiosk).then(|| {
+                button::icon(Icon::DriveFileMove)
+                    .on_press(Message::Player {
+                        grid_id,
+                        player_id,
+                        event: Event::Transfer(Transfer::Move),
+                    })
+                    .tooltip(lang::action::move_to_folder())
+            }))
+            .push((!kiosk).then(|| {
+                button::icon(Icon::FileCopy)
+                    .on_press(Message::Player {
+                        grid_id,
+                        player_id,
+                        event: Event::Transfer(Transfer::Copy),
+                    })
+                    .tooltip(lang::action::copy_to_folder())
+            }))
+            .push((!kiosk).then(|| {
+                button::icon(Icon::Close)
+                    .on_press(Message::Player {
+                        grid_id,
+                        player_id,
+                        event: Event::Close,
+                    })
+                    .tooltip(lang::action::close())
+            }))
+            .into()
+    }
+
     fn overlay(&self, viewport: iced::Size, obscured: bool, hovered: bool) -> Overlay {
         let show = !obscured && hovered;
 
@@ -1028,7 +2401,7 @@ impl Player {
                 bottom_controls: false,
                 timestamps: false,
             },
-            Self::Image { .. } | Self::Svg { .. } | Self::Gif { .. } | Self::Apng { .. } => Overlay {
+            Self::Image { .. } | Self::Svg { .. } | Self::Animation { .. } => Overlay {
                 show,
                 center_controls: show && viewport.height > 100.0 && viewport.width > 150.0,
                 top_controls: show && viewport.width > 100.0,
@@ -1079,6 +2452,12 @@ impl Player {
                     None
                 }
                 Event::Refresh => None,
+                Event::Stop => None,
+                Event::DeleteToTrash => None,
+                Event::Blacklist => None,
+                Event::Transfer(_) => None,
+                Event::CycleContentFit(_) => None,
+                Event::PinContentFit(_) => None,
                 Event::Close => Some(Update::Close),
                 Event::WindowFocused => None,
                 Event::WindowUnfocused => None,
@@ -1105,6 +2484,15 @@ impl Player {
                     None
                 }
                 Event::Refresh => Some(Update::Refresh),
+                Event::Stop => {
+                    self.go_idle();
+                    Some(Update::Stop)
+                }
+                Event::DeleteToTrash => self.media().cloned().map(Update::DeleteToTrash),
+                Event::Blacklist => self.media().cloned().map(Update::Blacklist),
+                Event::Transfer(transfer) => self.media().cloned().map(|media| Update::Transfer { media, transfer }),
+                Event::CycleContentFit(_) => None,
+                Event::PinContentFit(_) => None,
                 Event::Close => Some(Update::Close),
                 Event::WindowFocused => None,
                 Event::WindowUnfocused => None,
@@ -1140,7 +2528,7 @@ impl Player {
                 }
                 Event::SeekRelative(offset) => {
                     *position = Duration::from_secs_f64(duration.as_secs_f64() * offset);
-                    None
+                    Some(Update::RelativePositionChanged(offset))
                 }
                 Event::SeekStop => {
                     *dragging = false;
@@ -1149,7 +2537,8 @@ impl Player {
                 Event::SeekRandom => None,
                 Event::SeekRandomRelative(_) => None,
                 Event::Step(step) => {
-                    *position = step.compute(*position, *duration, IMAGE_STEP);
+                    let size = Duration::from_secs(playback.seek_step_image.get() as u64);
+                    *position = step.compute(*position, *duration, size);
                     Some(Update::Step(step))
                 }
                 Event::EndOfStream => Some(Update::EndOfStream),
@@ -1163,6 +2552,21 @@ impl Player {
                     None
                 }
                 Event::Refresh => Some(Update::Refresh),
+                Event::Stop => {
+                    self.go_idle();
+                    Some(Update::Stop)
+                }
+                Event::DeleteToTrash => self.media().cloned().map(Update::DeleteToTrash),
+                Event::Blacklist => self.media().cloned().map(Update::Blacklist),
+                Event::Transfer(transfer) => self.media().cloned().map(|media| Update::Transfer { media, transfer }),
+                Event::CycleContentFit(default) => {
+                    self.cycle_content_fit(default);
+                    None
+                }
+                Event::PinContentFit(flag) => {
+                    self.pin_content_fit(flag);
+                    None
+                }
                 Event::Close => Some(Update::Close),
                 Event::WindowFocused => {
                     if *need_play_on_focus {
@@ -1210,7 +2614,7 @@ impl Player {
                 }
                 Event::SeekRelative(offset) => {
                     *position = Duration::from_secs_f64(duration.as_secs_f64() * offset);
-                    None
+                    Some(Update::RelativePositionChanged(offset))
                 }
                 Event::SeekStop => {
                     *dragging = false;
@@ -1219,7 +2623,8 @@ impl Player {
                 Event::SeekRandom => None,
                 Event::SeekRandomRelative(_) => None,
                 Event::Step(step) => {
-                    *position = step.compute(*position, *duration, IMAGE_STEP);
+                    let size = Duration::from_secs(playback.seek_step_image.get() as u64);
+                    *position = step.compute(*position, *duration, size);
                     Some(Update::Step(step))
                 }
                 Event::EndOfStream => Some(Update::EndOfStream),
@@ -1233,76 +2638,21 @@ impl Player {
                     None
                 }
                 Event::Refresh => Some(Update::Refresh),
-                Event::Close => Some(Update::Close),
-                Event::WindowFocused => {
-                    if *need_play_on_focus {
-                        *paused = false;
-                        *need_play_on_focus = false;
-                    }
-                    None
-                }
-                Event::WindowUnfocused => {
-                    if playback.pause_on_unfocus {
-                        *paused = true;
-                        *need_play_on_focus = true;
-                    }
-                    None
-                }
-            },
-            Self::Gif {
-                position,
-                duration,
-                paused,
-                muted,
-                looping,
-                dragging,
-                hovered,
-                need_play_on_focus,
-                ..
-            } => match event {
-                Event::SetPause(flag) => {
-                    *paused = flag;
-                    Some(Update::PauseChanged(flag))
-                }
-                Event::SetLoop(flag) => {
-                    *looping = flag;
-                    None
-                }
-                Event::SetMute(flag) => {
-                    *muted = flag;
-                    Some(Update::MuteChanged)
-                }
-                Event::SetVolume(_) => None,
-                Event::Seek(offset) => {
-                    *dragging = true;
-                    *position = offset.min(*duration);
-                    Update::relative_position_changed(*position, *duration)
-                }
-                Event::SeekRelative(offset) => {
-                    *position = Duration::from_secs_f64(duration.as_secs_f64() * offset);
-                    None
-                }
-                Event::SeekStop => {
-                    *dragging = false;
-                    None
-                }
-                Event::SeekRandom => None,
-                Event::SeekRandomRelative(_) => None,
-                Event::Step(step) => {
-                    *position = step.compute(*position, *duration, IMAGE_STEP);
-                    Some(Update::Step(step))
-                }
-                Event::EndOfStream => Some(Update::EndOfStream),
-                Event::NewFrame => None,
-                Event::MouseEnter => {
-                    *hovered = true;
+                Event::Stop => {
+                    self.go_idle();
+                    Some(Update::Stop)
+                }
+                Event::DeleteToTrash => self.media().cloned().map(Update::DeleteToTrash),
+                Event::Blacklist => self.media().cloned().map(Update::Blacklist),
+                Event::Transfer(transfer) => self.media().cloned().map(|media| Update::Transfer { media, transfer }),
+                Event::CycleContentFit(default) => {
+                    self.cycle_content_fit(default);
                     None
                 }
-                Event::MouseExit => {
-                    *hovered = false;
+                Event::PinContentFit(flag) => {
+                    self.pin_content_fit(flag);
                     None
                 }
-                Event::Refresh => Some(Update::Refresh),
                 Event::Close => Some(Update::Close),
                 Event::WindowFocused => {
                     if *need_play_on_focus {
@@ -1319,7 +2669,7 @@ impl Player {
                     None
                 }
             },
-            Self::Apng {
+            Self::Animation {
                 position,
                 duration,
                 paused,
@@ -1350,7 +2700,7 @@ impl Player {
                 }
                 Event::SeekRelative(offset) => {
                     *position = Duration::from_secs_f64(duration.as_secs_f64() * offset);
-                    None
+                    Some(Update::RelativePositionChanged(offset))
                 }
                 Event::SeekStop => {
                     *dragging = false;
@@ -1359,7 +2709,8 @@ impl Player {
                 Event::SeekRandom => None,
                 Event::SeekRandomRelative(_) => None,
                 Event::Step(step) => {
-                    *position = step.compute(*position, *duration, IMAGE_STEP);
+                    let size = Duration::from_secs(playback.seek_step_image.get() as u64);
+                    *position = step.compute(*position, *duration, size);
                     Some(Update::Step(step))
                 }
                 Event::EndOfStream => Some(Update::EndOfStream),
@@ -1373,6 +2724,21 @@ impl Player {
                     None
                 }
                 Event::Refresh => Some(Update::Refresh),
+                Event::Stop => {
+                    self.go_idle();
+                    Some(Update::Stop)
+                }
+                Event::DeleteToTrash => self.media().cloned().map(Update::DeleteToTrash),
+                Event::Blacklist => self.media().cloned().map(Update::Blacklist),
+                Event::Transfer(transfer) => self.media().cloned().map(|media| Update::Transfer { media, transfer }),
+                Event::CycleContentFit(default) => {
+                    self.cycle_content_fit(default);
+                    None
+                }
+                Event::PinContentFit(flag) => {
+                    self.pin_content_fit(flag);
+                    None
+                }
                 Event::Close => Some(Update::Close),
                 Event::WindowFocused => {
                     if *need_play_on_focus {
@@ -1393,7 +2759,9 @@ impl Player {
             #[cfg(feature = "audio")]
             Self::Audio {
                 sink,
+                gain,
                 duration,
+                clip,
                 paused,
                 looping,
                 dragging,
@@ -1418,24 +2786,27 @@ impl Player {
                     if flag {
                         sink.set_volume(0.0);
                     } else {
-                        sink.set_volume(playback.volume);
+                        sink.set_volume(audio_volume(playback.volume, *gain));
                     }
                     Some(Update::MuteChanged)
                 }
                 Event::SetVolume(volume) => {
                     if !playback.muted {
-                        sink.set_volume(volume);
+                        sink.set_volume(audio_volume(volume, *gain));
                     }
                     None
                 }
                 Event::Seek(offset) => {
+                    // Seeking by hand overrides the random clip.
+                    *clip = None;
                     *dragging = true;
                     let _ = sink.try_seek(offset);
                     Update::relative_position_changed(offset, *duration)
                 }
                 Event::SeekRelative(offset) | Event::SeekRandomRelative(offset) => {
+                    *clip = None;
                     let _ = sink.try_seek(Duration::from_secs_f64(duration.as_secs_f64() * offset));
-                    None
+                    Some(Update::RelativePositionChanged(offset))
                 }
                 Event::SeekStop => {
                     *dragging = false;
@@ -1444,11 +2815,14 @@ impl Player {
                 Event::SeekRandom => {
                     use rand::Rng;
                     let position = Duration::from_secs_f64(rand::rng().random_range(0.0..duration.as_secs_f64()));
+                    *clip = None;
                     let _ = sink.try_seek(position);
                     Update::relative_position_changed(position, *duration)
                 }
                 Event::Step(step) => {
-                    let position = step.compute(sink.get_pos(), *duration, AUDIO_STEP);
+                    let size = Duration::from_secs(playback.seek_step_audio.get() as u64);
+                    let position = step.compute(sink.get_pos(), *duration, size);
+                    *clip = None;
                     let _ = sink.try_seek(position);
                     Some(Update::Step(step))
                 }
@@ -1463,6 +2837,21 @@ impl Player {
                     None
                 }
                 Event::Refresh => Some(Update::Refresh),
+                Event::Stop => {
+                    self.go_idle();
+                    Some(Update::Stop)
+                }
+                Event::DeleteToTrash => self.media().cloned().map(Update::DeleteToTrash),
+                Event::Blacklist => self.media().cloned().map(Update::Blacklist),
+                Event::Transfer(transfer) => self.media().cloned().map(|media| Update::Transfer { media, transfer }),
+                Event::CycleContentFit(default) => {
+                    self.cycle_content_fit(default);
+                    None
+                }
+                Event::PinContentFit(flag) => {
+                    self.pin_content_fit(flag);
+                    None
+                }
                 Event::Close => Some(Update::Close),
                 Event::WindowFocused => {
                     if *need_play_on_focus {
@@ -1487,6 +2876,7 @@ impl Player {
                 pipeline,
                 position,
                 duration,
+                clip,
                 paused,
                 dragging,
                 hovered,
@@ -1516,15 +2906,18 @@ impl Player {
                     None
                 }
                 Event::Seek(offset) => {
+                    // Seeking by hand overrides the random clip.
+                    *clip = None;
                     *dragging = true;
                     *position = offset;
                     seek_video(video, *position);
                     Update::relative_position_changed(offset, *duration)
                 }
                 Event::SeekRelative(offset) | Event::SeekRandomRelative(offset) => {
+                    *clip = None;
                     *position = Duration::from_secs_f64(duration.as_secs_f64() * offset);
                     seek_video(video, *position);
-                    None
+                    Some(Update::RelativePositionChanged(offset))
                 }
                 Event::SeekStop => {
                     *dragging = false;
@@ -1533,11 +2926,14 @@ impl Player {
                 Event::SeekRandom => {
                     use rand::Rng;
                     *position = Duration::from_secs_f64(rand::rng().random_range(0.0..duration.as_secs_f64()));
+                    *clip = None;
                     seek_video(video, *position);
                     Update::relative_position_changed(*position, *duration)
                 }
                 Event::Step(step) => {
-                    *position = step.compute(*position, *duration, VIDEO_STEP);
+                    let size = Duration::from_secs(playback.seek_step_video.get() as u64);
+                    *position = step.compute(*position, *duration, size);
+                    *clip = None;
                     seek_video(video, *position);
                     Some(Update::Step(step))
                 }
@@ -1557,6 +2953,21 @@ impl Player {
                     None
                 }
                 Event::Refresh => Some(Update::Refresh),
+                Event::Stop => {
+                    self.go_idle();
+                    Some(Update::Stop)
+                }
+                Event::DeleteToTrash => self.media().cloned().map(Update::DeleteToTrash),
+                Event::Blacklist => self.media().cloned().map(Update::Blacklist),
+                Event::Transfer(transfer) => self.media().cloned().map(|media| Update::Transfer { media, transfer }),
+                Event::CycleContentFit(default) => {
+                    self.cycle_content_fit(default);
+                    None
+                }
+                Event::PinContentFit(flag) => {
+                    self.pin_content_fit(flag);
+                    None
+                }
                 Event::Close => Some(Update::Close),
                 Event::WindowFocused => {
                     if *need_play_on_focus {
@@ -1585,9 +2996,38 @@ impl Player {
         selected: bool,
         obscured: bool,
         content_fit: ContentFit,
+        filter_method: FilterMethod,
+        aspect_override: AspectOverride,
+        opacity: u8,
+        source: Option<String>,
+        options: ViewOptions,
     ) -> Element {
         Responsive::new(move |viewport| {
-            mouse_area(self.view_inner(grid_id, player_id, selected, obscured, content_fit, viewport))
+            let min = f32::from(options.min_tile_px);
+            let too_small = viewport.width < min || viewport.height < min;
+
+            let inner = if too_small {
+                Container::new(text(lang::tell::player_is_too_small()).size(12))
+                    .center(Length::Fill)
+                    .padding(2)
+                    .into()
+            } else {
+                self.view_inner(
+                    grid_id,
+                    player_id,
+                    selected,
+                    obscured,
+                    content_fit,
+                    filter_method,
+                    aspect_override,
+                    opacity,
+                    source.as_deref(),
+                    options,
+                    viewport,
+                )
+            };
+
+            let mut area = mouse_area(inner)
                 .on_enter(if obscured {
                     Message::Ignore
                 } else {
@@ -1616,7 +3056,28 @@ impl Player {
                         player_id,
                         event: Event::MouseExit,
                     }
-                })
+                });
+
+            // Clicks on the overlay buttons and seek slider are captured by those widgets,
+            // so the mouse area will only see clicks on the media itself.
+            if let Some(paused) = self.is_paused().filter(|_| !obscured && !too_small) {
+                let message = Message::Player {
+                    grid_id,
+                    player_id,
+                    event: Event::SetPause(!paused),
+                };
+
+                area = match options.click_to_pause {
+                    ClickToPause::Off => area,
+                    ClickToPause::SingleClick => area.on_press(message),
+                    ClickToPause::DoubleClick => area.on_double_click(message),
+                };
+            }
+
+            Container::new(area)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .id(container_id(grid_id, player_id))
                 .into()
         })
         .into()
@@ -1629,9 +3090,36 @@ impl Player {
         selected: bool,
         obscured: bool,
         content_fit: ContentFit,
+        filter_method: FilterMethod,
+        aspect_override: AspectOverride,
+        opacity: u8,
+        source: Option<&str>,
+        options: ViewOptions,
         viewport: iced::Size,
     ) -> Element {
-        match self {
+        let ViewOptions {
+            show_media_date,
+            show_info,
+            show_progress_bars,
+            kiosk,
+            ..
+        } = options;
+        let frame = Frame::new(
+            viewport,
+            self.content_fit_override().0.unwrap_or(content_fit),
+            aspect_override,
+        );
+        let date = show_media_date.then(|| self.date()).flatten();
+
+        // The hover controls already show these details.
+        let info = (show_info && !self.is_hovered())
+            .then(|| self.info_panel(source))
+            .flatten();
+        let progress_bar = (show_progress_bars && !self.is_hovered())
+            .then(|| self.progress_bar())
+            .flatten();
+
+        let content: Element = match self {
             Self::Idle { hovered } => {
                 let overlay = self.overlay(viewport, obscured, *hovered || selected);
 
@@ -1648,17 +3136,15 @@ impl Player {
                 );
 
                 let top_controls = overlay.top_controls.then_some(
-                    Container::new(
-                        Row::new().push(space::horizontal()).push(
-                            button::icon(Icon::Close)
-                                .on_press(Message::Player {
-                                    grid_id,
-                                    player_id,
-                                    event: Event::Close,
-                                })
-                                .tooltip(lang::action::close()),
-                        ),
-                    )
+                    Container::new(Row::new().push(space::horizontal()).push((!kiosk).then(|| {
+                        button::icon(Icon::Close)
+                            .on_press(Message::Player {
+                                grid_id,
+                                player_id,
+                                event: Event::Close,
+                            })
+                            .tooltip(lang::action::close())
+                    })))
                     .align_top(Length::Fill)
                     .width(Length::Fill),
                 );
@@ -1672,97 +3158,16 @@ impl Player {
             Self::Error {
                 media,
                 message,
-                hovered,
-            } => {
-                let overlay = self.overlay(viewport, obscured, *hovered || selected);
-
-                let body = Container::new(text(message))
-                    .align_x(Alignment::Center)
-                    .align_y(Alignment::Center)
-                    .width(Length::Fill)
-                    .height(Length::Fill);
-
-                let controls_background = overlay.show.then_some(
-                    Container::new("")
-                        .center(Length::Fill)
-                        .class(style::Container::ModalBackground),
-                );
-
-                let top_controls = overlay.top_controls.then_some(
-                    Container::new(
-                        Row::new()
-                            .push(
-                                button::icon(Icon::OpenInNew)
-                                    .on_press(Message::OpenDir {
-                                        path: media.path().clone(),
-                                    })
-                                    .tooltip(media.path().render()),
-                            )
-                            .push(space::horizontal())
-                            .push(
-                                button::icon(Icon::Close)
-                                    .on_press(Message::Player {
-                                        grid_id,
-                                        player_id,
-                                        event: Event::Close,
-                                    })
-                                    .tooltip(lang::action::close()),
-                            ),
-                    )
-                    .align_top(Length::Fill)
-                    .width(Length::Fill),
-                );
-
-                let center_controls = overlay.center_controls.then_some(
-                    Container::new(
-                        Row::new()
-                            .spacing(5)
-                            .align_y(alignment::Vertical::Center)
-                            .padding(padding::all(10.0))
-                            .push(
-                                button::big_icon(Icon::Refresh)
-                                    .on_press(Message::Player {
-                                        grid_id,
-                                        player_id,
-                                        event: Event::Refresh,
-                                    })
-                                    .tooltip(lang::action::shuffle()),
-                            ),
-                    )
-                    .center(Length::Fill),
-                );
-
-                Stack::new()
-                    .push(body)
-                    .push(controls_background)
-                    .push(top_controls)
-                    .push(center_controls)
-                    .into()
-            }
-            Self::Image {
-                media,
-                handle,
-                position,
-                duration,
-                paused,
-                muted,
-                looping,
-                dragging,
-                hovered,
-                ..
-            } => {
-                let overlay = self.overlay(viewport, obscured, *hovered || selected || *dragging);
-
-                let body = Container::new(
-                    Image::new(handle)
-                        .width(Length::Fill)
-                        .height(Length::Fill)
-                        .content_fit(content_fit.into()),
-                )
-                .align_x(Alignment::Center)
-                .align_y(Alignment::Center)
-                .width(Length::Fill)
-                .height(Length::Fill);
+                hovered,
+                ..
+            } => {
+                let overlay = self.overlay(viewport, obscured, *hovered || selected);
+
+                let body = Container::new(text(message))
+                    .align_x(Alignment::Center)
+                    .align_y(Alignment::Center)
+                    .width(Length::Fill)
+                    .height(Length::Fill);
 
                 let controls_background = overlay.show.then_some(
                     Container::new("")
@@ -1770,35 +3175,26 @@ impl Player {
                         .class(style::Container::ModalBackground),
                 );
 
-                let oveerlay_top_controls = overlay.top_controls.then_some(
+                let top_controls = overlay.top_controls.then_some(
                     Container::new(
                         Row::new()
                             .push(
-                                button::icon(Icon::Image)
+                                button::icon(Icon::OpenInNew)
                                     .on_press(Message::OpenDir {
                                         path: media.path().clone(),
                                     })
                                     .tooltip(media.path().render()),
                             )
                             .push(space::horizontal())
-                            .push(
-                                button::icon(Icon::Refresh)
-                                    .on_press(Message::Player {
-                                        grid_id,
-                                        player_id,
-                                        event: Event::Refresh,
-                                    })
-                                    .tooltip(lang::action::shuffle()),
-                            )
-                            .push(
+                            .push((!kiosk).then(|| {
                                 button::icon(Icon::Close)
                                     .on_press(Message::Player {
                                         grid_id,
                                         player_id,
                                         event: Event::Close,
                                     })
-                                    .tooltip(lang::action::close()),
-                            ),
+                                    .tooltip(lang::action::close())
+                            })),
                     )
                     .align_top(Length::Fill)
                     .width(Length::Fill),
@@ -1811,83 +3207,26 @@ impl Player {
                             .align_y(alignment::Vertical::Center)
                             .padding(padding::all(10.0))
                             .push(
-                                button::icon(if *muted { Icon::Mute } else { Icon::VolumeHigh })
-                                    .on_press(Message::Player {
-                                        grid_id,
-                                        player_id,
-                                        event: Event::SetMute(!*muted),
-                                    })
-                                    .tooltip(if *muted {
-                                        lang::action::unmute()
-                                    } else {
-                                        lang::action::mute()
-                                    }),
-                            )
-                            .push(
-                                button::big_icon(if *paused { Icon::Play } else { Icon::Pause })
-                                    .on_press(Message::Player {
-                                        grid_id,
-                                        player_id,
-                                        event: Event::SetPause(!*paused),
-                                    })
-                                    .tooltip(if *paused {
-                                        lang::action::play()
-                                    } else {
-                                        lang::action::pause()
-                                    }),
-                            )
-                            .push(
-                                button::icon(if *looping { Icon::Loop } else { Icon::Shuffle })
+                                button::big_icon(Icon::Refresh)
                                     .on_press(Message::Player {
                                         grid_id,
                                         player_id,
-                                        event: Event::SetLoop(!*looping),
+                                        event: Event::Refresh,
                                     })
-                                    .tooltip(if *looping {
-                                        lang::tell::player_will_loop()
-                                    } else {
-                                        lang::tell::player_will_shuffle()
-                                    }),
+                                    .tooltip(lang::action::shuffle()),
                             ),
                     )
                     .center(Length::Fill),
                 );
 
-                let bottom_controls = overlay.bottom_controls.then_some(
-                    Container::new(
-                        Column::new()
-                            .padding(padding::left(10).right(10).bottom(5))
-                            .push(space::vertical())
-                            .push(overlay.timestamps.then_some(timestamps(*position, *duration)))
-                            .push(Container::new(
-                                iced::widget::slider(0.0..=duration.as_secs_f64(), position.as_secs_f64(), move |x| {
-                                    Message::Player {
-                                        grid_id,
-                                        player_id,
-                                        event: Event::Seek(Duration::from_secs_f64(x)),
-                                    }
-                                })
-                                .step(0.1)
-                                .on_release(Message::Player {
-                                    grid_id,
-                                    player_id,
-                                    event: Event::SeekStop,
-                                }),
-                            )),
-                    )
-                    .align_bottom(Length::Fill)
-                    .center_x(Length::Fill),
-                );
-
                 Stack::new()
                     .push(body)
                     .push(controls_background)
-                    .push(oveerlay_top_controls)
+                    .push(top_controls)
                     .push(center_controls)
-                    .push(bottom_controls)
                     .into()
             }
-            Self::Svg {
+            Self::Image {
                 media,
                 handle,
                 position,
@@ -1902,10 +3241,11 @@ impl Player {
                 let overlay = self.overlay(viewport, obscured, *hovered || selected || *dragging);
 
                 let body = Container::new(
-                    Svg::new(handle.clone())
-                        .width(Length::Fill)
-                        .height(Length::Fill)
-                        .content_fit(content_fit.into()),
+                    Image::new(handle)
+                        .width(frame.width)
+                        .height(frame.height)
+                        .content_fit(frame.content_fit)
+                        .filter_method(filter_method.into()),
                 )
                 .align_x(Alignment::Center)
                 .align_y(Alignment::Center)
@@ -1918,7 +3258,7 @@ impl Player {
                         .class(style::Container::ModalBackground),
                 );
 
-                let top_controls = overlay.top_controls.then_some(
+                let oveerlay_top_controls = overlay.top_controls.then_some(
                     Container::new(
                         Row::new()
                             .push(
@@ -1928,25 +3268,9 @@ impl Player {
                                     })
                                     .tooltip(media.path().render()),
                             )
+                            .push(self.content_fit_controls(grid_id, player_id, content_fit))
                             .push(space::horizontal())
-                            .push(
-                                button::icon(Icon::Refresh)
-                                    .on_press(Message::Player {
-                                        grid_id,
-                                        player_id,
-                                        event: Event::Refresh,
-                                    })
-                                    .tooltip(lang::action::shuffle()),
-                            )
-                            .push(
-                                button::icon(Icon::Close)
-                                    .on_press(Message::Player {
-                                        grid_id,
-                                        player_id,
-                                        event: Event::Close,
-                                    })
-                                    .tooltip(lang::action::close()),
-                            ),
+                            .push(self.action_controls(grid_id, player_id, kiosk)),
                     )
                     .align_top(Length::Fill)
                     .width(Length::Fill),
@@ -2006,6 +3330,7 @@ impl Player {
                         Column::new()
                             .padding(padding::left(10).right(10).bottom(5))
                             .push(space::vertical())
+                            .push(overlay.timestamps.then(|| media_date_label(date)).flatten())
                             .push(overlay.timestamps.then_some(timestamps(*position, *duration)))
                             .push(Container::new(
                                 iced::widget::slider(0.0..=duration.as_secs_f64(), position.as_secs_f64(), move |x| {
@@ -2029,15 +3354,15 @@ impl Player {
 
                 Stack::new()
                     .push(body)
+                    .push(dimmer(opacity))
                     .push(controls_background)
-                    .push(top_controls)
+                    .push(oveerlay_top_controls)
                     .push(center_controls)
                     .push(bottom_controls)
                     .into()
             }
-            Self::Gif {
+            Self::Svg {
                 media,
-                frames,
                 handle,
                 position,
                 duration,
@@ -2050,29 +3375,16 @@ impl Player {
             } => {
                 let overlay = self.overlay(viewport, obscured, *hovered || selected || *dragging);
 
-                let body = {
-                    let media = if *paused {
-                        Container::new(
-                            Image::new(handle)
-                                .width(Length::Fill)
-                                .height(Length::Fill)
-                                .content_fit(content_fit.into()),
-                        )
-                    } else {
-                        Container::new(
-                            gif(frames)
-                                .width(Length::Fill)
-                                .height(Length::Fill)
-                                .content_fit(content_fit.into()),
-                        )
-                    };
-
-                    media
-                        .align_x(Alignment::Center)
-                        .align_y(Alignment::Center)
-                        .width(Length::Fill)
-                        .height(Length::Fill)
-                };
+                let body = Container::new(
+                    Svg::new(handle.clone())
+                        .width(frame.width)
+                        .height(frame.height)
+                        .content_fit(frame.content_fit),
+                )
+                .align_x(Alignment::Center)
+                .align_y(Alignment::Center)
+                .width(Length::Fill)
+                .height(Length::Fill);
 
                 let controls_background = overlay.show.then_some(
                     Container::new("")
@@ -2090,25 +3402,9 @@ impl Player {
                                     })
                                     .tooltip(media.path().render()),
                             )
+                            .push(self.content_fit_controls(grid_id, player_id, content_fit))
                             .push(space::horizontal())
-                            .push(
-                                button::icon(Icon::Refresh)
-                                    .on_press(Message::Player {
-                                        grid_id,
-                                        player_id,
-                                        event: Event::Refresh,
-                                    })
-                                    .tooltip(lang::action::shuffle()),
-                            )
-                            .push(
-                                button::icon(Icon::Close)
-                                    .on_press(Message::Player {
-                                        grid_id,
-                                        player_id,
-                                        event: Event::Close,
-                                    })
-                                    .tooltip(lang::action::close()),
-                            ),
+                            .push(self.action_controls(grid_id, player_id, kiosk)),
                     )
                     .align_top(Length::Fill)
                     .width(Length::Fill),
@@ -2168,6 +3464,7 @@ impl Player {
                         Column::new()
                             .padding(padding::left(10).right(10).bottom(5))
                             .push(space::vertical())
+                            .push(overlay.timestamps.then(|| media_date_label(date)).flatten())
                             .push(overlay.timestamps.then_some(timestamps(*position, *duration)))
                             .push(Container::new(
                                 iced::widget::slider(0.0..=duration.as_secs_f64(), position.as_secs_f64(), move |x| {
@@ -2191,13 +3488,14 @@ impl Player {
 
                 Stack::new()
                     .push(body)
+                    .push(dimmer(opacity))
                     .push(controls_background)
                     .push(top_controls)
                     .push(center_controls)
                     .push(bottom_controls)
                     .into()
             }
-            Self::Apng {
+            Self::Animation {
                 media,
                 frames,
                 handle,
@@ -2216,17 +3514,13 @@ impl Player {
                     let media = if *paused {
                         Container::new(
                             Image::new(handle)
-                                .width(Length::Fill)
-                                .height(Length::Fill)
-                                .content_fit(content_fit.into()),
+                                .width(frame.width)
+                                .height(frame.height)
+                                .content_fit(frame.content_fit)
+                                .filter_method(filter_method.into()),
                         )
                     } else {
-                        Container::new(
-                            apng(frames)
-                                .width(Length::Fill)
-                                .height(Length::Fill)
-                                .content_fit(content_fit.into()),
-                        )
+                        Container::new(frames.view(frame))
                     };
 
                     media
@@ -2252,25 +3546,9 @@ impl Player {
                                     })
                                     .tooltip(media.path().render()),
                             )
+                            .push(self.content_fit_controls(grid_id, player_id, content_fit))
                             .push(space::horizontal())
-                            .push(
-                                button::icon(Icon::Refresh)
-                                    .on_press(Message::Player {
-                                        grid_id,
-                                        player_id,
-                                        event: Event::Refresh,
-                                    })
-                                    .tooltip(lang::action::shuffle()),
-                            )
-                            .push(
-                                button::icon(Icon::Close)
-                                    .on_press(Message::Player {
-                                        grid_id,
-                                        player_id,
-                                        event: Event::Close,
-                                    })
-                                    .tooltip(lang::action::close()),
-                            ),
+                            .push(self.action_controls(grid_id, player_id, kiosk)),
                     )
                     .align_top(Length::Fill)
                     .width(Length::Fill),
@@ -2330,6 +3608,7 @@ impl Player {
                         Column::new()
                             .padding(padding::left(10).right(10).bottom(5))
                             .push(space::vertical())
+                            .push(overlay.timestamps.then(|| media_date_label(date)).flatten())
                             .push(overlay.timestamps.then_some(timestamps(*position, *duration)))
                             .push(Container::new(
                                 iced::widget::slider(0.0..=duration.as_secs_f64(), position.as_secs_f64(), move |x| {
@@ -2353,6 +3632,7 @@ impl Player {
 
                 Stack::new()
                     .push(body)
+                    .push(dimmer(opacity))
                     .push(controls_background)
                     .push(top_controls)
                     .push(center_controls)
@@ -2363,6 +3643,7 @@ impl Player {
             Self::Audio {
                 media,
                 sink,
+                tags,
                 duration,
                 paused,
                 looping,
@@ -2372,13 +3653,21 @@ impl Player {
             } => {
                 let overlay = self.overlay(viewport, obscured, *hovered || selected || *dragging);
 
-                let body = (!overlay.show).then_some(
-                    Container::new(Icon::Music.max_control())
-                        .align_x(Alignment::Center)
+                let body = match &tags.cover {
+                    Some(cover) => Some(Container::new(
+                        Image::new(cover.clone())
+                            .width(frame.width)
+                            .height(frame.height)
+                            .content_fit(frame.content_fit),
+                    )),
+                    None => (!overlay.show).then_some(Container::new(Icon::Music.max_control())),
+                }
+                .map(|body| {
+                    body.align_x(Alignment::Center)
                         .align_y(Alignment::Center)
                         .width(Length::Fill)
-                        .height(Length::Fill),
-                );
+                        .height(Length::Fill)
+                });
 
                 let controls_background = overlay.show.then_some(
                     Container::new("")
@@ -2396,25 +3685,9 @@ impl Player {
                                     })
                                     .tooltip(media.path().render()),
                             )
+                            .push(self.content_fit_controls(grid_id, player_id, content_fit))
                             .push(space::horizontal())
-                            .push(
-                                button::icon(Icon::Refresh)
-                                    .on_press(Message::Player {
-                                        grid_id,
-                                        player_id,
-                                        event: Event::Refresh,
-                                    })
-                                    .tooltip(lang::action::shuffle()),
-                            )
-                            .push(
-                                button::icon(Icon::Close)
-                                    .on_press(Message::Player {
-                                        grid_id,
-                                        player_id,
-                                        event: Event::Close,
-                                    })
-                                    .tooltip(lang::action::close()),
-                            ),
+                            .push(self.action_controls(grid_id, player_id, kiosk)),
                     )
                     .align_top(Length::Fill)
                     .width(Length::Fill),
@@ -2476,6 +3749,9 @@ impl Player {
                         Column::new()
                             .padding(padding::left(10).right(10).bottom(5))
                             .push(space::vertical())
+                            .push(text(tags.label(media)))
+                            .push(tags.album.as_ref().map(|album| text(album).size(12)))
+                            .push(overlay.timestamps.then(|| media_date_label(date)).flatten())
                             .push(overlay.timestamps.then_some(timestamps(sink.get_pos(), *duration)))
                             .push(Container::new(
                                 iced::widget::slider(
@@ -2501,6 +3777,7 @@ impl Player {
 
                 Stack::new()
                     .push(body)
+                    .push(dimmer(opacity))
                     .push(controls_background)
                     .push(top_controls)
                     .push(center_controls)
@@ -2511,16 +3788,18 @@ impl Player {
             Self::Video {
                 media,
                 video,
+                seek_previews,
                 position,
                 duration,
                 paused,
+                buffering,
                 dragging,
                 hovered,
                 ..
             } => {
                 let overlay = self.overlay(viewport, obscured, *hovered || selected || *dragging);
 
-                let body = Container::new(build_video_player(video, grid_id, player_id, content_fit))
+                let body = Container::new(build_video_player(video, grid_id, player_id, frame))
                     .align_x(Alignment::Center)
                     .align_y(Alignment::Center)
                     .width(Length::Fill)
@@ -2542,25 +3821,9 @@ impl Player {
                                     })
                                     .tooltip(media.path().render()),
                             )
+                            .push(self.content_fit_controls(grid_id, player_id, content_fit))
                             .push(space::horizontal())
-                            .push(
-                                button::icon(Icon::Refresh)
-                                    .on_press(Message::Player {
-                                        grid_id,
-                                        player_id,
-                                        event: Event::Refresh,
-                                    })
-                                    .tooltip(lang::action::shuffle()),
-                            )
-                            .push(
-                                button::icon(Icon::Close)
-                                    .on_press(Message::Player {
-                                        grid_id,
-                                        player_id,
-                                        event: Event::Close,
-                                    })
-                                    .tooltip(lang::action::close()),
-                            ),
+                            .push(self.action_controls(grid_id, player_id, kiosk)),
                     )
                     .align_top(Length::Fill)
                     .width(Length::Fill),
@@ -2615,11 +3878,26 @@ impl Player {
                     .center(Length::Fill),
                 );
 
+                let seek_preview = dragging
+                    .then(|| seek_previews.nearest(*position))
+                    .flatten()
+                    .map(|handle| {
+                        // Line up the preview with the slider's thumb, but keep it within the player.
+                        let track = (viewport.width - 20.0 - SEEK_PREVIEW_WIDTH).max(0.0);
+                        let ratio = (position.as_secs_f64() / duration.as_secs_f64()).clamp(0.0, 1.0);
+                        let offset = if ratio.is_finite() { track * ratio as f32 } else { 0.0 };
+
+                        Container::new(Image::new(handle).width(SEEK_PREVIEW_WIDTH))
+                            .padding(padding::left(offset).bottom(5))
+                    });
+
                 let bottom_controls = overlay.bottom_controls.then_some(
                     Container::new(
                         Column::new()
                             .padding(padding::left(10).right(10).bottom(5))
                             .push(space::vertical())
+                            .push(seek_preview)
+                            .push(overlay.timestamps.then(|| media_date_label(date)).flatten())
                             .push(overlay.timestamps.then_some(timestamps(*position, *duration)))
                             .push(Container::new(
                                 iced::widget::slider(0.0..=duration.as_secs_f64(), position.as_secs_f64(), move |x| {
@@ -2641,14 +3919,172 @@ impl Player {
                     .center_x(Length::Fill),
                 );
 
+                let buffering_indicator = buffering.then(|| {
+                    Container::new(
+                        Row::new()
+                            .spacing(5)
+                            .align_y(Alignment::Center)
+                            .push(Icon::HourglassEmpty.small_control())
+                            .push(text(lang::tell::buffering())),
+                    )
+                    .center(Length::Fill)
+                });
+
                 Stack::new()
                     .push(body)
+                    .push(dimmer(opacity))
+                    .push(buffering_indicator)
                     .push(controls_background)
                     .push(top_controls)
                     .push(center_controls)
                     .push(bottom_controls)
                     .into()
             }
+        };
+
+        if info.is_none() && progress_bar.is_none() {
+            return content;
+        }
+
+        Stack::new().push(content).push(info).push(progress_bar).into()
+    }
+
+    /// Current position and total duration, for players that advance over time.
+    fn progress(&self) -> Option<(Duration, Duration)> {
+        match self {
+            Self::Idle { .. } => None,
+            Self::Error { .. } => None,
+            Self::Image { position, duration, .. } => Some((*position, *duration)),
+            Self::Svg { position, duration, .. } => Some((*position, *duration)),
+            Self::Animation { position, duration, .. } => Some((*position, *duration)),
+            #[cfg(feature = "audio")]
+            Self::Audio { sink, duration, .. } => Some((sink.get_pos(), *duration)),
+            #[cfg(feature = "video")]
+            Self::Video { position, duration, .. } => Some((*position, *duration)),
+        }
+    }
+
+    /// A thin bar along the bottom edge that shows how far along the media is.
+    fn progress_bar<'a>(&self) -> Option<Container<'a>> {
+        const PRECISION: u16 = 1000;
+
+        let (position, duration) = self.progress()?;
+        if duration.is_zero() {
+            return None;
+        }
+
+        let ratio = (position.as_secs_f64() / duration.as_secs_f64()).clamp(0.0, 1.0);
+        let done = (ratio * f64::from(PRECISION)).round() as u16;
+
+        Some(
+            Container::new(
+                Row::new()
+                    .push(
+                        Container::new("")
+                            .width(Length::FillPortion(done))
+                            .height(3)
+                            .class(style::Container::Progress),
+                    )
+                    .push(Container::new("").width(Length::FillPortion(PRECISION - done))),
+            )
+            .align_bottom(Length::Fill),
+        )
+    }
+
+    fn subtitles(&self) -> Option<&StrictPath> {
+        match self {
+            #[cfg(feature = "video")]
+            Self::Video { subtitles, .. } => subtitles.as_ref(),
+            _ => None,
         }
     }
+
+    /// Details about the media for the info overlay.
+    fn info_panel<'a>(&self, source: Option<&str>) -> Option<Container<'a>> {
+        let path = self.media()?.path();
+
+        Some(
+            Container::new(
+                Container::new(
+                    Column::new()
+                        .push(text(path.leaf().unwrap_or_else(|| path.render())).size(12))
+                        .push(media_date_label(self.date()))
+                        .push(
+                            self.subtitles()
+                                .and_then(|subtitles| subtitles.leaf())
+                                .map(|x| text(x).size(12)),
+                        )
+                        .push(source.map(|source| {
+                            text(format!("{} {}", lang::field(&lang::thing::source()), source)).size(12)
+                        })),
+                )
+                .padding(5)
+                .class(style::Container::Tooltip),
+            )
+            .align_top(Length::Fill)
+            .padding(5),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::testing::repo;
+
+    /// Both fixtures are 4x4 with 2 frames.
+    const FIXTURE_MEMORY: usize = 4 * 4 * 4 * 2;
+
+    fn fixture(name: &str) -> StrictPath {
+        StrictPath::new(format!("{}/tests/media/{name}", repo()))
+    }
+
+    #[test]
+    fn can_load_gif() {
+        let path = fixture("animation.gif");
+        let media = Media::identify(&path).unwrap();
+        assert!(matches!(media, Media::Gif { .. }));
+
+        let playback = Playback::default();
+        let Ok(Player::Animation {
+            frames: Frames::Gif(_),
+            memory,
+            duration,
+            ..
+        }) = Player::new(&media, &playback)
+        else {
+            panic!("GIF did not load as an animation");
+        };
+        assert_eq!(FIXTURE_MEMORY, memory);
+        assert_eq!(Duration::from_secs(playback.image_duration.get() as u64), duration);
+    }
+
+    #[test]
+    fn can_load_apng() {
+        let path = fixture("animation.png");
+        let media = Media::identify(&path).unwrap();
+        assert!(matches!(media, Media::Apng { .. }));
+
+        let playback = Playback::default();
+        let Ok(Player::Animation {
+            frames: Frames::Apng(_),
+            memory,
+            duration,
+            ..
+        }) = Player::new(&media, &playback)
+        else {
+            panic!("APNG did not load as an animation");
+        };
+        assert_eq!(FIXTURE_MEMORY, memory);
+        assert_eq!(Duration::from_secs(playback.image_duration.get() as u64), duration);
+    }
+
+    #[test]
+    fn can_count_gif_frames() {
+        let bytes = fixture("animation.gif").try_read_bytes().unwrap();
+        assert_eq!(Some(2), count_gif_frames(&bytes));
+        assert_eq!(None, count_gif_frames(&bytes[..bytes.len() - 1]));
+    }
 }
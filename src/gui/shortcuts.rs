@@ -94,12 +94,18 @@ impl TextHistory {
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct TextHistories {
     pub image_duration: TextHistory,
+    pub seek_step_image: TextHistory,
+    pub seek_step_audio: TextHistory,
+    pub seek_step_video: TextHistory,
 }
 
 impl TextHistories {
     pub fn new(config: &Config) -> Self {
         Self {
             image_duration: TextHistory::raw(&config.playback.image_duration.to_string()),
+            seek_step_image: TextHistory::raw(&config.playback.seek_step_image.to_string()),
+            seek_step_audio: TextHistory::raw(&config.playback.seek_step_audio.to_string()),
+            seek_step_video: TextHistory::raw(&config.playback.seek_step_video.to_string()),
         }
     }
 }
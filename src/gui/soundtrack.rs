@@ -0,0 +1,148 @@
+use std::time::Duration;
+
+use iced::{Alignment, Length};
+
+use crate::{
+    gui::{
+        button,
+        common::Message,
+        icon::Icon,
+        player::{self, Player},
+        style,
+        widget::{text, Container, Element, Row},
+    },
+    lang, media,
+    path::StrictPath,
+    resource::config::Playback,
+};
+
+/// Audio that plays alongside all of the grids, regardless of what they're showing.
+/// It follows the global controls for pausing, muting, and volume,
+/// but it can also be muted on its own.
+/// The grids are kept muted while it's loaded.
+pub struct Soundtrack {
+    /// As saved in the playlist, possibly with placeholders.
+    path: StrictPath,
+    /// This is `None` if the audio couldn't be loaded.
+    player: Option<Player>,
+    /// Muted separately from the global setting.
+    muted: bool,
+}
+
+impl Soundtrack {
+    pub fn new(path: StrictPath, playlist: Option<&StrictPath>, playback: &Playback) -> Self {
+        let player = Self::load(&media::fill_placeholders_in_path(&path, playlist), playback);
+        Self {
+            path,
+            player,
+            muted: false,
+        }
+    }
+
+    #[cfg(feature = "audio")]
+    fn load(path: &StrictPath, playback: &Playback) -> Option<Player> {
+        let media = media::Media::Audio {
+            path: path.normalized(),
+        };
+
+        match Player::new(&media, playback) {
+            Ok(mut player) => {
+                let _ = player.update(player::Event::SetLoop(true), playback);
+                Some(player)
+            }
+            Err(_) => {
+                log::error!("Unable to load soundtrack: {path:?}");
+                None
+            }
+        }
+    }
+
+    #[cfg(not(feature = "audio"))]
+    fn load(path: &StrictPath, _playback: &Playback) -> Option<Player> {
+        log::warn!("Soundtrack requires audio support: {path:?}");
+        None
+    }
+
+    pub fn path(&self) -> &StrictPath {
+        &self.path
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.muted
+    }
+
+    /// Mute or unmute just the soundtrack.
+    /// It stays muted while the global setting is muted.
+    pub fn set_muted(&mut self, muted: bool, playback: &Playback) {
+        self.muted = muted;
+        self.update(player::Event::SetMute(playback.muted), playback);
+    }
+
+    pub fn is_progressing(&self) -> bool {
+        self.player.as_ref().is_some_and(|player| player.is_progressing())
+    }
+
+    pub fn tick(&mut self, elapsed: Duration, playback: &Playback) {
+        if let Some(player) = self.player.as_mut() {
+            // The player loops on its own, so there's nothing else to do at the end.
+            let _ = player.tick(elapsed, playback);
+        }
+    }
+
+    #[cfg(feature = "audio")]
+    pub fn reload_audio(&mut self, playback: &Playback) {
+        let playback = playback.with_muted(playback.muted || self.muted);
+        if let Some(player) = self.player.as_mut() {
+            player.reload_audio(&playback);
+        }
+    }
+
+    pub fn update(&mut self, event: player::Event, playback: &Playback) {
+        let playback = playback.with_muted(playback.muted || self.muted);
+        let event = match event {
+            player::Event::SetMute(muted) => player::Event::SetMute(muted || self.muted),
+            event => event,
+        };
+
+        if let Some(player) = self.player.as_mut() {
+            let _ = player.update(event, &playback);
+        }
+    }
+
+    pub fn view(&self, obscured: bool) -> Element {
+        let name = self.path.file_stem().unwrap_or_else(|| self.path.render());
+
+        Container::new(
+            Row::new()
+                .spacing(10)
+                .padding([0, 10])
+                .align_y(Alignment::Center)
+                .push(text(lang::field(&lang::thing::soundtrack())))
+                .push(text(name))
+                .push(
+                    self.player
+                        .is_none()
+                        .then(|| text(lang::tell::unable_to_load_soundtrack())),
+                )
+                .push(self.player.is_some().then(|| {
+                    button::mini_icon(if self.muted { Icon::Mute } else { Icon::VolumeHigh })
+                        .on_press(Message::SetSoundtrackMuted(!self.muted))
+                        .obscured(obscured)
+                        .tooltip(if self.muted {
+                            lang::action::unmute()
+                        } else {
+                            lang::action::mute()
+                        })
+                }))
+                .push(
+                    button::mini_icon(Icon::Close)
+                        .on_press(Message::SetSoundtrack { path: None })
+                        .obscured(obscured)
+                        .tooltip(lang::action::remove_soundtrack()),
+                ),
+        )
+        .width(Length::Fill)
+        .class(style::Container::PlayerGroupTitle)
+        .into()
+    }
+}
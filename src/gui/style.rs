@@ -34,6 +34,69 @@ pub struct Theme {
     positive: Color,
     negative: Color,
     disabled: Color,
+    selection: Selection,
+    tiles: Tiles,
+}
+
+/// Shape of the players and grids.
+#[derive(Debug, Clone, Copy)]
+struct Tiles {
+    radius: f32,
+    shadow: bool,
+}
+
+impl Default for Tiles {
+    fn default() -> Self {
+        Self {
+            radius: 10.0,
+            shadow: false,
+        }
+    }
+}
+
+/// Overrides for how selected players and grids are highlighted.
+#[derive(Debug, Clone, Copy, Default)]
+struct Selection {
+    color: Option<Color>,
+    thickness: Option<f32>,
+    high_contrast: bool,
+}
+
+impl Theme {
+    pub fn with_selection(mut self, selection: &config::Selection) -> Self {
+        self.selection = Selection {
+            color: selection.color(),
+            thickness: Some(f32::from(selection.thickness)),
+            high_contrast: selection.high_contrast,
+        };
+        self
+    }
+
+    pub fn with_tiles(mut self, radius: u8, shadow: bool) -> Self {
+        self.tiles = Tiles {
+            radius: f32::from(radius),
+            shadow,
+        };
+        self
+    }
+
+    fn selected_border_color(&self, default: Color) -> Color {
+        match (self.selection.color, self.selection.high_contrast) {
+            (Some(color), true) => color.alpha(1.0),
+            (Some(color), false) => color,
+            (None, true) => self.text,
+            (None, false) => default,
+        }
+    }
+
+    fn selected_border_width(&self) -> f32 {
+        let thickness = self.selection.thickness.unwrap_or(1.0);
+        if self.selection.high_contrast {
+            thickness.max(3.0)
+        } else {
+            thickness
+        }
+    }
 }
 
 impl Default for Theme {
@@ -55,6 +118,8 @@ impl From<config::Theme> for Theme {
                 positive: rgb8!(28, 107, 223),
                 negative: rgb8!(255, 0, 0),
                 disabled: rgb8!(169, 169, 169),
+                selection: Selection::default(),
+                tiles: Tiles::default(),
             },
             config::Theme::Dark => Self {
                 source,
@@ -240,6 +305,14 @@ pub enum Container {
     PlayerGroupTitle,
     Tooltip,
     FileDrag,
+    Backdrop {
+        color: Color,
+    },
+    /// Covers media to make it look less opaque.
+    Dimmer {
+        opacity: f32,
+    },
+    Progress,
 }
 impl container::Catalog for Theme {
     type Class<'a> = Container;
@@ -259,6 +332,9 @@ impl container::Catalog for Theme {
                 Container::ModalBackground => self.field.alpha(0.5).into(),
                 Container::Tooltip => self.field.into(),
                 Container::FileDrag => self.field.alpha(0.9).into(),
+                Container::Backdrop { color } => (*color).into(),
+                Container::Dimmer { opacity } => self.background.alpha(1.0 - opacity).into(),
+                Container::Progress => self.positive.into(),
                 _ => self.background.into(),
             }),
             border: Border {
@@ -266,14 +342,14 @@ impl container::Catalog for Theme {
                     Container::Wrapper => Color::TRANSPARENT,
                     Container::Player { selected } => {
                         if *selected {
-                            self.positive.alpha(0.8)
+                            self.selected_border_color(self.positive.alpha(0.8))
                         } else {
                             self.field.alpha(0.8)
                         }
                     }
                     Container::PlayerGroup { selected } => {
                         if *selected {
-                            self.positive
+                            self.selected_border_color(self.positive)
                         } else {
                             self.field
                         }
@@ -284,6 +360,9 @@ impl container::Catalog for Theme {
                     _ => self.text,
                 },
                 width: match class {
+                    Container::Player { selected: true } | Container::PlayerGroup { selected: true } => {
+                        self.selected_border_width()
+                    }
                     Container::Player { .. }
                     | Container::PlayerGroup { .. }
                     | Container::PlayerGroupControls
@@ -292,11 +371,10 @@ impl container::Catalog for Theme {
                     _ => 0.0,
                 },
                 radius: match class {
-                    Container::ModalForeground | Container::Player { .. } | Container::PlayerGroupControls => {
-                        10.0.into()
-                    }
-                    Container::PlayerGroup { .. } => Radius::new(10.0).top(0.0),
-                    Container::PlayerGroupTitle => Radius::new(10.0).bottom(0.0),
+                    Container::ModalForeground | Container::PlayerGroupControls => 10.0.into(),
+                    Container::Player { .. } => self.tiles.radius.into(),
+                    Container::PlayerGroup { .. } => Radius::new(self.tiles.radius).top(0.0),
+                    Container::PlayerGroupTitle => Radius::new(self.tiles.radius).bottom(0.0),
                     Container::ModalBackground => 5.0.into(),
                     Container::Tooltip => 20.0.into(),
                     _ => 0.0.into(),
@@ -306,10 +384,17 @@ impl container::Catalog for Theme {
                 Container::Wrapper => None,
                 _ => Some(self.text),
             },
-            shadow: Shadow {
-                color: Color::TRANSPARENT,
-                offset: Vector::ZERO,
-                blur_radius: 0.0,
+            shadow: match class {
+                Container::Player { .. } | Container::PlayerGroup { .. } if self.tiles.shadow => Shadow {
+                    color: Color::BLACK.alpha(0.4),
+                    offset: Vector::new(0.0, 2.0),
+                    blur_radius: 8.0,
+                },
+                _ => Shadow {
+                    color: Color::TRANSPARENT,
+                    offset: Vector::ZERO,
+                    blur_radius: 0.0,
+                },
             },
             snap: true,
         }
@@ -8,6 +8,9 @@ use unic_langid::LanguageIdentifier;
 
 use crate::prelude::Error;
 
+const ACTIVE: &str = "active";
+const NUMBER: &str = "number";
+const TOTAL: &str = "total";
 const VERSION: &str = "version";
 
 /// Display language.
@@ -197,10 +200,21 @@ pub fn handle_error(error: &Error) -> String {
     let error = match error {
         Error::ConfigInvalid { why } => format!("{}\n\n{why}", tell::config_is_invalid()),
         Error::NoMediaFound => tell::no_media_found_in_sources(),
+        Error::NoSubfoldersFound(path) => format!("{}\n\n{}", tell::no_subfolders_found(), path.render()),
         Error::PlaylistInvalid { why } => format!("{}\n\n{why}", tell::playlist_is_invalid()),
         Error::UnableToOpenPath(path) => format!("{}\n\n{}", tell::unable_to_open_path(), path.render()),
         Error::UnableToOpenUrl(url) => format!("{}\n\n{}", tell::unable_to_open_url(), url),
+        Error::UnableToCopyFile { path, why } => {
+            format!("{}\n\n{}\n\n{why}", tell::unable_to_copy_file(), path.render())
+        }
+        Error::UnableToMoveFile { path, why } => {
+            format!("{}\n\n{}\n\n{why}", tell::unable_to_move_file(), path.render())
+        }
+        Error::UnableToMoveToTrash { path, why } => {
+            format!("{}\n\n{}\n\n{why}", tell::unable_to_move_file_to_trash(), path.render())
+        }
         Error::UnableToSavePlaylist { why } => format!("{}\n\n{why}", tell::unable_to_save_playlist()),
+        Error::VideoPipelineInvalid { why } => format!("{}\n\n{why}", tell::video_pipeline_is_invalid()),
     };
 
     format!("{} {}", field(&thing::error()), error)
@@ -221,6 +235,10 @@ pub mod thing {
         translate("thing-application")
     }
 
+    pub fn aspect_ratio() -> String {
+        translate("thing-aspect-ratio")
+    }
+
     pub fn audio() -> String {
         translate("thing-audio")
     }
@@ -233,6 +251,14 @@ pub mod thing {
         translate("thing-error")
     }
 
+    pub fn fallback_source() -> String {
+        translate("thing-fallback-source")
+    }
+
+    pub fn filter() -> String {
+        translate("thing-filter")
+    }
+
     pub fn glob() -> String {
         translate("thing-glob")
     }
@@ -253,10 +279,28 @@ pub mod thing {
         translate("thing-layout")
     }
 
+    pub fn log_level() -> String {
+        translate("thing-log-level")
+    }
+
     pub fn media() -> String {
         translate("thing-media")
     }
 
+    pub fn name() -> String {
+        translate("thing-name")
+    }
+
+    pub fn numbered_grid(number: usize) -> String {
+        let mut args = FluentArgs::new();
+        args.set(NUMBER, number);
+        translate_args("thing-numbered-grid", &args)
+    }
+
+    pub fn opacity() -> String {
+        translate("thing-opacity")
+    }
+
     pub fn orientation() -> String {
         translate("thing-orientation")
     }
@@ -269,10 +313,30 @@ pub mod thing {
         translate("thing-playlist")
     }
 
+    pub fn preset() -> String {
+        translate("thing-preset")
+    }
+
+    pub fn refresh_button() -> String {
+        translate("thing-refresh-button")
+    }
+
+    pub fn scaling() -> String {
+        translate("thing-scaling")
+    }
+
     pub fn settings() -> String {
         translate("thing-settings")
     }
 
+    pub fn soundtrack() -> String {
+        translate("thing-soundtrack")
+    }
+
+    pub fn source() -> String {
+        translate("thing-source")
+    }
+
     pub fn sources() -> String {
         translate("thing-sources")
     }
@@ -281,6 +345,14 @@ pub mod thing {
         translate("thing-theme")
     }
 
+    pub fn update_rate() -> String {
+        translate("thing-update-rate")
+    }
+
+    pub fn video() -> String {
+        translate("thing-video")
+    }
+
     pub mod key {
         use super::*;
 
@@ -297,18 +369,42 @@ pub mod action {
         translate("action-add-player")
     }
 
+    pub fn allow_duplicate_media_across_groups() -> String {
+        translate("action-allow-duplicate-media-across-groups")
+    }
+
+    pub fn apply_to_all_grids() -> String {
+        translate("action-apply-to-all-grids")
+    }
+
     pub fn cancel() -> String {
         translate("action-cancel")
     }
 
+    pub fn check_for_new_media() -> String {
+        translate("action-check-for-new-media")
+    }
+
     pub fn check_for_updates() -> String {
         translate("action-check-for-updates")
     }
 
+    pub fn choose_preset() -> String {
+        translate("action-choose-preset")
+    }
+
+    pub fn choose_soundtrack() -> String {
+        translate("action-choose-soundtrack")
+    }
+
     pub fn close() -> String {
         translate("action-close")
     }
 
+    pub fn collapse_duplicate_files() -> String {
+        translate("action-collapse-duplicate-files")
+    }
+
     pub fn confirm() -> String {
         translate("action-confirm")
     }
@@ -317,26 +413,110 @@ pub mod action {
         translate("action-confirm-when-discarding-unsaved-playlist")
     }
 
+    pub fn confirm_when_exiting_while_playing() -> String {
+        translate("action-confirm-when-exiting-while-playing")
+    }
+
+    pub fn copy_to_folder() -> String {
+        translate("action-copy-to-folder")
+    }
+
+    pub fn create_grid_per_subfolder() -> String {
+        translate("action-create-grid-per-subfolder")
+    }
+
     pub fn crop() -> String {
         translate("action-crop")
     }
 
+    pub fn cycle_through_media_in_order() -> String {
+        translate("action-cycle-through-media-in-order")
+    }
+
     pub fn desynchronize() -> String {
         translate("action-desynchronize")
     }
 
+    pub fn duplicate() -> String {
+        translate("action-duplicate")
+    }
+
+    pub fn edit_sources() -> String {
+        translate("action-edit-sources")
+    }
+
+    pub fn even_out_layout() -> String {
+        translate("action-even-out-layout")
+    }
+
     pub fn exit_app() -> String {
         translate("action-exit-app")
     }
 
+    pub fn follow_symlinked_folders() -> String {
+        translate("action-follow-symlinked-folders")
+    }
+
+    pub fn freeze_globs() -> String {
+        translate("action-freeze-globs")
+    }
+
+    pub fn hide_for_this_session() -> String {
+        translate("action-hide-for-this-session")
+    }
+
     pub fn jump_position() -> String {
         translate("action-jump-position")
     }
 
+    pub fn keep_content_fit_for_new_media() -> String {
+        translate("action-keep-content-fit-for-new-media")
+    }
+
+    pub fn keep_screen_awake_during_playback() -> String {
+        translate("action-keep-screen-awake-during-playback")
+    }
+
+    pub fn load_subtitles_automatically() -> String {
+        translate("action-load-subtitles-automatically")
+    }
+
+    pub fn lock_layout() -> String {
+        translate("action-lock-layout")
+    }
+
+    pub fn maximize() -> String {
+        translate("action-maximize")
+    }
+
+    pub fn mirror_another_grid() -> String {
+        translate("action-mirror-another-grid")
+    }
+
+    pub fn move_to_folder() -> String {
+        translate("action-move-to-folder")
+    }
+
+    pub fn move_to_trash() -> String {
+        translate("action-move-to-trash")
+    }
+
     pub fn mute() -> String {
         translate("action-mute")
     }
 
+    pub fn mute_new_players() -> String {
+        translate("action-mute-new-players")
+    }
+
+    pub fn mute_when_window_is_minimized() -> String {
+        translate("action-mute-when-window-is-minimized")
+    }
+
+    pub fn normalize_audio_volume() -> String {
+        translate("action-normalize-audio-volume")
+    }
+
     pub fn open_folder() -> String {
         translate("action-open-folder")
     }
@@ -345,6 +525,14 @@ pub mod action {
         translate("action-open-file")
     }
 
+    pub fn open_config_folder() -> String {
+        translate("action-open-config-folder")
+    }
+
+    pub fn open_log_folder() -> String {
+        translate("action-open-log-folder")
+    }
+
     pub fn open_playlist() -> String {
         translate("action-open-playlist")
     }
@@ -353,18 +541,98 @@ pub mod action {
         translate("action-pause")
     }
 
+    #[cfg_attr(not(feature = "audio"), allow(unused))]
+    pub fn pause_all_audio() -> String {
+        translate("action-pause-all-audio")
+    }
+
+    pub fn pause_all_images() -> String {
+        translate("action-pause-all-images")
+    }
+
+    #[cfg_attr(not(feature = "video"), allow(unused))]
+    pub fn pause_all_videos() -> String {
+        translate("action-pause-all-videos")
+    }
+
+    pub fn pause_when_window_is_hidden() -> String {
+        translate("action-pause-when-window-is-hidden")
+    }
+
     pub fn pause_when_window_loses_focus() -> String {
         translate("action-pause-when-window-loses-focus")
     }
 
+    pub fn pin_to_keyboard_shortcut() -> String {
+        translate("action-pin-to-keyboard-shortcut")
+    }
+
     pub fn play() -> String {
         translate("action-play")
     }
 
+    #[cfg_attr(not(feature = "audio"), allow(unused))]
+    pub fn play_all_audio() -> String {
+        translate("action-play-all-audio")
+    }
+
+    pub fn play_all_images() -> String {
+        translate("action-play-all-images")
+    }
+
+    #[cfg_attr(not(feature = "video"), allow(unused))]
+    pub fn play_all_videos() -> String {
+        translate("action-play-all-videos")
+    }
+
     pub fn play_for_this_many_seconds() -> String {
         translate("action-play-for-this-many-seconds")
     }
 
+    pub fn play_images_for_this_many_seconds() -> String {
+        translate("action-play-images-for-this-many-seconds")
+    }
+
+    pub fn play_random_clips_of_this_many_seconds() -> String {
+        translate("action-play-random-clips-of-this-many-seconds")
+    }
+
+    pub fn randomize_layout() -> String {
+        translate("action-randomize-layout")
+    }
+
+    pub fn remove_soundtrack() -> String {
+        translate("action-remove-soundtrack")
+    }
+
+    pub fn report_issue() -> String {
+        translate("action-report-issue")
+    }
+
+    pub fn repeat() -> String {
+        translate("action-repeat")
+    }
+
+    pub fn rescan_sources_automatically() -> String {
+        translate("action-rescan-sources-automatically")
+    }
+
+    pub fn reset_content_fit_for_new_media() -> String {
+        translate("action-reset-content-fit-for-new-media")
+    }
+
+    pub fn restore_globs() -> String {
+        translate("action-restore-globs")
+    }
+
+    pub fn restore_layout() -> String {
+        translate("action-restore-layout")
+    }
+
+    pub fn restore_last_session_on_launch() -> String {
+        translate("action-restore-last-session-on-launch")
+    }
+
     pub fn save_playlist() -> String {
         translate("action-save-playlist")
     }
@@ -373,6 +641,14 @@ pub mod action {
         translate("action-save-playlist-as-new-file")
     }
 
+    pub fn save_playlist_automatically() -> String {
+        translate("action-save-playlist-automatically")
+    }
+
+    pub fn save_window_size_in_playlist() -> String {
+        translate("action-save-window-size-in-playlist")
+    }
+
     pub fn scale() -> String {
         translate("action-scale")
     }
@@ -381,6 +657,14 @@ pub mod action {
         translate("action-scale-down")
     }
 
+    pub fn scale_pixelated() -> String {
+        translate("action-scale-pixelated")
+    }
+
+    pub fn scale_smoothly() -> String {
+        translate("action-scale-smoothly")
+    }
+
     pub fn select_folder() -> String {
         translate("action-select-folder")
     }
@@ -389,10 +673,38 @@ pub mod action {
         translate("action-select-file")
     }
 
+    pub fn show_media_counter() -> String {
+        translate("action-show-media-counter")
+    }
+
+    pub fn show_media_date() -> String {
+        translate("action-show-media-date")
+    }
+
+    pub fn show_notice_when_no_media_found() -> String {
+        translate("action-show-notice-when-no-media-found")
+    }
+
+    pub fn show_progress_bars() -> String {
+        translate("action-show-progress-bars")
+    }
+
     pub fn shuffle() -> String {
         translate("action-shuffle")
     }
 
+    pub fn shuffle_and_check_for_new_media() -> String {
+        translate("action-shuffle-and-check-for-new-media")
+    }
+
+    pub fn shuffle_when_selected() -> String {
+        translate("action-shuffle-when-selected")
+    }
+
+    pub fn solo_audio() -> String {
+        translate("action-solo-audio")
+    }
+
     pub fn split_horizontally() -> String {
         translate("action-split-horizontally")
     }
@@ -405,6 +717,22 @@ pub mod action {
         translate("action-start-new-playlist")
     }
 
+    pub fn start_playlist_rotation() -> String {
+        translate("action-start-playlist-rotation")
+    }
+
+    pub fn step_this_many_seconds() -> String {
+        translate("action-step-this-many-seconds")
+    }
+
+    pub fn stop() -> String {
+        translate("action-stop")
+    }
+
+    pub fn stop_playlist_rotation() -> String {
+        translate("action-stop-playlist-rotation")
+    }
+
     pub fn stretch() -> String {
         translate("action-stretch")
     }
@@ -413,42 +741,153 @@ pub mod action {
         translate("action-synchronize")
     }
 
+    pub fn toggle_pause_on_click() -> String {
+        translate("action-toggle-pause-on-click")
+    }
+
+    pub fn toggle_pause_with_space() -> String {
+        translate("action-toggle-pause-with-space")
+    }
+
+    pub fn unlock_layout() -> String {
+        translate("action-unlock-layout")
+    }
+
     pub fn unmute() -> String {
         translate("action-unmute")
     }
 
+    pub fn unpin_from_keyboard_shortcut() -> String {
+        translate("action-unpin-from-keyboard-shortcut")
+    }
+
+    pub fn unsolo_audio() -> String {
+        translate("action-unsolo-audio")
+    }
+
+    pub fn use_high_contrast_selection() -> String {
+        translate("action-use-high-contrast-selection")
+    }
+
     pub fn view_releases() -> String {
         translate("action-view-releases")
     }
+
+    pub fn when_media_ends() -> String {
+        translate("action-when-media-ends")
+    }
 }
 
 pub mod state {
     use super::*;
 
+    pub fn all_players() -> String {
+        translate("state-all-players")
+    }
+
+    pub fn balanced() -> String {
+        translate("state-balanced")
+    }
+
+    pub fn contact_sheet() -> String {
+        translate("state-contact-sheet")
+    }
+
     pub fn dark() -> String {
         translate("state-dark")
     }
 
+    pub fn double_click() -> String {
+        translate("state-double-click")
+    }
+
+    pub fn grayscale() -> String {
+        translate("state-grayscale")
+    }
+
     pub fn horizontal() -> String {
         translate("state-horizontal")
     }
 
+    pub fn inverted() -> String {
+        translate("state-inverted")
+    }
+
     pub fn light() -> String {
         translate("state-light")
     }
 
+    pub fn off() -> String {
+        translate("state-off")
+    }
+
+    pub fn original() -> String {
+        translate("state-original")
+    }
+
+    pub fn player_under_cursor() -> String {
+        translate("state-player-under-cursor")
+    }
+
+    pub fn power_saving() -> String {
+        translate("state-power-saving")
+    }
+
+    pub fn selection_only() -> String {
+        translate("state-selection-only")
+    }
+
+    pub fn sepia() -> String {
+        translate("state-sepia")
+    }
+
+    pub fn single_click() -> String {
+        translate("state-single-click")
+    }
+
+    pub fn single_focus() -> String {
+        translate("state-single-focus")
+    }
+
+    pub fn smooth() -> String {
+        translate("state-smooth")
+    }
+
     pub fn vertical() -> String {
         translate("state-vertical")
     }
+
+    pub fn video_wall() -> String {
+        translate("state-video-wall")
+    }
 }
 
 pub mod tell {
     use super::*;
 
+    pub fn active_players_of_total_media(active: usize, total: usize) -> String {
+        let mut args = FluentArgs::new();
+        args.set(ACTIVE, active);
+        args.set(TOTAL, total);
+        translate_args("tell-active-players-of-total-media", &args)
+    }
+
+    pub fn buffering() -> String {
+        translate("tell-buffering")
+    }
+
     pub fn config_is_invalid() -> String {
         translate("tell-config-is-invalid")
     }
 
+    pub fn media_is_playing() -> String {
+        translate("tell-media-is-playing")
+    }
+
+    pub fn player_is_too_small() -> String {
+        translate("tell-player-is-too-small")
+    }
+
     pub fn player_will_loop() -> String {
         translate("tell-player-will-loop")
     }
@@ -475,11 +914,43 @@ pub mod tell {
         translate("tell-no-media-found-in-sources")
     }
 
+    pub fn no_subfolders_found() -> String {
+        translate("tell-no-subfolders-found")
+    }
+
+    pub fn only_first_folders_will_be_used(total: usize) -> String {
+        let mut args = FluentArgs::new();
+        args.set(TOTAL, total);
+        translate_args("tell-only-first-folders-will-be-used", &args)
+    }
+
+    pub fn skipped_files_over_size_limit(total: usize) -> String {
+        let mut args = FluentArgs::new();
+        args.set(TOTAL, total);
+        translate_args("tell-skipped-files-over-size-limit", &args)
+    }
+
+    pub fn unable_to_copy_file() -> String {
+        translate("tell-unable-to-copy-file")
+    }
+
     #[allow(unused)]
     pub fn unable_to_determine_media_duration() -> String {
         translate("tell-unable-to-determine-media-duration")
     }
 
+    pub fn unable_to_load_soundtrack() -> String {
+        translate("tell-unable-to-load-soundtrack")
+    }
+
+    pub fn unable_to_move_file() -> String {
+        translate("tell-unable-to-move-file")
+    }
+
+    pub fn unable_to_move_file_to_trash() -> String {
+        translate("tell-unable-to-move-file-to-trash")
+    }
+
     pub fn unable_to_open_path() -> String {
         translate("tell-unable-to-open-path")
     }
@@ -491,19 +962,51 @@ pub mod tell {
     pub fn unable_to_save_playlist() -> String {
         translate("tell-unable-to-save-playlist")
     }
+
+    pub fn video_pipeline_is_invalid() -> String {
+        translate("tell-video-pipeline-is-invalid")
+    }
 }
 
 pub mod ask {
     use super::*;
 
+    pub fn apply_layout_to_grids(total: usize) -> String {
+        let mut args = FluentArgs::new();
+        args.set(TOTAL, total);
+        translate_args("ask-apply-layout-to-grids", &args)
+    }
+
+    pub fn copy_file_to_folder() -> String {
+        translate("ask-copy-file-to-folder")
+    }
+
     pub fn discard_changes() -> String {
         translate("ask-discard-changes")
     }
 
+    pub fn exit_anyway() -> String {
+        translate("ask-exit-anyway")
+    }
+
     pub fn load_new_playlist_anyway() -> String {
         translate("ask-load-new-playlist-anyway")
     }
 
+    pub fn move_file_to_folder() -> String {
+        translate("ask-move-file-to-folder")
+    }
+
+    pub fn move_file_to_trash() -> String {
+        translate("ask-move-file-to-trash")
+    }
+
+    pub fn replace_layout_with_folder_grids(total: usize) -> String {
+        let mut args = FluentArgs::new();
+        args.set(TOTAL, total);
+        translate_args("ask-replace-layout-with-folder-grids", &args)
+    }
+
     pub fn view_release_notes() -> String {
         translate("ask-view-release-notes")
     }
@@ -18,7 +18,7 @@ mod testing;
 
 use crate::{
     gui::Flags,
-    prelude::{app_dir, CONFIG_DIR, VERSION},
+    prelude::{app_dir, retain_logger, CONFIG_DIR, VERSION},
 };
 
 /// The logger handle must be retained until the application closes.
@@ -111,9 +111,12 @@ fn prepare_winit() {
 /// We have tried relaunching the program with a special environment variable,
 /// but that eventually raised a false positive from Windows Defender (`Win32/Wacapew.C!ml`).
 ///
-/// We may eventually want to try using a manifest to set `<consoleAllocationPolicy>`,
+/// We may eventually want to rely on a manifest to set `<consoleAllocationPolicy>`,
 /// but that is not yet widely available:
 /// https://github.com/microsoft/terminal/blob/5383cb3a1bb8095e214f7d4da085ea4646db8868/doc/specs/%237335%20-%20Console%20Allocation%20Policy.md
+/// This can be tested by building with the `console-allocation-policy` feature.
+/// Windows versions that support the policy won't give us a console when launched from Explorer,
+/// so there's nothing to detach, but older versions ignore it and still need this function.
 ///
 /// ## Considerations
 /// The current approach is to let the console appear and then immediately `FreeConsole`.
@@ -135,6 +138,11 @@ unsafe fn detach_console() {
         System::Console::{FreeConsole, SetStdHandle, STD_ERROR_HANDLE, STD_INPUT_HANDLE, STD_OUTPUT_HANDLE},
     };
 
+    #[cfg(feature = "console-allocation-policy")]
+    if windows::Win32::System::Console::GetConsoleWindow().is_invalid() {
+        return;
+    }
+
     fn tell(msg: &str) {
         eprintln!("{msg}");
         log::error!("{msg}");
@@ -170,6 +178,9 @@ fn main() {
     let logger = prepare_logging();
     #[allow(clippy::useless_asref)]
     prepare_panic_hook(logger.as_ref().map(|x| x.clone()).ok());
+    if let Ok(logger) = &logger {
+        retain_logger(logger.clone());
+    }
     let flush_logger = || {
         if let Ok(logger) = &logger {
             logger.flush();
@@ -196,8 +207,23 @@ fn main() {
     match args.sub {
         None => {
             // Do any extra CLI parsing before we detach the console.
-            let mut sources = cli::parse_sources(args.sources);
-            sources.extend(args.glob.into_iter().map(media::Source::new_glob));
+            let mut playlist = None;
+            let mut sources = vec![];
+            if args.playlist_stdin {
+                match cli::parse_playlist_from_stdin() {
+                    Ok(parsed) => {
+                        playlist = Some(parsed);
+                    }
+                    Err(e) => {
+                        eprintln!("{}", lang::handle_error(&e));
+                        flush_logger();
+                        std::process::exit(1);
+                    }
+                }
+            } else {
+                sources = cli::parse_sources(args.sources);
+                sources.extend(args.glob.into_iter().map(media::Source::new_glob));
+            }
 
             #[cfg(target_os = "windows")]
             if std::env::var(crate::prelude::ENV_DEBUG).is_err() {
@@ -206,7 +232,11 @@ fn main() {
                 }
             }
 
-            let flags = Flags { sources };
+            let flags = Flags {
+                sources,
+                playlist,
+                kiosk: args.kiosk,
+            };
             gui::run(flags);
         }
         Some(sub) => {
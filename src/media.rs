@@ -1,18 +1,139 @@
-use std::collections::{BTreeSet, HashMap, HashSet};
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
 
 use itertools::Itertools;
 
-use crate::{lang, path::StrictPath};
+use crate::{
+    lang,
+    path::{expand_env_vars, StrictPath},
+    resource::cache::ContentHash,
+};
 
 mod placeholder {
     pub const PLAYLIST: &str = "<playlist>";
 }
 
+/// Resolve placeholders and environment variables in a path that the user entered.
 pub fn fill_placeholders_in_path(path: &StrictPath, playlist: Option<&StrictPath>) -> StrictPath {
     let playlist = playlist
         .and_then(|x| x.parent_if_file().ok())
         .unwrap_or_else(StrictPath::cwd);
-    path.replace_raw_prefix(placeholder::PLAYLIST, playlist.raw_ref())
+    path.with_env_vars_expanded()
+        .replace_raw_prefix(placeholder::PLAYLIST, playlist.raw_ref())
+}
+
+/// Express a path with the playlist placeholder when it's possible to make it relative,
+/// so that the playlist can be moved along with its media.
+pub fn path_in_playlist(path: StrictPath, playlist: Option<&StrictPath>) -> StrictPath {
+    playlist
+        .and_then(|playlist| playlist.parent_if_file().ok())
+        .and_then(|folder| path.relative_to(&folder))
+        .map(|relative| StrictPath::new(format!("{}/{}", placeholder::PLAYLIST, relative.raw_ref())))
+        .unwrap_or(path)
+}
+
+/// How many scan results to report at once.
+const SCAN_BATCH_SIZE: usize = 100;
+
+const BYTES_PER_MEGABYTE: u64 = 1_000_000;
+
+/// How many scans can run at once if the number of CPUs is unknown.
+const DEFAULT_SCAN_CONCURRENCY: usize = 4;
+
+static SCAN_PERMITS: Mutex<Option<Arc<tokio::sync::Semaphore>>> = Mutex::new(None);
+
+fn default_scan_concurrency() -> usize {
+    std::thread::available_parallelism()
+        .map(|x| x.get())
+        .unwrap_or(DEFAULT_SCAN_CONCURRENCY)
+}
+
+/// Limit how many scans can run at once.
+/// If unset, then this is the number of CPUs.
+pub fn set_scan_concurrency(limit: Option<NonZeroUsize>) {
+    let limit = limit.map(|x| x.get()).unwrap_or_else(default_scan_concurrency);
+    *SCAN_PERMITS.lock().unwrap() = Some(Arc::new(tokio::sync::Semaphore::new(limit)));
+}
+
+/// Each scan should hold one of these permits while it runs.
+pub fn scan_permits() -> Arc<tokio::sync::Semaphore> {
+    SCAN_PERMITS
+        .lock()
+        .unwrap()
+        .get_or_insert_with(|| Arc::new(tokio::sync::Semaphore::new(default_scan_concurrency())))
+        .clone()
+}
+
+/// Scans that have been started but not finished, including ones still waiting for a permit.
+static SCANS_IN_PROGRESS: AtomicUsize = AtomicUsize::new(0);
+
+pub fn start_scan() {
+    SCANS_IN_PROGRESS.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Returns whether this was the last scan in progress.
+pub fn finish_scan() -> bool {
+    SCANS_IN_PROGRESS.fetch_sub(1, Ordering::SeqCst) == 1
+}
+
+pub fn is_scanning() -> bool {
+    SCANS_IN_PROGRESS.load(Ordering::SeqCst) > 0
+}
+
+/// Check if a folder contains the ignore marker.
+/// Its parents aren't checked, since they're outside of the source.
+fn is_ignored_dir(dir: &StrictPath, marker: &str) -> bool {
+    dir.joined(marker).exists()
+}
+
+/// Skip files larger than these limits when scanning, in megabytes.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+)]
+#[serde(default)]
+pub struct SizeLimits {
+    /// Applies to any category without its own limit.
+    pub all: Option<u64>,
+    pub image: Option<u64>,
+    pub audio: Option<u64>,
+    pub video: Option<u64>,
+}
+
+impl SizeLimits {
+    fn for_category(&self, category: Category) -> Option<u64> {
+        let specific = match category {
+            Category::Image => self.image,
+            #[cfg(feature = "audio")]
+            Category::Audio => self.audio,
+            #[cfg(feature = "video")]
+            Category::Video => self.video,
+        };
+        specific.or(self.all)
+    }
+
+    fn allows(&self, media: &Media, size: u64) -> bool {
+        match self.for_category(media.category()) {
+            Some(limit) => size <= limit.saturating_mul(BYTES_PER_MEGABYTE),
+            None => true,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -38,6 +159,11 @@ impl Source {
         Self::Path { path }
     }
 
+    /// Like `new_path`, but relative to the playlist's folder when possible.
+    pub fn new_path_in_playlist(path: StrictPath, playlist: Option<&StrictPath>) -> Self {
+        Self::new_path(path_in_playlist(path, playlist))
+    }
+
     pub fn new_glob(pattern: String) -> Self {
         Self::Glob { pattern }
     }
@@ -99,18 +225,29 @@ impl Source {
             Self::Path { path } => Self::Path {
                 path: fill_placeholders_in_path(path, Some(playlist)),
             },
-            Self::Glob { pattern } => Self::Glob {
-                pattern: match pattern.strip_prefix(placeholder::PLAYLIST) {
-                    Some(suffix) => format!("{}{}", playlist.render(), suffix),
-                    None => pattern.clone(),
-                },
-            },
+            Self::Glob { pattern } => {
+                let pattern = expand_env_vars(pattern);
+                Self::Glob {
+                    pattern: match pattern.strip_prefix(placeholder::PLAYLIST) {
+                        Some(suffix) => format!("{}{}", playlist.render(), suffix),
+                        None => pattern,
+                    },
+                }
+            }
         }
     }
 
     pub fn has_playlist_placeholder(&self) -> bool {
         self.raw().contains(placeholder::PLAYLIST)
     }
+
+    /// Whether this source points directly at a single file.
+    pub fn is_file(&self) -> bool {
+        match self {
+            Self::Path { path } => path.is_file(),
+            Self::Glob { .. } => false,
+        }
+    }
 }
 
 impl Default for Source {
@@ -173,17 +310,58 @@ pub enum Scan {
         original_source: Option<Source>,
         playlist: Option<StrictPath>,
         context: RefreshContext,
+        follow_symlinks: bool,
+        /// Skip folders containing a file with this name, along with their subfolders.
+        ignore_marker: Option<String>,
+        max_file_size: SizeLimits,
     },
     Identify {
         source: Source,
         path: StrictPath,
         context: RefreshContext,
+        max_file_size: SizeLimits,
     },
     Found {
         source: Source,
         media: Media,
+        /// In bytes.
+        size: u64,
+        context: RefreshContext,
+    },
+    /// Check if the media has the same content as any of the candidates,
+    /// which are known media of the same size.
+    CompareContent {
+        source: Source,
+        media: Media,
+        size: u64,
+        candidates: Vec<Media>,
+        /// Previously saved hashes for any of the files, which may be outdated.
+        hashes: Vec<(StrictPath, ContentHash)>,
+        context: RefreshContext,
+    },
+    /// The media was compared to others and doesn't have the same content as any of them.
+    Unique {
+        source: Source,
+        media: Media,
+        size: u64,
+        /// The candidates that it was compared to,
+        /// in case more media of the same size was found in the meantime.
+        compared: Vec<Media>,
+        /// Hashes that were calculated during the comparison, to be saved.
+        hashes: Vec<(StrictPath, ContentHash)>,
         context: RefreshContext,
     },
+    /// The media has the same content as other media, which should be used instead.
+    Duplicate {
+        source: Source,
+        media: Media,
+        original: Media,
+        /// Hashes that were calculated during the comparison, to be saved.
+        hashes: Vec<(StrictPath, ContentHash)>,
+        context: RefreshContext,
+    },
+    /// The file was skipped because it exceeded the size limit.
+    TooLarge { source: Source, path: StrictPath },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -246,7 +424,9 @@ impl Media {
         }
     }
 
-    fn identify(path: &StrictPath) -> Option<Self> {
+    pub fn identify(path: &StrictPath) -> Option<Self> {
+        let extension = path.file_extension().map(|x| x.to_lowercase());
+
         let inferrable = match path.as_std_path_buf() {
             Ok(pb) => pb,
             Err(e) => {
@@ -256,7 +436,7 @@ impl Media {
         };
 
         #[allow(clippy::unnecessary_lazy_evaluations)]
-        let mime = infer::get_from_path(&inferrable)
+        let sniffed = infer::get_from_path(&inferrable)
             .map_err(|e| {
                 log::error!("Error inferring file type: {path:?} | {e:?}");
                 e
@@ -273,8 +453,18 @@ impl Media {
                 {
                     tree_magic_mini::from_filepath(&inferrable).map(Mime::Database)
                 }
-            })
-            .or_else(|| mime_guess::from_path(&inferrable).first().map(Mime::Extension));
+            });
+
+        // Only trust the extension if the content didn't tell us anything,
+        // since extensions like `.ts` are also used for non-video files.
+        #[cfg(feature = "video")]
+        if sniffed.is_none() && extension.as_deref().is_some_and(is_video_extension) {
+            return Some(Self::Video {
+                path: path.normalized(),
+            });
+        }
+
+        let mime = sniffed.or_else(|| mime_guess::from_path(&inferrable).first().map(Mime::Extension));
 
         log::debug!("Inferred file type '{mime:?}': {path:?}");
 
@@ -284,24 +474,15 @@ impl Media {
             #[cfg(feature = "video")]
             if mime.starts_with("video/") {
                 // The exact formats supported will depend on the user's GStreamer plugins,
-                // so just go ahead and try it. Some that work by default on Windows:
-                // * video/mp4
-                // * video/mpeg
-                // * video/quicktime
-                // * video/webm
-                // * video/x-m4v
-                // * video/x-matroska
-                // * video/x-msvideo
+                // so just go ahead and try it. See `VIDEO_EXTENSIONS` for some common ones.
                 return Some(Self::Video {
                     path: path.normalized(),
                 });
             }
 
-            let extension = path.file_extension().map(|x| x.to_lowercase());
-
             match mime {
                 #[cfg(feature = "audio")]
-                "audio/mpeg" | "audio/m4a" | "audio/x-flac" | "audio/x-wav" => Some(Self::Audio {
+                "audio/mpeg" | "audio/m4a" | "audio/ogg" | "audio/x-flac" | "audio/x-wav" => Some(Self::Audio {
                     path: path.normalized(),
                 }),
                 "image/bmp" | "image/jpeg" | "image/tiff" | "image/vnd.microsoft.icon" | "image/webp" => {
@@ -335,27 +516,86 @@ impl Media {
     }
 }
 
+/// File extensions that are treated as video when the file's content doesn't identify its type.
+/// The exact formats supported will depend on the user's GStreamer plugins.
+///
+/// Ambiguous extensions like `.ogg` are left out so that they can be identified as audio.
+#[cfg(feature = "video")]
+pub const VIDEO_EXTENSIONS: &[&str] = &[
+    "3g2", "3gp", "asf", "avi", "divx", "f4v", "flv", "m2ts", "m2v", "m4v", "mkv", "mov", "mp4", "mpeg", "mpg", "mts",
+    "mxf", "ogv", "qt", "ts", "vob", "webm", "wmv",
+];
+
+#[cfg(feature = "video")]
+fn is_video_extension(extension: &str) -> bool {
+    VIDEO_EXTENSIONS.contains(&extension)
+}
+
 pub type SourceMap = HashMap<Source, HashSet<Media>>;
 
 #[derive(Debug, Default, Clone)]
 pub struct Collection {
     media: SourceMap,
     errored: HashSet<Media>,
+    /// Files to skip for the rest of the session, even if they're still in the sources.
+    blacklisted: HashSet<StrictPath>,
+    /// Media shown by any player, as of the last call to `set_on_screen`.
+    on_screen: HashSet<Media>,
+    /// Files skipped for exceeding the size limit.
+    too_large: HashMap<Source, HashSet<StrictPath>>,
+    /// When collapsing duplicates, media known to have unique content, grouped by size.
+    unique: HashMap<u64, HashSet<Media>>,
+    /// When collapsing duplicates, media with the same content as other media,
+    /// mapped to the media that's used in its place.
+    duplicates: HashMap<Media, Media>,
 }
 
 impl Collection {
     pub fn clear(&mut self) {
         self.media.clear();
+        self.too_large.clear();
+        self.unique.clear();
+        self.duplicates.clear();
     }
 
     pub fn prune(&mut self, sources: &[Source]) {
         self.media.retain(|k, _| sources.contains(k));
+        self.too_large.retain(|k, _| sources.contains(k));
     }
 
     pub fn mark_error(&mut self, media: &Media) {
         self.errored.insert(media.clone());
     }
 
+    pub fn clear_error(&mut self, media: &Media) {
+        self.errored.remove(media);
+    }
+
+    /// Forget about media that no longer exists, like after deleting it.
+    pub fn remove(&mut self, media: &Media) {
+        for known in self.media.values_mut() {
+            known.remove(media);
+        }
+        for known in self.unique.values_mut() {
+            known.remove(media);
+        }
+        self.duplicates
+            .retain(|duplicate, original| duplicate != media && original != media);
+        self.errored.remove(media);
+    }
+
+    pub fn blacklist(&mut self, media: &Media) {
+        self.blacklisted.insert(media.path().clone());
+    }
+
+    pub fn clear_blacklist(&mut self) {
+        self.blacklisted.clear();
+    }
+
+    pub fn set_on_screen(&mut self, media: HashSet<Media>) {
+        self.on_screen = media;
+    }
+
     pub fn is_error(&self, media: &Media) -> bool {
         self.errored.contains(media)
     }
@@ -371,36 +611,55 @@ impl Collection {
             .all(|known| !known.contains(media))
     }
 
-    pub fn find(scan: Scan) -> Vec<Scan> {
+    /// Process one step of a scan, passing the results to `emit`.
+    /// Large folders and globs are emitted in batches as they're discovered,
+    /// so that media can start appearing before the whole scan is done.
+    pub fn find(scan: Scan, mut emit: impl FnMut(Vec<Scan>)) {
         match scan {
             Scan::Source {
                 source,
                 original_source,
                 playlist,
                 context,
+                follow_symlinks,
+                ignore_marker,
+                max_file_size,
             } => {
                 let basis = playlist
                     .as_ref()
                     .and_then(|x| x.parent_if_file().ok())
                     .unwrap_or_else(StrictPath::cwd);
 
-                let filled = source.fill_placeholders(&basis);
+                // Paths found from a source are already resolved,
+                // so we only fill in the user's original source.
+                let filled = match &original_source {
+                    None => source.fill_placeholders(&basis),
+                    Some(_) => source.clone(),
+                };
                 let original_source = original_source.unwrap_or(source);
 
                 match filled {
                     Source::Path { path } => {
                         if path.is_file() {
                             log::debug!("Source is file: {path:?}");
-                            vec![Scan::Identify {
+                            emit(vec![Scan::Identify {
                                 path,
                                 source: original_source,
                                 context,
-                            }]
+                                max_file_size,
+                            }]);
                         } else if path.is_dir() {
+                            if let Some(marker) = ignore_marker.as_deref() {
+                                if is_ignored_dir(&path, marker) {
+                                    log::debug!("Source is ignored directory: {path:?}");
+                                    return;
+                                }
+                            }
+
                             log::debug!("Source is directory: {path:?}");
-                            path.joined("*")
-                                .glob()
-                                .into_iter()
+                            let files = path
+                                .joined("*")
+                                .glob(follow_symlinks)
                                 .filter(|x| x.is_file())
                                 .map(|file| {
                                     log::debug!("Found file from directory: {file:?} <- {path:?}");
@@ -408,51 +667,144 @@ impl Collection {
                                         path: file,
                                         source: original_source.clone(),
                                         context,
+                                        max_file_size,
                                     }
-                                })
-                                .collect()
+                                });
+                            for batch in &files.chunks(SCAN_BATCH_SIZE) {
+                                emit(batch.collect());
+                            }
                         } else if path.is_symlink() {
                             log::debug!("Source is symlink: {path:?}");
                             match path.interpreted() {
                                 Ok(target) => {
                                     log::debug!("Found target from symlink: {target:?} <- {path:?}");
-                                    vec![Scan::Source {
+                                    emit(vec![Scan::Source {
                                         source: Source::new_path(target),
                                         original_source: Some(original_source),
                                         playlist,
                                         context,
-                                    }]
+                                        follow_symlinks,
+                                        ignore_marker,
+                                        max_file_size,
+                                    }]);
                                 }
                                 Err(error) => {
                                     log::error!("Failed to traverse symlink: {path:?} | {error:?}");
-                                    vec![]
                                 }
                             }
                         } else {
                             log::debug!("Source is unknown path: {path:?}");
-                            vec![]
                         }
                     }
-                    Source::Glob { pattern } => StrictPath::new(pattern.clone())
-                        .glob()
-                        .into_iter()
-                        .map(|file| {
+                    Source::Glob { pattern } => {
+                        // Relative globs follow the playlist so that it can be moved along with its media.
+                        let glob = StrictPath::relative(pattern.clone(), Some(basis.raw()));
+                        // Ignored folders are pruned during the walk, so we never look inside of them.
+                        let found = match ignore_marker.clone() {
+                            Some(marker) => glob.glob_pruned(follow_symlinks, move |dir| dir.join(&marker).exists()),
+                            None => glob.glob(follow_symlinks),
+                        };
+                        let files = found.map(|file| {
                             log::debug!("Found file from glob: {file:?} <- {pattern}");
                             Scan::Source {
                                 source: Source::new_path(file),
                                 original_source: Some(original_source.clone()),
                                 playlist: playlist.clone(),
                                 context,
+                                follow_symlinks,
+                                // Already checked above.
+                                ignore_marker: None,
+                                max_file_size,
                             }
-                        })
-                        .collect(),
+                        });
+                        for batch in &files.chunks(SCAN_BATCH_SIZE) {
+                            emit(batch.collect());
+                        }
+                    }
                 }
             }
-            Scan::Identify { path, source, context } => match Media::identify(&path) {
-                Some(media) => vec![Scan::Found { media, source, context }],
-                None => vec![],
-            },
-            Scan::Found { media, source, context } => vec![Scan::Found { media, source, context }],
+            Scan::Identify {
+                path,
+                source,
+                context,
+                max_file_size,
+            } => {
+                if let Some(media) = Media::identify(&path) {
+                    let size = path.size();
+                    if max_file_size.allows(&media, size) {
+                        emit(vec![Scan::Found {
+                            media,
+                            source,
+                            size,
+                            context,
+                        }]);
+                    } else {
+                        log::debug!("Skipping file over size limit: {path:?}");
+                        emit(vec![Scan::TooLarge { source, path }]);
+                    }
+                }
+            }
+            Scan::CompareContent {
+                source,
+                media,
+                size,
+                candidates,
+                hashes,
+                context,
+            } => {
+                let mut known: HashMap<_, _> = hashes
+                    .into_iter()
+                    .filter(|(path, hash)| hash.is_current(path))
+                    .collect();
+                let mut fresh = vec![];
+                let mut hash_of = |path: &StrictPath| {
+                    if let Some(hash) = known.get(path) {
+                        return Some(hash.hash);
+                    }
+                    let hash = ContentHash::read(path)?;
+                    known.insert(path.clone(), hash.clone());
+                    fresh.push((path.clone(), hash.clone()));
+                    Some(hash.hash)
+                };
+
+                // Different hashes rule out a match without reading both files again,
+                // but matching hashes are still confirmed byte by byte.
+                let ours = hash_of(media.path());
+                let original = candidates
+                    .iter()
+                    .find(|candidate| {
+                        ours.is_some()
+                            && hash_of(candidate.path()) == ours
+                            && media.path().same_content(candidate.path())
+                    })
+                    .cloned();
+
+                match original {
+                    Some(original) => {
+                        log::debug!("Found duplicate: {:?} = {:?}", media.path(), original.path());
+                        emit(vec![Scan::Duplicate {
+                            source,
+                            media,
+                            original,
+                            hashes: fresh,
+                            context,
+                        }]);
+                    }
+                    None => {
+                        emit(vec![Scan::Unique {
+                            source,
+                            media,
+                            size,
+                            compared: candidates,
+                            hashes: fresh,
+                            context,
+                        }]);
+                    }
+                }
+            }
+            scan @ (Scan::Found { .. } | Scan::Unique { .. } | Scan::Duplicate { .. } | Scan::TooLarge { .. }) => {
+                emit(vec![scan]);
+            }
         }
     }
 
@@ -460,7 +812,50 @@ impl Collection {
         self.media.entry(source).or_default().insert(media);
     }
 
-    pub fn one_new(&self, sources: &[Source], old: HashSet<&Media>) -> Option<Media> {
+    /// Insert media after confirming that its content is unique.
+    pub fn insert_unique(&mut self, source: Source, media: Media, size: u64) {
+        self.unique.entry(size).or_default().insert(media.clone());
+        self.insert(source, media);
+    }
+
+    /// Insert the original media in place of a duplicate,
+    /// and remember the duplicate so that it doesn't need to be compared again.
+    pub fn insert_duplicate(&mut self, source: Source, media: Media, original: Media) {
+        self.duplicates.insert(media, original.clone());
+        self.insert(source, original);
+    }
+
+    pub fn original_of(&self, media: &Media) -> Option<&Media> {
+        self.duplicates.get(media)
+    }
+
+    /// Known media with the same size, which might have the same content.
+    /// This is empty if the media itself is already known to be unique.
+    pub fn duplicate_candidates(&self, media: &Media, size: u64) -> Vec<Media> {
+        match self.unique.get(&size) {
+            Some(known) if !known.contains(media) => known.iter().cloned().collect(),
+            _ => vec![],
+        }
+    }
+
+    pub fn insert_too_large(&mut self, source: Source, path: StrictPath) {
+        self.too_large.entry(source).or_default().insert(path);
+    }
+
+    /// How many files were skipped for exceeding the size limit.
+    pub fn total_too_large(&self, sources: &[Source]) -> usize {
+        sources
+            .iter()
+            .filter_map(|source| self.too_large.get(source))
+            .flatten()
+            .unique()
+            .count()
+    }
+
+    /// Pick a random media that isn't in `old`.
+    /// If `unique_on_screen` is set, then prefer media that isn't shown anywhere else,
+    /// but fall back to a duplicate if there isn't enough media to go around.
+    pub fn one_new(&self, sources: &[Source], old: HashSet<&Media>, unique_on_screen: bool) -> Option<Media> {
         use rand::seq::SliceRandom;
 
         let mut media: Vec<_> = sources
@@ -471,10 +866,74 @@ impl Collection {
             .collect();
         media.shuffle(&mut rand::rng());
 
-        media
+        let mut available = media.into_iter().filter(|media| {
+            !self.errored.contains(media) && !self.blacklisted.contains(media.path()) && !old.contains(media)
+        });
+
+        if unique_on_screen {
+            let available: Vec<_> = available.collect();
+            available
+                .iter()
+                .find(|media| !self.on_screen.contains(**media))
+                .or(available.first())
+                .map(|media| (*media).clone())
+        } else {
+            available.next().cloned()
+        }
+    }
+
+    /// Pick the next media for a player that's currently showing `current`.
+    /// If the main sources can't provide any, then try the fallback source instead.
+    pub fn one_next(
+        &self,
+        sources: &[Source],
+        fallback: Option<&Source>,
+        current: Option<&Media>,
+        old: HashSet<&Media>,
+        unique_on_screen: bool,
+        in_order: bool,
+    ) -> Option<Media> {
+        let pick = |sources: &[Source], old: HashSet<&Media>| {
+            if in_order {
+                self.next_in_order(sources, current, old)
+            } else {
+                self.one_new(sources, old, unique_on_screen)
+            }
+        };
+
+        pick(sources, old.clone()).or_else(|| pick(std::slice::from_ref(fallback?), old))
+    }
+
+    /// Pick the media that comes after `current` when sorted by path,
+    /// wrapping around at the end and skipping anything in `old`.
+    pub fn next_in_order(&self, sources: &[Source], current: Option<&Media>, old: HashSet<&Media>) -> Option<Media> {
+        let mut media: Vec<_> = self
+            .all_for_sources(sources)
             .into_iter()
-            .find(|media| !self.errored.contains(media) && !old.contains(media))
-            .cloned()
+            .filter(|media| !self.errored.contains(*media) && !self.blacklisted.contains(media.path()))
+            .collect();
+        media.sort_by(|a, b| a.path().cmp(b.path()));
+
+        let start = current
+            .and_then(|current| media.iter().position(|media| *media == current))
+            .map(|index| index + 1)
+            .unwrap_or(0);
+
+        media
+            .iter()
+            .cycle()
+            .skip(start)
+            .take(media.len())
+            .find(|media| !old.contains(**media))
+            .map(|media| (*media).clone())
+    }
+
+    /// Which of the sources produced this media.
+    /// If several did, then the first one wins.
+    pub fn source_of<'a>(&self, media: &Media, sources: &'a [Source]) -> Option<&'a Source> {
+        sources
+            .iter()
+            .find(|source| self.media.get(*source).is_some_and(|known| known.contains(media)))
     }
 
     pub fn all_for_sources(&self, sources: &[Source]) -> BTreeSet<&Media> {
@@ -514,6 +973,7 @@ fn is_animated_png(path: &StrictPath) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::TempDir;
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -545,4 +1005,386 @@ mod tests {
         let playlist = StrictPath::new("/tmp");
         assert_eq!(source, source.fill_placeholders(&playlist))
     }
+
+    #[test]
+    fn can_compare_content_with_saved_hashes() {
+        let root = TempDir::new("compare-content");
+        std::fs::write(root.join("a.png"), "foo").unwrap();
+        std::fs::write(root.join("b.png"), "foo").unwrap();
+        std::fs::write(root.join("c.png"), "bar").unwrap();
+
+        let image = |name: &str| Media::Image {
+            path: StrictPath::from(root.join(name)),
+        };
+        let compare = |name: &str, hashes: Vec<(StrictPath, ContentHash)>| {
+            let mut found = vec![];
+            Collection::find(
+                Scan::CompareContent {
+                    source: Source::default(),
+                    media: image(name),
+                    size: 3,
+                    candidates: vec![image("a.png")],
+                    hashes,
+                    context: RefreshContext::Manual,
+                },
+                |new| found.extend(new),
+            );
+            found
+        };
+
+        let duplicate = compare("b.png", vec![]);
+        assert!(matches!(
+            duplicate.as_slice(),
+            [Scan::Duplicate { original, hashes, .. }] if *original == image("a.png") && hashes.len() == 2
+        ));
+
+        // An outdated hash for `a.png` that happens to match `c.png` must be ignored.
+        let mut stale = ContentHash::read(image("c.png").path()).unwrap();
+        stale.size = 999;
+        let unique = compare("c.png", vec![(image("a.png").path().clone(), stale)]);
+        assert!(matches!(
+            unique.as_slice(),
+            [Scan::Unique { compared, hashes, .. }] if *compared == vec![image("a.png")] && hashes.len() == 2
+        ));
+    }
+
+    #[test]
+    fn can_make_path_source_relative_to_playlist() {
+        let root = TempDir::new("relative-path-source");
+        std::fs::create_dir_all(root.join("playlists")).unwrap();
+        std::fs::write(root.join("playlists/playlist.madamiru"), "").unwrap();
+        let playlist = StrictPath::from(root.join("playlists/playlist.madamiru"));
+
+        assert_eq!(
+            Source::new_path(StrictPath::new(format!("{}/photos/a.png", placeholder::PLAYLIST))),
+            Source::new_path_in_playlist(StrictPath::from(root.join("playlists/photos/a.png")), Some(&playlist)),
+        );
+        assert_eq!(
+            Source::new_path(StrictPath::new(format!("{}/../a.png", placeholder::PLAYLIST))),
+            Source::new_path_in_playlist(StrictPath::from(root.join("a.png")), Some(&playlist)),
+        );
+        assert_eq!(
+            Source::new_path(StrictPath::from(root.join("a.png"))),
+            Source::new_path_in_playlist(StrictPath::from(root.join("a.png")), None),
+        );
+    }
+
+    #[test]
+    fn can_resolve_relative_glob_from_playlist_folder() {
+        let root = TempDir::new("relative-glob");
+        std::fs::create_dir_all(root.join("before/photos")).unwrap();
+        std::fs::write(root.join("before/playlist.madamiru"), "").unwrap();
+        std::fs::write(root.join("before/photos/a.png"), "").unwrap();
+
+        let find = |folder: &str| {
+            let mut found = vec![];
+            Collection::find(
+                Scan::Source {
+                    source: Source::new_glob("./photos/*.png".to_string()),
+                    original_source: None,
+                    playlist: Some(StrictPath::from(root.join(folder).join("playlist.madamiru"))),
+                    context: RefreshContext::Manual,
+                    follow_symlinks: true,
+                    ignore_marker: None,
+                    max_file_size: SizeLimits::default(),
+                },
+                |scans| {
+                    for scan in scans {
+                        if let Scan::Source {
+                            source: Source::Path { path },
+                            ..
+                        } = scan
+                        {
+                            found.push(path);
+                        }
+                    }
+                },
+            );
+            found
+        };
+
+        let before = find("before");
+        std::fs::rename(root.join("before"), root.join("after")).unwrap();
+        let after = find("after");
+
+        assert_eq!(
+            vec![StrictPath::from(root.join("before/photos/a.png")).render()],
+            before.iter().map(|x| x.render()).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![StrictPath::from(root.join("after/photos/a.png")).render()],
+            after.iter().map(|x| x.render()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn can_pick_next_media_in_order() {
+        let source = Source::new_glob("/tmp/camera_*/latest.jpg".to_string());
+        let a = Media::Image {
+            path: StrictPath::new("/tmp/camera_a/latest.jpg"),
+        };
+        let b = Media::Image {
+            path: StrictPath::new("/tmp/camera_b/latest.jpg"),
+        };
+        let c = Media::Image {
+            path: StrictPath::new("/tmp/camera_c/latest.jpg"),
+        };
+
+        let mut collection = Collection::default();
+        collection.insert(source.clone(), c.clone());
+        collection.insert(source.clone(), a.clone());
+        collection.insert(source.clone(), b.clone());
+        let sources = vec![source];
+
+        assert_eq!(
+            Some(a.clone()),
+            collection.next_in_order(&sources, None, HashSet::new())
+        );
+        assert_eq!(
+            Some(b.clone()),
+            collection.next_in_order(&sources, Some(&a), HashSet::from([&a]))
+        );
+        assert_eq!(
+            Some(a.clone()),
+            collection.next_in_order(&sources, Some(&c), HashSet::from([&c]))
+        );
+        assert_eq!(
+            Some(c.clone()),
+            collection.next_in_order(&sources, Some(&a), HashSet::from([&a, &b]))
+        );
+        assert_eq!(
+            None,
+            collection.next_in_order(&sources, Some(&a), HashSet::from([&a, &b, &c]))
+        );
+    }
+
+    #[test]
+    fn can_find_source_of_media() {
+        let photos = Source::new_path(StrictPath::new("/tmp/photos"));
+        let glob = Source::new_glob("/tmp/**/*.jpg".to_string());
+        let other = Source::new_path(StrictPath::new("/tmp/other"));
+        let a = Media::Image {
+            path: StrictPath::new("/tmp/photos/a.jpg"),
+        };
+        let b = Media::Image {
+            path: StrictPath::new("/tmp/misc/b.jpg"),
+        };
+
+        let mut collection = Collection::default();
+        collection.insert(photos.clone(), a.clone());
+        collection.insert(glob.clone(), a.clone());
+        collection.insert(glob.clone(), b.clone());
+        let sources = vec![photos.clone(), glob.clone()];
+
+        assert_eq!(Some(&photos), collection.source_of(&a, &sources));
+        assert_eq!(Some(&glob), collection.source_of(&b, &sources));
+        assert_eq!(None, collection.source_of(&b, &[photos, other]));
+    }
+
+    #[test]
+    fn can_fall_back_when_sources_are_exhausted() {
+        let source = Source::new_path(StrictPath::new("/tmp/photos"));
+        let fallback = Source::new_path(StrictPath::new("/tmp/filler"));
+        let a = Media::Image {
+            path: StrictPath::new("/tmp/photos/a.jpg"),
+        };
+        let b = Media::Image {
+            path: StrictPath::new("/tmp/filler/b.jpg"),
+        };
+
+        let mut collection = Collection::default();
+        collection.insert(source.clone(), a.clone());
+        collection.insert(fallback.clone(), b.clone());
+        let sources = vec![source];
+
+        assert_eq!(
+            Some(a.clone()),
+            collection.one_next(&sources, Some(&fallback), None, HashSet::new(), true, false)
+        );
+        assert_eq!(
+            Some(b.clone()),
+            collection.one_next(&sources, Some(&fallback), None, HashSet::from([&a]), true, false)
+        );
+        assert_eq!(
+            None,
+            collection.one_next(&sources, None, None, HashSet::from([&a]), true, false)
+        );
+        assert_eq!(
+            None,
+            collection.one_next(&sources, Some(&fallback), None, HashSet::from([&a, &b]), true, true)
+        );
+    }
+
+    #[test]
+    fn does_not_expand_env_vars_in_found_file_names() {
+        let root = TempDir::new("found-env-var");
+        // Just the PNG signature, so that it can be identified.
+        std::fs::write(root.join("$HOME.png"), b"\x89PNG\r\n\x1a\n").unwrap();
+
+        let mut scans = vec![Scan::Source {
+            source: Source::new_path(StrictPath::from(root.path())),
+            original_source: None,
+            playlist: None,
+            context: RefreshContext::Manual,
+            follow_symlinks: true,
+            ignore_marker: None,
+            max_file_size: SizeLimits::default(),
+        }];
+        let mut found = vec![];
+        while let Some(scan) = scans.pop() {
+            if let Scan::Found { media, .. } = scan {
+                found.push(media.path().raw());
+            } else {
+                Collection::find(scan, |new| scans.extend(new));
+            }
+        }
+
+        assert_eq!(vec![StrictPath::from(root.join("$HOME.png")).raw()], found);
+    }
+
+    #[test]
+    fn can_skip_ignored_folders() {
+        let root = TempDir::new("ignore-marker");
+        std::fs::create_dir_all(root.join("kept/ignored/nested")).unwrap();
+        std::fs::write(root.join("kept/a.png"), "").unwrap();
+        std::fs::write(root.join("kept/ignored/.madamiru-ignore"), "").unwrap();
+        std::fs::write(root.join("kept/ignored/b.png"), "").unwrap();
+        std::fs::write(root.join("kept/ignored/nested/c.png"), "").unwrap();
+
+        let mut globbed = vec![];
+        Collection::find(
+            Scan::Source {
+                source: Source::new_glob(StrictPath::from(root.join("**/*.png")).render()),
+                original_source: None,
+                playlist: None,
+                context: RefreshContext::Manual,
+                follow_symlinks: true,
+                ignore_marker: Some(".madamiru-ignore".to_string()),
+                max_file_size: SizeLimits::default(),
+            },
+            |scans| {
+                for scan in scans {
+                    if let Scan::Source {
+                        source: Source::Path { path },
+                        ..
+                    } = scan
+                    {
+                        globbed.push(path.leaf().unwrap());
+                    }
+                }
+            },
+        );
+
+        let mut direct = vec![];
+        Collection::find(
+            Scan::Source {
+                source: Source::new_path(StrictPath::from(root.join("kept/ignored"))),
+                original_source: None,
+                playlist: None,
+                context: RefreshContext::Manual,
+                follow_symlinks: true,
+                ignore_marker: Some(".madamiru-ignore".to_string()),
+                max_file_size: SizeLimits::default(),
+            },
+            |new| direct.extend(new),
+        );
+
+        assert_eq!(vec!["a.png".to_string()], globbed);
+        assert!(direct.is_empty());
+    }
+
+    #[test]
+    fn can_skip_files_over_size_limit() {
+        let root = TempDir::new("max-file-size");
+        // Just the PNG signature, so that it can be identified.
+        let large = root.join("large.png");
+        std::fs::write(&large, b"\x89PNG\r\n\x1a\n").unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&large)
+            .unwrap()
+            .set_len(2 * BYTES_PER_MEGABYTE)
+            .unwrap();
+
+        let find = |max_file_size| {
+            let mut found = vec![];
+            Collection::find(
+                Scan::Identify {
+                    source: Source::new_path(StrictPath::from(root.path())),
+                    path: StrictPath::from(large.clone()),
+                    context: RefreshContext::Manual,
+                    max_file_size,
+                },
+                |new| found.extend(new),
+            );
+            found
+        };
+
+        let skipped = find(SizeLimits {
+            all: Some(1),
+            ..Default::default()
+        });
+        let allowed = find(SizeLimits {
+            all: Some(1),
+            image: Some(5),
+            ..Default::default()
+        });
+
+        assert!(matches!(skipped.as_slice(), [Scan::TooLarge { .. }]));
+        assert!(matches!(allowed.as_slice(), [Scan::Found { .. }]));
+    }
+
+    #[test]
+    #[cfg(feature = "video")]
+    fn can_identify_video_by_extension() {
+        for extension in VIDEO_EXTENSIONS {
+            let path = StrictPath::new(format!("/tmp/madamiru-test.{extension}"));
+            assert_eq!(
+                Some(Media::Video {
+                    path: path.normalized()
+                }),
+                Media::identify(&path),
+                "{extension}",
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "video")]
+    fn can_identify_video_by_extension_case_insensitively() {
+        let path = StrictPath::new("/tmp/madamiru-test.MKV");
+        assert_eq!(
+            Some(Media::Video {
+                path: path.normalized()
+            }),
+            Media::identify(&path),
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "audio")]
+    fn can_identify_ambiguous_extension_as_audio() {
+        let path = StrictPath::new("/tmp/madamiru-test.ogg");
+        assert_eq!(
+            Some(Media::Audio {
+                path: path.normalized()
+            }),
+            Media::identify(&path),
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "video", not(target_os = "windows")))]
+    fn does_not_identify_video_by_extension_when_content_is_known() {
+        let root = TempDir::new("sniff-before-extension");
+        let file = root.join("script.ts");
+        std::fs::write(&file, "export const x = 1;\n").unwrap();
+
+        assert_ne!(
+            Some(Media::Video {
+                path: StrictPath::from(file.as_path()).normalized()
+            }),
+            Media::identify(&StrictPath::from(file.as_path())),
+        );
+    }
 }
@@ -1,12 +1,15 @@
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[cfg_attr(not(feature = "update-check"), allow(unused))]
 pub struct Release {
     pub version: semver::Version,
     pub url: String,
 }
 
 impl Release {
+    #[cfg(feature = "update-check")]
     const URL: &'static str = "https://api.github.com/repos/mtkennerly/madamiru/releases/latest";
 
+    #[cfg(feature = "update-check")]
     pub async fn fetch() -> Result<Self, crate::prelude::AnyError> {
         #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
         pub struct Response {
@@ -34,6 +37,7 @@ impl Release {
         }
     }
 
+    #[cfg(feature = "update-check")]
     pub fn fetch_sync() -> Result<Self, crate::prelude::AnyError> {
         #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
         pub struct Response {
@@ -69,3 +73,137 @@ impl Release {
         }
     }
 }
+
+/// Information to include when reporting an issue.
+pub struct Diagnostics {
+    summary: String,
+    log: Option<String>,
+}
+
+impl Diagnostics {
+    const ISSUE_URL: &'static str = "https://github.com/mtkennerly/madamiru/issues/new";
+    /// Only read the end of the log file, since it can be fairly large.
+    const LOG_TAIL_BYTES: u64 = 8 * 1024;
+    /// Longer URLs may be rejected by the browser or GitHub.
+    const URL_MAX_LENGTH: usize = 8000;
+
+    pub fn gather(config: &crate::resource::config::Config) -> Self {
+        let config = Self::redact_config(config);
+
+        let features: Vec<_> = [
+            ("audio", cfg!(feature = "audio")),
+            ("battery", cfg!(feature = "battery")),
+            ("console-allocation-policy", cfg!(feature = "console-allocation-policy")),
+            ("http-api", cfg!(feature = "http-api")),
+            ("keep-awake", cfg!(feature = "keep-awake")),
+            ("media-controls", cfg!(feature = "media-controls")),
+            ("trash", cfg!(feature = "trash")),
+            ("update-check", cfg!(feature = "update-check")),
+            ("video", cfg!(feature = "video")),
+        ]
+        .into_iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(feature, _)| feature)
+        .collect();
+
+        let summary = format!(
+            "* Version: {}\n* OS: {} ({})\n* Features: {}\n* Config:\n```yaml\n{}\n```\n",
+            *crate::prelude::VERSION,
+            std::env::consts::OS,
+            std::env::consts::ARCH,
+            if features.is_empty() {
+                "none".to_string()
+            } else {
+                features.join(", ")
+            },
+            serde_yaml::to_string(&config).unwrap_or_default().trim(),
+        );
+
+        Self {
+            summary: Self::redact(summary),
+            log: Self::read_log_tail().map(Self::redact),
+        }
+    }
+
+    /// Paths can include personal details, like folder names outside of the home folder,
+    /// so every path in the config is hidden.
+    fn redact_config(config: &crate::resource::config::Config) -> crate::resource::config::Config {
+        use crate::{prelude::StrictPath, resource::config::AppBackground};
+
+        let redacted = || StrictPath::new("<redacted>");
+        let mut config = config.clone();
+
+        if let AppBackground::Image(path) = &mut config.view.app_background {
+            *path = redacted();
+        }
+        for path in &mut config.view.pinned_destinations {
+            *path = redacted();
+        }
+        for path in &mut config.view.recent_destinations {
+            *path = redacted();
+        }
+        if let Some(path) = &mut config.status_file.path {
+            *path = redacted();
+        }
+        for entry in &mut config.rotation.playlists {
+            entry.path = redacted();
+        }
+        if config.api.token.is_some() {
+            config.api.token = Some("<redacted>".to_string());
+        }
+
+        config
+    }
+
+    fn read_log_tail() -> Option<String> {
+        use std::io::{Read, Seek, SeekFrom};
+
+        crate::prelude::flush_logger();
+
+        let path = crate::prelude::app_dir().joined("madamiru_rCURRENT.log");
+        let mut file = std::fs::File::open(path.as_std_path_buf().ok()?).ok()?;
+        let start = file.metadata().ok()?.len().saturating_sub(Self::LOG_TAIL_BYTES);
+        file.seek(SeekFrom::Start(start)).ok()?;
+
+        let mut bytes = vec![];
+        file.read_to_end(&mut bytes).ok()?;
+        let text = String::from_utf8_lossy(&bytes);
+
+        // Skip the first line if we started reading in the middle of it.
+        let text = if start > 0 {
+            text.split_once('\n').map(|(_, rest)| rest).unwrap_or_default()
+        } else {
+            text.as_ref()
+        };
+
+        Some(text.trim().to_string())
+    }
+
+    /// Hide the user's home folder, which usually includes their name.
+    fn redact(text: String) -> String {
+        match dirs::home_dir().and_then(|home| home.to_str().map(|home| home.to_string())) {
+            Some(home) if !home.is_empty() => text.replace(&home, "~"),
+            _ => text,
+        }
+    }
+
+    pub fn text(&self) -> String {
+        match &self.log {
+            Some(log) => format!("{}* Log:\n```\n{log}\n```\n", self.summary),
+            None => self.summary.clone(),
+        }
+    }
+
+    /// URL for a new issue, pre-filled with as much of the diagnostics as will fit.
+    pub fn issue_url(&self) -> String {
+        for body in [self.text(), self.summary.clone()] {
+            if let Ok(url) = url::Url::parse_with_params(Self::ISSUE_URL, &[("body", body)]) {
+                if url.as_str().len() <= Self::URL_MAX_LENGTH {
+                    return url.to_string();
+                }
+            }
+        }
+
+        Self::ISSUE_URL.to_string()
+    }
+}
@@ -44,6 +44,66 @@ pub fn render_pathbuf(value: &std::path::Path) -> String {
     value.display().to_string()
 }
 
+/// Expand environment variables like `%VAR%`, `$VAR`, and `${VAR}`.
+/// Unknown variables are left as-is.
+fn expand_vars(raw: &str, lookup: impl Fn(&str) -> Option<String>) -> String {
+    fn is_name_char(c: char) -> bool {
+        c.is_ascii_alphanumeric() || c == '_'
+    }
+
+    let mut out = String::with_capacity(raw.len());
+    let mut rest = raw;
+
+    while let Some(index) = rest.find(['%', '$']) {
+        out.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        let (name, len) = if let Some(inner) = rest.strip_prefix('%') {
+            // Windows variable names may contain parentheses, like `%ProgramFiles(x86)%`.
+            match inner.find('%') {
+                Some(end) if end > 0 && inner[..end].chars().all(|c| is_name_char(c) || c == '(' || c == ')') => {
+                    (&inner[..end], end + 2)
+                }
+                _ => ("", 1),
+            }
+        } else if let Some(inner) = rest.strip_prefix("${") {
+            match inner.find('}') {
+                Some(end) if end > 0 && inner[..end].chars().all(is_name_char) => (&inner[..end], end + 3),
+                _ => ("", 1),
+            }
+        } else {
+            let inner = &rest[1..];
+            let end = inner.find(|c| !is_name_char(c)).unwrap_or(inner.len());
+            if inner.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+                (&inner[..end], end + 1)
+            } else {
+                ("", 1)
+            }
+        };
+
+        match (name.is_empty(), lookup(name)) {
+            (false, Some(value)) => out.push_str(&value),
+            _ => {
+                if !name.is_empty() {
+                    log::trace!("Unknown environment variable in path: {name} | {raw}");
+                }
+                out.push_str(&rest[..len]);
+            }
+        }
+        rest = &rest[len..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Expand environment variables in text that the user entered, like a source.
+/// This shouldn't be used for paths found on disk,
+/// since file names may legitimately contain `$` or `%`.
+pub fn expand_env_vars(raw: &str) -> String {
+    expand_vars(raw, |name| std::env::var(name).ok())
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum StrictPathError {
     Empty,
@@ -449,6 +509,35 @@ impl StrictPath {
         Ok(())
     }
 
+    /// A path for a new file in this folder,
+    /// adding a number to the name if it already exists (e.g., `foo (2).png`).
+    pub fn available_child(&self, stem: &str, extension: Option<&str>) -> Self {
+        let name = |suffix: String| match extension {
+            Some(extension) => format!("{stem}{suffix}.{extension}"),
+            None => format!("{stem}{suffix}"),
+        };
+
+        let mut candidate = self.joined(&name(String::new()));
+        let mut i = 2;
+        while candidate.exists() {
+            candidate = self.joined(&name(format!(" ({i})")));
+            i += 1;
+        }
+        candidate
+    }
+
+    /// Move to the system's trash/recycle bin instead of deleting permanently.
+    #[cfg(feature = "trash")]
+    pub fn move_to_trash(&self) -> Result<(), Box<dyn std::error::Error>> {
+        trash::delete(self.as_std_path_buf()?)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "trash"))]
+    pub fn move_to_trash(&self) -> Result<(), Box<dyn std::error::Error>> {
+        Err("built without trash support".into())
+    }
+
     pub fn joined(&self, other: &str) -> Self {
         Self {
             raw: format!("{}/{}", &self.raw, other).replace('\\', "/"),
@@ -526,6 +615,11 @@ impl StrictPath {
         }
     }
 
+    /// See `expand_env_vars`.
+    pub fn with_env_vars_expanded(&self) -> Self {
+        Self::relative(expand_env_vars(&self.raw), self.basis.clone())
+    }
+
     pub fn create(&self) -> std::io::Result<std::fs::File> {
         std::fs::File::create(self.as_std_path_buf()?)
     }
@@ -550,6 +644,25 @@ impl StrictPath {
         std::fs::copy(self.as_std_path_buf()?, target.as_std_path_buf()?)
     }
 
+    /// Like `move_to`, but also works when the target is on another drive,
+    /// by copying the file and then removing the original.
+    pub fn move_across_devices(&self, target: &StrictPath) -> std::io::Result<()> {
+        match self.move_to(target) {
+            Err(e) if e.kind() == std::io::ErrorKind::CrossesDevices => {
+                self.copy_to(target)?;
+                if let Err(e) = std::fs::remove_file(self.as_std_path_buf()?) {
+                    // Don't leave the file in both places.
+                    if let Ok(target) = target.as_std_path_buf() {
+                        let _ = std::fs::remove_file(target);
+                    }
+                    return Err(e);
+                }
+                Ok(())
+            }
+            result => result,
+        }
+    }
+
     pub fn create_dirs(&self) -> std::io::Result<()> {
         std::fs::create_dir_all(self.as_std_path_buf()?)?;
         Ok(())
@@ -642,6 +755,39 @@ impl StrictPath {
         us.parts.iter().zip(them.parts.iter()).all(|(us, them)| us == them)
     }
 
+    /// Express this path relative to `base`, using `..` where needed.
+    /// The result always uses forward slashes.
+    /// Returns `None` if the paths are on different drives/roots.
+    pub fn relative_to(&self, base: &Self) -> Option<Self> {
+        if self.raw.trim().is_empty() || base.raw.trim().is_empty() {
+            return None;
+        }
+
+        let us = self.analyze();
+        let base = base.analyze();
+
+        if us.drive != base.drive {
+            return None;
+        }
+
+        let common = us
+            .parts
+            .iter()
+            .zip(base.parts.iter())
+            .take_while(|(us, base)| us == base)
+            .count();
+
+        let parts: Vec<_> = std::iter::repeat_n("..", base.parts.len() - common)
+            .chain(us.parts[common..].iter().map(|x| x.as_str()))
+            .collect();
+
+        if parts.is_empty() {
+            Some(Self::new("."))
+        } else {
+            Some(Self::new(parts.join("/")))
+        }
+    }
+
     pub fn nearest_prefix(&self, others: Vec<StrictPath>) -> Option<StrictPath> {
         let us = self.analyze();
         let us_count = us.parts.len();
@@ -663,37 +809,100 @@ impl StrictPath {
         nearest
     }
 
-    pub fn glob(&self) -> Vec<StrictPath> {
+    /// Find paths matching this glob pattern, producing them as they're found.
+    /// Symlinked directories are only traversed if `follow_links` is set,
+    /// since they can otherwise cause loops or unexpected matches.
+    pub fn glob(&self, follow_links: bool) -> Box<dyn Iterator<Item = StrictPath>> {
         let case_insensitive = cfg!(target_os = "windows") || cfg!(target_os = "macos");
 
-        self.glob_case_sensitive(!case_insensitive)
+        self.glob_case_sensitive(!case_insensitive, follow_links)
     }
 
-    pub fn glob_case_sensitive(&self, case_sensitive: bool) -> Vec<StrictPath> {
+    pub fn glob_case_sensitive(
+        &self,
+        case_sensitive: bool,
+        follow_links: bool,
+    ) -> Box<dyn Iterator<Item = StrictPath>> {
         let options = globetter::MatchOptions {
             case_sensitive,
             require_literal_separator: true,
             require_literal_leading_dot: false,
-            follow_links: true,
+            follow_links,
         };
         let rendered = self.render();
         match globetter::glob_with(&rendered, options) {
-            Ok(xs) => xs
-                .filter_map(|r| {
+            Ok(xs) => Box::new(
+                xs.filter_map(move |r| {
                     if let Err(e) = &r {
                         log::trace!("Glob error 2: {rendered} | {e}");
                     }
                     r.ok()
                 })
-                .map(StrictPath::from)
-                .collect(),
+                .map(StrictPath::from),
+            ),
             Err(e) => {
                 log::trace!("Glob error 1: {rendered} | {e}");
-                vec![]
+                Box::new(std::iter::empty())
             }
         }
     }
 
+    /// Like `glob`, but without looking inside of any folder where `prune` returns true.
+    /// The walk starts from the part of the pattern before its first wildcard.
+    pub fn glob_pruned(
+        &self,
+        follow_links: bool,
+        prune: impl Fn(&std::path::Path) -> bool + 'static,
+    ) -> Box<dyn Iterator<Item = StrictPath>> {
+        let case_insensitive = cfg!(target_os = "windows") || cfg!(target_os = "macos");
+        let rendered = self.render().replace('\\', "/");
+
+        let matcher = match globset::GlobBuilder::new(&rendered)
+            .case_insensitive(case_insensitive)
+            .literal_separator(true)
+            .build()
+        {
+            Ok(glob) => glob.compile_matcher(),
+            Err(e) => {
+                log::trace!("Glob error 1: {rendered} | {e}");
+                return Box::new(std::iter::empty());
+            }
+        };
+
+        let parts: Vec<_> = rendered.split('/').collect();
+        let literal = parts
+            .iter()
+            .take_while(|part| !part.contains(['*', '?', '[', '{']))
+            .count();
+        let mut root = parts[..literal].join("/");
+        if root.is_empty() && literal > 0 {
+            root = "/".to_string();
+        } else if root.is_empty() {
+            root = ".".to_string();
+        } else if root.ends_with(':') {
+            root.push('/');
+        }
+
+        let mut walker = walkdir::WalkDir::new(root).follow_links(follow_links);
+        if !parts[literal..].contains(&"**") {
+            walker = walker.max_depth(parts.len() - literal);
+        }
+
+        Box::new(
+            walker
+                .into_iter()
+                .filter_entry(move |entry| !(entry.file_type().is_dir() && prune(entry.path())))
+                .filter_map(move |entry| {
+                    if let Err(e) = &entry {
+                        log::trace!("Glob error 2: {e}");
+                    }
+                    entry.ok()
+                })
+                .filter(move |entry| matcher.is_match(entry.path()))
+                .map(|entry| StrictPath::from(entry.into_path())),
+        )
+    }
+
     pub fn same_content(&self, other: &StrictPath) -> bool {
         self.try_same_content(other).unwrap_or(false)
     }
@@ -701,25 +910,28 @@ impl StrictPath {
     pub fn try_same_content(&self, other: &StrictPath) -> Result<bool, Box<dyn std::error::Error>> {
         use std::io::Read;
 
-        let f1 = self.open()?;
-        let mut f1r = std::io::BufReader::new(f1);
-        let f2 = other.open()?;
-        let mut f2r = std::io::BufReader::new(f2);
+        // Files of different sizes can't match, and this is much cheaper than reading them.
+        if self.metadata()?.len() != other.metadata()?.len() {
+            return Ok(false);
+        }
+
+        let mut f1r = self.open_buffered()?;
+        let mut f2r = other.open_buffered()?;
 
-        let mut f1b = [0; 1024];
-        let mut f2b = [0; 1024];
+        let mut f1b = [0; 8192];
+        let mut f2b = [0; 8192];
         loop {
-            let f1n = f1r.read(&mut f1b[..])?;
-            let f2n = f2r.read(&mut f2b[..])?;
+            let f1n = f1r.read(&mut f1b)?;
+            if f1n == 0 {
+                return Ok(true);
+            }
 
-            if f1n != f2n || f1b.iter().zip(f2b.iter()).any(|(a, b)| a != b) {
+            // Reads may come back in different sizes, so we ask for exactly as much as the first file gave.
+            f2r.read_exact(&mut f2b[..f1n])?;
+            if f1b[..f1n] != f2b[..f1n] {
                 return Ok(false);
             }
-            if f1n == 0 || f2n == 0 {
-                break;
-            }
         }
-        Ok(true)
     }
 
     pub fn read(&self) -> Option<String> {
@@ -837,7 +1049,7 @@ impl schemars::JsonSchema for StrictPath {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::testing::repo;
+    use crate::testing::{repo, TempDir};
 
     fn home() -> String {
         CommonPath::Home.get().unwrap().to_string()
@@ -867,6 +1079,83 @@ mod tests {
             assert!(!StrictPath::new(format!("{}/fake", repo())).exists());
         }
 
+        #[test]
+        #[cfg(unix)]
+        fn can_glob_with_or_without_following_symlinks() {
+            let root = TempDir::new("glob-symlinks");
+            std::fs::create_dir_all(root.join("real")).unwrap();
+            std::fs::write(root.join("real/file.txt"), "").unwrap();
+            std::os::unix::fs::symlink(root.join("real"), root.join("link")).unwrap();
+
+            let pattern = StrictPath::from(root.join("**/*.txt"));
+            let followed: Vec<_> = pattern.glob(true).collect();
+            let unfollowed: Vec<_> = pattern.glob(false).collect();
+
+            assert_eq!(2, followed.len());
+            assert_eq!(
+                vec![StrictPath::from(root.join("real/file.txt")).rendered()],
+                unfollowed.iter().map(|x| x.rendered()).collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn can_glob_while_pruning_folders() {
+            let root = TempDir::new("glob-pruned");
+            std::fs::create_dir_all(root.join("kept/skipped/nested")).unwrap();
+            std::fs::write(root.join("kept/a.txt"), "").unwrap();
+            std::fs::write(root.join("kept/skipped/b.txt"), "").unwrap();
+            std::fs::write(root.join("kept/skipped/nested/c.txt"), "").unwrap();
+
+            let found: Vec<_> = StrictPath::from(root.join("**/*.txt"))
+                .glob_pruned(true, |dir| dir.ends_with("skipped"))
+                .map(|x| x.rendered())
+                .collect();
+            let shallow: Vec<_> = StrictPath::from(root.join("*/*.txt"))
+                .glob_pruned(true, |_| false)
+                .map(|x| x.rendered())
+                .collect();
+
+            assert_eq!(vec![StrictPath::from(root.join("kept/a.txt")).rendered()], found);
+            assert_eq!(vec![StrictPath::from(root.join("kept/a.txt")).rendered()], shallow);
+        }
+
+        #[test]
+        fn can_check_if_files_have_same_content() {
+            let root = TempDir::new("same-content");
+            std::fs::write(root.join("a.txt"), "foo").unwrap();
+            std::fs::write(root.join("b.txt"), "foo").unwrap();
+            std::fs::write(root.join("c.txt"), "bar").unwrap();
+            std::fs::write(root.join("d.txt"), "foobar").unwrap();
+
+            let file = |name: &str| StrictPath::from(root.join(name));
+            let same = file("a.txt").same_content(&file("b.txt"));
+            let different_content = file("a.txt").same_content(&file("c.txt"));
+            let different_size = file("a.txt").same_content(&file("d.txt"));
+            let missing = file("a.txt").same_content(&file("fake.txt"));
+
+            assert!(same);
+            assert!(!different_content);
+            assert!(!different_size);
+            assert!(!missing);
+        }
+
+        #[test]
+        fn can_find_available_child() {
+            let folder = StrictPath::new(repo());
+            assert_eq!(
+                StrictPath::new(format!("{}/README (2).md", repo())),
+                folder.available_child("README", Some("md"))
+            );
+            assert_eq!(
+                StrictPath::new(format!("{}/fake.md", repo())),
+                folder.available_child("fake", Some("md"))
+            );
+            assert_eq!(
+                StrictPath::new(format!("{}/fake", repo())),
+                folder.available_child("fake", None)
+            );
+        }
+
         #[test]
         fn is_prefix_of() {
             assert!(StrictPath::new("/").is_prefix_of(&StrictPath::new("/foo")));
@@ -912,6 +1201,83 @@ mod tests {
             );
         }
 
+        #[test]
+        fn relative_to_with_unix_roots() {
+            let base = StrictPath::new("/foo/bar");
+
+            assert_eq!(
+                Some(StrictPath::new("baz")),
+                StrictPath::new("/foo/bar/baz").relative_to(&base)
+            );
+            assert_eq!(
+                Some(StrictPath::new("baz/qux")),
+                StrictPath::new("/foo/bar/baz/qux").relative_to(&base)
+            );
+            assert_eq!(
+                Some(StrictPath::new(".")),
+                StrictPath::new("/foo/bar").relative_to(&base)
+            );
+            assert_eq!(Some(StrictPath::new("..")), StrictPath::new("/foo").relative_to(&base));
+            assert_eq!(
+                Some(StrictPath::new("../baz")),
+                StrictPath::new("/foo/baz").relative_to(&base)
+            );
+            assert_eq!(
+                Some(StrictPath::new("../../qux/baz")),
+                StrictPath::new("/qux/baz").relative_to(&base)
+            );
+            assert_eq!(Some(StrictPath::new("../..")), StrictPath::new("/").relative_to(&base));
+            assert_eq!(
+                Some(StrictPath::new("foo/bar")),
+                StrictPath::new("/foo/bar").relative_to(&StrictPath::new("/"))
+            );
+        }
+
+        #[test]
+        fn relative_to_with_windows_drives() {
+            assert_eq!(
+                Some(StrictPath::new("baz")),
+                StrictPath::new(r"C:\foo\bar\baz").relative_to(&StrictPath::new("C:/foo/bar"))
+            );
+            assert_eq!(
+                Some(StrictPath::new("../baz")),
+                StrictPath::new(r"c:\foo\baz").relative_to(&StrictPath::new(r"C:\foo\bar"))
+            );
+            assert_eq!(
+                Some(StrictPath::new("foo")),
+                StrictPath::new(r"\\?\C:\foo").relative_to(&StrictPath::new("C:/"))
+            );
+            assert_eq!(
+                None,
+                StrictPath::new(r"D:\foo").relative_to(&StrictPath::new(r"C:\foo"))
+            );
+        }
+
+        #[test]
+        fn relative_to_with_unc_paths() {
+            assert_eq!(
+                Some(StrictPath::new("../baz")),
+                StrictPath::new(r"\\server\share\foo\baz").relative_to(&StrictPath::new(r"\\server\share\foo\bar"))
+            );
+            assert_eq!(
+                None,
+                StrictPath::new(r"\\server\other\foo").relative_to(&StrictPath::new(r"\\server\share\foo"))
+            );
+            assert_eq!(
+                None,
+                StrictPath::new(r"\\server\share\foo").relative_to(&StrictPath::new(r"C:\foo"))
+            );
+        }
+
+        #[test]
+        fn relative_to_with_mismatched_roots() {
+            assert_eq!(None, StrictPath::new("/foo").relative_to(&StrictPath::new(r"C:\foo")));
+            assert_eq!(None, StrictPath::new(r"C:\foo").relative_to(&StrictPath::new("/foo")));
+            assert_eq!(None, StrictPath::new("foo").relative_to(&StrictPath::new("/foo")));
+            assert_eq!(None, StrictPath::new("").relative_to(&StrictPath::new("/foo")));
+            assert_eq!(None, StrictPath::new("/foo").relative_to(&StrictPath::new("")));
+        }
+
         #[test]
         fn can_replace() {
             // Identical
@@ -964,6 +1330,56 @@ mod tests {
         }
     }
 
+    mod env_vars {
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        fn lookup(name: &str) -> Option<String> {
+            match name {
+                "HOME" => Some("/home/foo".to_string()),
+                "USERPROFILE" => Some(r"C:\Users\foo".to_string()),
+                "ProgramFiles(x86)" => Some(r"C:\Program Files (x86)".to_string()),
+                _ => None,
+            }
+        }
+
+        #[test]
+        fn unix_style() {
+            assert_eq!("/home/foo/Pictures", expand_vars("$HOME/Pictures", lookup));
+            assert_eq!("/home/foo/Pictures", expand_vars("${HOME}/Pictures", lookup));
+            assert_eq!("/home/foo_bar", expand_vars("${HOME}_bar", lookup));
+            assert_eq!("/mnt/home/foo", expand_vars("/mnt$HOME", lookup));
+        }
+
+        #[test]
+        fn windows_style() {
+            assert_eq!(r"C:\Users\foo\Pictures", expand_vars(r"%USERPROFILE%\Pictures", lookup));
+            assert_eq!(
+                r"C:\Program Files (x86)\app",
+                expand_vars(r"%ProgramFiles(x86)%\app", lookup)
+            );
+        }
+
+        #[test]
+        fn missing_vars_are_left_as_is() {
+            assert_eq!("$FAKE/Pictures", expand_vars("$FAKE/Pictures", lookup));
+            assert_eq!("${FAKE}/Pictures", expand_vars("${FAKE}/Pictures", lookup));
+            assert_eq!("%FAKE%/Pictures", expand_vars("%FAKE%/Pictures", lookup));
+        }
+
+        #[test]
+        fn non_vars_are_left_as_is() {
+            assert_eq!("/tmp/100%", expand_vars("/tmp/100%", lookup));
+            assert_eq!("/tmp/50% off 50%", expand_vars("/tmp/50% off 50%", lookup));
+            assert_eq!("/tmp/$5", expand_vars("/tmp/$5", lookup));
+            assert_eq!("/tmp/$", expand_vars("/tmp/$", lookup));
+            assert_eq!("/tmp/${}", expand_vars("/tmp/${}", lookup));
+            assert_eq!("/tmp/${HOME", expand_vars("/tmp/${HOME", lookup));
+            assert_eq!("/tmp/%%", expand_vars("/tmp/%%", lookup));
+        }
+    }
+
     mod strict_path_display_and_access {
         use super::*;
 
@@ -1203,6 +1619,15 @@ mod tests {
             assert_eq!(Ok(home()), path.access());
         }
 
+        #[test]
+        fn env_var_is_not_expanded() {
+            let path = StrictPath::new("$HOME/foo".to_owned());
+            assert_eq!(
+                Analysis::new(None, vec!["$HOME".to_string(), "foo".to_string()]),
+                path.analyze()
+            );
+        }
+
         #[test]
         fn empty() {
             let path = StrictPath::from("");
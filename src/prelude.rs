@@ -2,11 +2,12 @@ use std::{path::PathBuf, sync::Mutex};
 
 use std::sync::LazyLock;
 
-use crate::path::CommonPath;
 pub use crate::path::StrictPath;
+use crate::{path::CommonPath, resource::config::LogLevel};
 
 pub static VERSION: LazyLock<&'static str> =
     LazyLock::new(|| option_env!("MADAMIRU_VERSION").unwrap_or(env!("CARGO_PKG_VERSION")));
+#[cfg(feature = "update-check")]
 pub static USER_AGENT: LazyLock<String> = LazyLock::new(|| format!("madamiru/{}", *VERSION));
 pub static CANONICAL_VERSION: LazyLock<(u32, u32, u32)> = LazyLock::new(|| {
     let version_parts: Vec<u32> = env!("CARGO_PKG_VERSION")
@@ -23,7 +24,6 @@ pub static CANONICAL_VERSION: LazyLock<(u32, u32, u32)> = LazyLock::new(|| {
 pub type AnyError = Box<dyn std::error::Error>;
 
 pub const APP_DIR_NAME: &str = "com.mtkennerly.madamiru";
-#[allow(unused)]
 pub const LINUX_APP_ID: &str = "com.mtkennerly.madamiru";
 const PORTABLE_FLAG_FILE_NAME: &str = "madamiru.portable";
 
@@ -32,17 +32,43 @@ pub static STEAM_DECK: LazyLock<bool> =
 
 pub static CONFIG_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
 
+/// Kept so that the log level can be changed while running.
+static LOGGER: Mutex<Option<flexi_logger::LoggerHandle>> = Mutex::new(None);
+
 #[allow(unused)]
 pub const ENV_DEBUG: &str = "MADAMIRU_DEBUG";
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Error {
-    ConfigInvalid { why: String },
+    ConfigInvalid {
+        why: String,
+    },
     NoMediaFound,
-    PlaylistInvalid { why: String },
+    NoSubfoldersFound(StrictPath),
+    PlaylistInvalid {
+        why: String,
+    },
     UnableToOpenPath(StrictPath),
     UnableToOpenUrl(String),
-    UnableToSavePlaylist { why: String },
+    UnableToCopyFile {
+        path: StrictPath,
+        why: String,
+    },
+    UnableToMoveFile {
+        path: StrictPath,
+        why: String,
+    },
+    UnableToMoveToTrash {
+        path: StrictPath,
+        why: String,
+    },
+    UnableToSavePlaylist {
+        why: String,
+    },
+    #[cfg_attr(not(feature = "video"), allow(unused))]
+    VideoPipelineInvalid {
+        why: String,
+    },
 }
 
 pub fn app_dir() -> StrictPath {
@@ -62,6 +88,30 @@ pub fn app_dir() -> StrictPath {
     StrictPath::new(format!("{}/{}", CommonPath::Config.get().unwrap(), APP_DIR_NAME))
 }
 
+pub fn retain_logger(logger: flexi_logger::LoggerHandle) {
+    *LOGGER.lock().unwrap() = Some(logger);
+}
+
+pub fn flush_logger() {
+    if let Some(logger) = LOGGER.lock().unwrap().as_ref() {
+        logger.flush();
+    }
+}
+
+pub fn set_log_level(level: LogLevel) {
+    let spec = level.spec();
+    match flexi_logger::LogSpecification::parse(spec) {
+        Ok(spec) => {
+            if let Some(logger) = LOGGER.lock().unwrap().as_ref() {
+                logger.set_new_spec(spec);
+            }
+        }
+        Err(e) => {
+            log::error!("Invalid log level: {spec} | {e:?}");
+        }
+    }
+}
+
 pub fn timestamp_mmss(seconds: u64) -> String {
     let minutes = seconds / 60;
     let seconds = seconds % 60;
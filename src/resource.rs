@@ -1,6 +1,7 @@
 pub mod cache;
 pub mod config;
 pub mod playlist;
+pub mod status;
 
 use crate::prelude::{app_dir, AnyError, StrictPath};
 
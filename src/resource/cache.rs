@@ -1,4 +1,8 @@
+use std::collections::HashMap;
+
 use crate::{
+    media,
+    path::StrictPath,
     prelude::CANONICAL_VERSION,
     resource::{config::Config, ResourceFile, SaveableResourceFile},
 };
@@ -8,6 +12,76 @@ use crate::{
 pub struct Cache {
     pub version: Option<(u32, u32, u32)>,
     pub release: Release,
+    /// Playlists that were opened or saved recently, most recent first.
+    pub recent_playlists: Vec<StrictPath>,
+    pub last_session: LastSession,
+    /// Content hashes from checking for duplicate files,
+    /// so that unchanged files don't need to be read again.
+    pub content_hashes: HashMap<StrictPath, ContentHash>,
+}
+
+/// A fingerprint of a file's content, used to rule out duplicates without reading the file again.
+/// It's only valid while the file keeps the same size and modification time.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize)]
+pub struct ContentHash {
+    pub size: u64,
+    pub modified: chrono::DateTime<chrono::Utc>,
+    pub hash: u64,
+}
+
+impl ContentHash {
+    pub fn read(path: &StrictPath) -> Option<Self> {
+        use std::io::Read;
+
+        let (size, modified) = Self::stamp(path)?;
+        let mut reader = path.open_buffered().ok()?;
+
+        // This is FNV-1a, which is stable between builds, so it's safe to save.
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let mut buffer = [0; 8192];
+        loop {
+            let read = reader.read(&mut buffer).ok()?;
+            if read == 0 {
+                break;
+            }
+            for byte in &buffer[..read] {
+                hash ^= *byte as u64;
+                hash = hash.wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+
+        Some(Self { size, modified, hash })
+    }
+
+    pub fn is_current(&self, path: &StrictPath) -> bool {
+        Self::stamp(path).is_some_and(|(size, modified)| size == self.size && modified == self.modified)
+    }
+
+    fn stamp(path: &StrictPath) -> Option<(u64, chrono::DateTime<chrono::Utc>)> {
+        let metadata = path.metadata().ok()?;
+        Some((metadata.len(), metadata.modified().ok()?.into()))
+    }
+}
+
+/// What was open when the application last exited.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct LastSession {
+    pub playlist: Option<StrictPath>,
+    /// Ad hoc sources, if there was no playlist file.
+    pub sources: Vec<media::Source>,
+}
+
+impl LastSession {
+    /// Sources to open on launch.
+    /// If the playlist file is gone, then we start fresh rather than using stale sources.
+    pub fn sources(&self) -> Vec<media::Source> {
+        match self.playlist.as_ref() {
+            Some(playlist) if playlist.is_file() => vec![media::Source::new_path(playlist.clone())],
+            Some(_) => vec![],
+            None => self.sources.clone(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -17,6 +91,9 @@ pub struct Release {
     pub latest: Option<semver::Version>,
 }
 
+/// How many recent playlists to remember.
+pub const MAX_RECENT_PLAYLISTS: usize = 5;
+
 impl ResourceFile for Cache {
     const FILE_NAME: &'static str = "cache.yaml";
 }
@@ -40,6 +117,22 @@ impl Cache {
         self
     }
 
+    pub fn add_recent_playlist(&mut self, path: StrictPath) {
+        self.recent_playlists.retain(|x| !x.equivalent(&path));
+        self.recent_playlists.insert(0, path);
+        self.recent_playlists.truncate(MAX_RECENT_PLAYLISTS);
+    }
+
+    /// Forget any recent playlists that have since been moved or deleted.
+    pub fn prune_recent_playlists(&mut self) {
+        self.recent_playlists.retain(|x| x.is_file());
+    }
+
+    /// Forget content hashes for files that have since been changed or deleted.
+    pub fn prune_content_hashes(&mut self) {
+        self.content_hashes.retain(|path, hash| hash.is_current(path));
+    }
+
     pub fn should_check_app_update(&self) -> bool {
         let now = chrono::offset::Utc::now();
         now.signed_duration_since(self.release.checked).num_hours() >= 24
@@ -1,19 +1,61 @@
-use std::num::NonZeroUsize;
+use std::{num::NonZeroUsize, time::Duration};
 
 use crate::{
     lang::{self, Language},
+    media,
     prelude::{app_dir, Error, StrictPath},
-    resource::{ResourceFile, SaveableResourceFile},
+    resource::{
+        playlist::{DisplayFilter, OnEnd},
+        status::Status,
+        ResourceFile, SaveableResourceFile,
+    },
 };
 
+/// Default port for the HTTP API.
+pub const DEFAULT_API_PORT: u16 = 47_808;
+
+/// How many recent destination folders to remember.
+pub const MAX_RECENT_DESTINATIONS: usize = 5;
+
+/// How many destination folders can be pinned.
+/// These can be chosen with keyboard shortcuts, so this should stay in single digits.
+pub const MAX_PINNED_DESTINATIONS: usize = 5;
+
 #[derive(Debug, Clone)]
 pub enum Event {
     Theme(Theme),
     Language(Language),
     CheckRelease(bool),
     ImageDurationRaw(String),
+    SeekStepImageRaw(String),
+    SeekStepAudioRaw(String),
+    SeekStepVideoRaw(String),
     PauseWhenWindowLosesFocus(bool),
+    MuteWhenWindowIsMinimized(bool),
+    PauseWhenWindowIsHidden(bool),
+    LoadSubtitles(bool),
     ConfirmWhenDiscardingUnsavedPlaylist(bool),
+    ConfirmWhenExitingWhilePlaying(bool),
+    ClickToPause(ClickToPause),
+    SpaceTarget(SpaceTarget),
+    MuteNewPlayers(bool),
+    ShowNoMediaNotice(bool),
+    ShowMediaDate(bool),
+    ShowProgressBars(bool),
+    AutosavePlaylist(bool),
+    RestoreLastSession(bool),
+    SaveWindowSizeInPlaylist(bool),
+    AutoRescan(bool),
+    FollowSymlinks(bool),
+    CollapseDuplicates(bool),
+    KeepAwake(bool),
+    PinDestination { folder: StrictPath, pinned: bool },
+    NormalizeAudio(bool),
+    OnEnd(OnEnd),
+    RefreshButton(RefreshButton),
+    HighContrastSelection(bool),
+    UpdateRate(UpdateRate),
+    LogLevel(LogLevel),
 }
 
 /// Settings for `config.yaml`
@@ -23,6 +65,10 @@ pub struct Config {
     pub release: Release,
     pub view: View,
     pub playback: Playback,
+    pub input: Input,
+    pub api: Api,
+    pub status_file: StatusFile,
+    pub rotation: Rotation,
 }
 
 impl ResourceFile for Config {
@@ -44,6 +90,27 @@ impl Config {
         Self::path().move_to(&Self::file_archived_invalid())?;
         Ok(())
     }
+
+    /// Remember a folder that a file was moved or copied to.
+    pub fn add_recent_destination(&mut self, folder: StrictPath) {
+        let recent = &mut self.view.recent_destinations;
+        recent.retain(|x| !x.equivalent(&folder));
+        recent.insert(0, folder);
+        recent.truncate(MAX_RECENT_DESTINATIONS);
+    }
+
+    /// Give a folder the next free keyboard shortcut slot,
+    /// unless it's already pinned or all slots are taken.
+    pub fn pin_destination(&mut self, folder: StrictPath) {
+        let pinned = &mut self.view.pinned_destinations;
+        if pinned.len() < MAX_PINNED_DESTINATIONS && !pinned.iter().any(|x| x.equivalent(&folder)) {
+            pinned.push(folder);
+        }
+    }
+
+    pub fn unpin_destination(&mut self, folder: &StrictPath) {
+        self.view.pinned_destinations.retain(|x| !x.equivalent(folder));
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
@@ -54,18 +121,169 @@ pub struct Release {
     pub check: bool,
 }
 
+impl Release {
+    /// Whether to check for new releases,
+    /// which is never the case in builds without the `update-check` feature.
+    pub fn should_check(&self) -> bool {
+        cfg!(feature = "update-check") && self.check
+    }
+}
+
 impl Default for Release {
     fn default() -> Self {
         Self { check: true }
     }
 }
 
+/// Local HTTP server for controlling the application from other programs.
+/// This is only available in builds with the `http-api` feature.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(default)]
+pub struct Api {
+    pub enabled: bool,
+    /// Address to listen on.
+    /// Requests are only accepted if they're addressed to the same computer.
+    pub host: String,
+    pub port: u16,
+    /// Secret that clients must send in an `Authorization: Bearer <token>` header.
+    /// The server won't start without one.
+    pub token: Option<String>,
+}
+
+impl Api {
+    pub fn token(&self) -> Option<&str> {
+        self.token.as_deref().filter(|token| !token.is_empty())
+    }
+}
+
+impl Default for Api {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: "127.0.0.1".to_string(),
+            port: DEFAULT_API_PORT,
+            token: None,
+        }
+    }
+}
+
+/// A JSON file with the current playback state,
+/// which other programs can poll without using the HTTP API.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(default)]
+pub struct StatusFile {
+    pub enabled: bool,
+    /// Where to write the file.
+    /// If unset, then `status.json` in the application folder is used.
+    pub path: Option<StrictPath>,
+}
+
+impl StatusFile {
+    pub fn path(&self) -> StrictPath {
+        self.path.clone().unwrap_or_else(Status::default_path)
+    }
+}
+
+/// Saved playlists to cycle through on a timer, such as for unattended displays.
+/// The rotation is started and stopped from the main menu.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(default)]
+pub struct Rotation {
+    pub playlists: Vec<RotationEntry>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub struct RotationEntry {
+    pub path: StrictPath,
+    /// How long to show this playlist before moving on to the next one, in seconds.
+    pub duration: NonZeroUsize,
+}
+
+impl RotationEntry {
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs(self.duration.get() as u64)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 #[serde(default)]
 pub struct View {
     pub language: Language,
     pub theme: Theme,
     pub confirm_discard_playlist: bool,
+    /// Whether to ask for confirmation before exiting while any media is playing,
+    /// even if the playlist has no unsaved changes.
+    pub confirm_exit_while_playing: bool,
+    /// Whether to show a notice in grids whose sources don't contain any media.
+    pub show_no_media_notice: bool,
+    /// Whether to show when media was captured or last modified
+    /// in the player overlay.
+    /// For images, this prefers the capture date from EXIF data.
+    pub show_media_date: bool,
+    /// Whether to show details like the file name on every player without hovering.
+    /// This can be toggled with the `I` key.
+    pub show_info: bool,
+    /// Whether to show a thin progress bar at the bottom of each player without hovering.
+    pub show_progress_bars: bool,
+    /// Whether to automatically save changes to a playlist that already has a file.
+    pub autosave_playlist: bool,
+    /// Whether to save the window size in playlists,
+    /// so that loading the playlist restores the size it was designed for.
+    pub save_window_size_in_playlist: bool,
+    /// When launched without any sources,
+    /// reopen the last playlist (or the last sources, if there was no playlist file).
+    pub restore_last_session: bool,
+    /// What to show behind the grids.
+    pub app_background: AppBackground,
+    /// If a player is narrower or shorter than this many pixels,
+    /// then show a placeholder instead of its media.
+    /// Set to 0 to always show the media.
+    pub min_tile_px: u16,
+    /// Whether to hide controls that would change the layout or settings,
+    /// such as for a public display.
+    /// This can also be enabled with the `--kiosk` command line flag.
+    pub kiosk: bool,
+    /// How much detail to write to the log file.
+    /// This is ignored on startup if the `RUST_LOG` environment variable is set.
+    pub log_level: LogLevel,
+    /// Folders for the move/copy keyboard shortcuts,
+    /// where the first one is ctrl+1 (or ctrl+shift+1), and so on.
+    /// Unlike `recent_destinations`, these only change when you pin or unpin a folder.
+    pub pinned_destinations: Vec<StrictPath>,
+    /// Folders recently used when moving or copying files, most recent first.
+    pub recent_destinations: Vec<StrictPath>,
+    /// What the refresh button in the main toolbar should do.
+    pub refresh_button: RefreshButton,
+    /// How selected players and grids are highlighted.
+    pub selection: Selection,
+    /// Hide the top controls, grid title bars, and mouse cursor
+    /// after this many seconds without mouse activity.
+    /// Moving the mouse shows them again.
+    pub autohide_after: Option<NonZeroUsize>,
+    /// How long the cursor must stay over a player before its controls appear, in milliseconds.
+    /// This avoids flickering when moving the cursor across many players.
+    pub overlay_hover_delay_ms: u64,
+    /// Corner radius of each player and grid, in pixels.
+    /// Players get extra padding as this increases so that the corners don't cut into the media.
+    pub tile_radius: u8,
+    /// Whether to draw a drop shadow under each player and grid.
+    pub tile_shadow: bool,
+}
+
+impl View {
+    pub fn autohide_after(&self) -> Option<Duration> {
+        self.autohide_after
+            .map(|seconds| Duration::from_secs(seconds.get() as u64))
+    }
+
+    pub fn overlay_hover_delay(&self) -> Duration {
+        Duration::from_millis(self.overlay_hover_delay_ms)
+    }
+
+    /// Space between a player's border and its media.
+    pub fn tile_padding(&self) -> f32 {
+        (f32::from(self.tile_radius) * 0.3).max(5.0)
+    }
 }
 
 impl Default for View {
@@ -74,10 +292,144 @@ impl Default for View {
             language: Default::default(),
             theme: Default::default(),
             confirm_discard_playlist: true,
+            confirm_exit_while_playing: false,
+            show_no_media_notice: true,
+            show_media_date: false,
+            show_info: false,
+            show_progress_bars: false,
+            autosave_playlist: false,
+            save_window_size_in_playlist: false,
+            restore_last_session: false,
+            app_background: Default::default(),
+            min_tile_px: 0,
+            kiosk: false,
+            log_level: Default::default(),
+            pinned_destinations: vec![],
+            recent_destinations: vec![],
+            refresh_button: RefreshButton::default(),
+            selection: Selection::default(),
+            autohide_after: None,
+            overlay_hover_delay_ms: 0,
+            tile_radius: 10,
+            tile_shadow: false,
         }
     }
 }
 
+/// How selected players and grids are highlighted.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(default)]
+pub struct Selection {
+    /// Border thickness in pixels.
+    pub thickness: u8,
+    /// A color in hex format, like `#1c6bdf`.
+    /// If not set, then the theme's accent color is used.
+    pub color: Option<String>,
+    /// Use a thick, fully opaque border that stands out against any media.
+    /// This uses the theme's text color unless `color` is set,
+    /// and the border will be at least 3 pixels thick.
+    pub high_contrast: bool,
+}
+
+impl Default for Selection {
+    fn default() -> Self {
+        Self {
+            thickness: 1,
+            color: None,
+            high_contrast: false,
+        }
+    }
+}
+
+impl Selection {
+    pub fn color(&self) -> Option<iced::Color> {
+        self.color.as_deref().and_then(parse_hex_color)
+    }
+}
+
+/// What to show behind the grids.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AppBackground {
+    /// Use the theme's background color.
+    #[default]
+    Theme,
+    /// A solid color in hex format, like `#202020`.
+    Color(String),
+    /// An image file, which will be scaled to cover the window.
+    Image(StrictPath),
+}
+
+impl AppBackground {
+    pub fn color(&self) -> Option<iced::Color> {
+        match self {
+            Self::Theme => None,
+            Self::Color(raw) => parse_hex_color(raw),
+            Self::Image(_) => None,
+        }
+    }
+}
+
+fn parse_hex_color(raw: &str) -> Option<iced::Color> {
+    let hex = raw.trim().strip_prefix('#')?;
+    if !hex.is_ascii() {
+        return None;
+    }
+
+    let channel = |index: usize| u8::from_str_radix(hex.get(index..index + 2)?, 16).ok();
+
+    match hex.len() {
+        6 => Some(iced::Color::from_rgb8(channel(0)?, channel(2)?, channel(4)?)),
+        8 => Some(iced::Color::from_rgba8(
+            channel(0)?,
+            channel(2)?,
+            channel(4)?,
+            channel(6)? as f32 / 255.0,
+        )),
+        _ => None,
+    }
+}
+
+/// How much detail to write to the log file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LogLevel {
+    Error,
+    #[default]
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    pub const ALL: &'static [Self] = &[Self::Error, Self::Warn, Self::Info, Self::Debug, Self::Trace];
+
+    /// Specification for `flexi_logger`.
+    pub fn spec(&self) -> &'static str {
+        match self {
+            Self::Error => "madamiru=error",
+            Self::Warn => "madamiru=warn",
+            Self::Info => "madamiru=info",
+            Self::Debug => "madamiru=debug",
+            Self::Trace => "madamiru=trace",
+        }
+    }
+}
+
+impl ToString for LogLevel {
+    fn to_string(&self) -> String {
+        match self {
+            Self::Error => "error",
+            Self::Warn => "warn",
+            Self::Info => "info",
+            Self::Debug => "debug",
+            Self::Trace => "trace",
+        }
+        .to_string()
+    }
+}
+
 /// Visual theme.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -105,6 +457,12 @@ impl ToString for Theme {
 pub struct Playback {
     #[serde(skip)]
     pub paused: bool,
+    #[serde(skip)]
+    pub display_filter: DisplayFilter,
+    /// Play a random part of this many seconds from audio and videos.
+    /// This is only set by grids.
+    #[serde(skip)]
+    pub clip_length: Option<NonZeroUsize>,
     /// Whether all players are muted.
     pub muted: bool,
     /// Volume level when not muted. 1.0 is 100%, 0.01 is 1%.
@@ -113,11 +471,90 @@ pub struct Playback {
     pub image_duration: NonZeroUsize,
     /// Whether to pause when window loses focus.
     pub pause_on_unfocus: bool,
+    /// Whether to mute all players while the application window is minimized.
+    /// Unlike `pause_on_unfocus`, playback continues.
+    pub mute_on_minimize: bool,
+    /// Whether to pause all players while the application window is hidden,
+    /// such as when it's minimized.
+    /// On platforms that can't report this, the window is treated as hidden while it's unfocused.
+    pub pause_when_hidden: bool,
+    /// Pause all players once the battery drops below this percentage
+    /// while running on battery power.
+    /// Playback isn't resumed automatically when the battery recovers.
+    pub pause_on_low_battery: Option<u8>,
     /// Whether to synchronize play/pause/seek events in media of the same category.
     pub synchronized: bool,
+    /// Whether clicking on a player should toggle its pause state.
+    pub click_to_pause: ClickToPause,
+    /// Whether players added to a grid should start muted,
+    /// without affecting players that already exist.
+    pub mute_new_players: bool,
+    /// How many preview thumbnails to generate for each video,
+    /// shown while dragging the seek bar. Set to 0 to disable.
+    pub seek_previews: usize,
+    /// Advanced: a custom GStreamer pipeline for video playback,
+    /// where `{uri}` will be replaced with the video's URI.
+    /// It must use `playbin` with an `appsink` named `iced_video`.
+    /// If unset or invalid, then the built-in pipeline is used.
+    pub video_pipeline: Option<String>,
+    /// Whether to show subtitles from a file next to each video with the same name,
+    /// like `movie.srt` for `movie.mp4`.
+    /// Files that aren't in UTF-8 are converted automatically.
+    pub load_subtitles: bool,
+    /// Whether to periodically check the sources for new media.
+    pub auto_rescan: bool,
+    /// How often to check the sources for new media, in minutes.
+    pub auto_rescan_minutes: NonZeroUsize,
+    /// Whether glob patterns with `**` should traverse symlinked directories.
+    /// Disabling this can avoid loops in unusual folder structures.
+    pub follow_symlinks: bool,
+    /// When scanning sources, skip any folder containing a file with this name,
+    /// along with its subfolders.
+    /// Leave blank to disable.
+    pub ignore_marker: String,
+    /// When scanning sources, skip files larger than this many megabytes.
+    /// Each category can have its own limit, falling back to `all`.
+    pub max_file_size_mb: media::SizeLimits,
+    /// When scanning sources, treat files with identical content as a single file,
+    /// so that duplicates don't show up more often than other media.
+    /// This costs extra disk reads when files have the same size.
+    pub collapse_duplicates: bool,
+    /// How many scans can run at once.
+    /// Lower values can help with spinning disks and network drives.
+    /// If unset, then this is the number of CPUs.
+    pub scan_concurrency: Option<NonZeroUsize>,
+    /// How far to step forward/backward in images, in seconds.
+    pub seek_step_image: NonZeroUsize,
+    /// How far to step forward/backward in audio, in seconds.
+    pub seek_step_audio: NonZeroUsize,
+    /// How far to step forward/backward in videos, in seconds.
+    pub seek_step_video: NonZeroUsize,
+    /// Whether to prevent the screen from sleeping while media is playing.
+    /// This only applies while the application window is focused.
+    pub keep_awake: bool,
+    /// Whether to adjust the volume of each audio file to a consistent loudness.
+    /// This uses the file's ReplayGain tag if available,
+    /// or else it scans the start of the file.
+    pub normalize_audio: bool,
+    /// What to do when a player reaches the end of its media.
+    /// Each grid can override this.
+    pub on_end: OnEnd,
+    /// How often to update players while media is playing.
+    /// Faster rates are smoother but use more CPU.
+    /// While everything is paused, players are updated less often regardless.
+    pub update_rate: UpdateRate,
+    /// Approximate limit for memory used by loaded images, in megabytes.
+    /// When exceeded, media in grids hidden behind a maximized grid is unloaded,
+    /// starting with the grids that have been hidden the longest.
+    pub memory_budget_mb: Option<NonZeroUsize>,
 }
 
 impl Playback {
+    pub fn ignore_marker(&self) -> Option<String> {
+        let marker = self.ignore_marker.trim();
+        (!marker.is_empty()).then(|| marker.to_string())
+    }
+
     pub fn with_paused(&self, paused: bool) -> Self {
         Self { paused, ..self.clone() }
     }
@@ -140,23 +577,214 @@ impl Playback {
         }
     }
 
+    pub fn with_display_filter(&self, display_filter: DisplayFilter) -> Self {
+        Self {
+            display_filter,
+            ..self.clone()
+        }
+    }
+
+    pub fn with_on_end_maybe(&self, on_end: Option<OnEnd>) -> Self {
+        Self {
+            on_end: on_end.unwrap_or(self.on_end),
+            ..self.clone()
+        }
+    }
+
+    pub fn with_image_duration_maybe(&self, image_duration: Option<NonZeroUsize>) -> Self {
+        Self {
+            image_duration: image_duration.unwrap_or(self.image_duration),
+            ..self.clone()
+        }
+    }
+
+    pub fn with_clip_length(&self, clip_length: Option<NonZeroUsize>) -> Self {
+        Self {
+            clip_length,
+            ..self.clone()
+        }
+    }
+
+    /// Playback for a player that is being newly added to a grid.
+    pub fn for_new_player(&self) -> Self {
+        if self.mute_new_players {
+            self.with_muted(true)
+        } else {
+            self.clone()
+        }
+    }
+
     pub fn with_synchronized(&self, synchronized: bool) -> Self {
         Self {
             synchronized,
             ..self.clone()
         }
     }
+
+    /// Memory budget in bytes.
+    pub fn memory_budget(&self) -> Option<usize> {
+        self.memory_budget_mb.map(|mb| mb.get().saturating_mul(1024 * 1024))
+    }
 }
 
 impl Default for Playback {
     fn default() -> Self {
         Self {
             paused: false,
+            display_filter: DisplayFilter::None,
+            clip_length: None,
             muted: false,
             volume: 1.0,
             image_duration: NonZeroUsize::new(10).unwrap(),
             pause_on_unfocus: false,
+            mute_on_minimize: false,
+            pause_when_hidden: false,
+            pause_on_low_battery: None,
             synchronized: false,
+            click_to_pause: ClickToPause::default(),
+            mute_new_players: false,
+            seek_previews: 20,
+            video_pipeline: None,
+            load_subtitles: false,
+            auto_rescan: true,
+            auto_rescan_minutes: NonZeroUsize::new(10).unwrap(),
+            follow_symlinks: true,
+            ignore_marker: ".madamiru-ignore".to_string(),
+            max_file_size_mb: media::SizeLimits::default(),
+            collapse_duplicates: false,
+            scan_concurrency: None,
+            seek_step_image: NonZeroUsize::new(2).unwrap(),
+            seek_step_audio: NonZeroUsize::new(10).unwrap(),
+            seek_step_video: NonZeroUsize::new(10).unwrap(),
+            keep_awake: true,
+            normalize_audio: false,
+            on_end: OnEnd::SwapRandom,
+            update_rate: UpdateRate::default(),
+            memory_budget_mb: None,
+        }
+    }
+}
+
+/// How clicking on a player affects its playback.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ClickToPause {
+    /// Clicking does not affect playback.
+    #[default]
+    Off,
+    /// A single click toggles the pause state.
+    SingleClick,
+    /// A double click toggles the pause state.
+    DoubleClick,
+}
+
+impl ClickToPause {
+    pub const ALL: &'static [Self] = &[Self::Off, Self::SingleClick, Self::DoubleClick];
+}
+
+impl ToString for ClickToPause {
+    fn to_string(&self) -> String {
+        match self {
+            Self::Off => lang::state::off(),
+            Self::SingleClick => lang::state::single_click(),
+            Self::DoubleClick => lang::state::double_click(),
+        }
+    }
+}
+
+/// How keyboard shortcuts behave.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(default)]
+pub struct Input {
+    /// What the space key should pause or resume when nothing is selected.
+    /// If a grid or player is selected, then the space key always targets that.
+    pub space_target: SpaceTarget,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SpaceTarget {
+    /// Toggle the global pause state.
+    #[default]
+    Global,
+    /// Toggle the player under the mouse cursor, if any.
+    HoveredTile,
+    /// Do nothing unless something is selected.
+    Selection,
+}
+
+impl SpaceTarget {
+    pub const ALL: &'static [Self] = &[Self::Global, Self::HoveredTile, Self::Selection];
+}
+
+impl ToString for SpaceTarget {
+    fn to_string(&self) -> String {
+        match self {
+            Self::Global => lang::state::all_players(),
+            Self::HoveredTile => lang::state::player_under_cursor(),
+            Self::Selection => lang::state::selection_only(),
+        }
+    }
+}
+
+/// What the refresh button in the main toolbar should do.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RefreshButton {
+    /// Switch to other media that has already been found.
+    #[default]
+    Shuffle,
+    /// Check the sources for new media, without switching what's playing.
+    Rescan,
+    /// Switch to other media and also check the sources for new media.
+    Both,
+}
+
+impl RefreshButton {
+    pub const ALL: &'static [Self] = &[Self::Shuffle, Self::Rescan, Self::Both];
+}
+
+impl ToString for RefreshButton {
+    fn to_string(&self) -> String {
+        match self {
+            Self::Shuffle => lang::action::shuffle(),
+            Self::Rescan => lang::action::check_for_new_media(),
+            Self::Both => lang::action::shuffle_and_check_for_new_media(),
+        }
+    }
+}
+
+/// How often to update players while media is playing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateRate {
+    /// About 30 times per second.
+    Smooth,
+    /// 10 times per second.
+    #[default]
+    Balanced,
+    /// 4 times per second.
+    PowerSaving,
+}
+
+impl UpdateRate {
+    pub const ALL: &'static [Self] = &[Self::Smooth, Self::Balanced, Self::PowerSaving];
+
+    pub fn interval(&self) -> Duration {
+        match self {
+            Self::Smooth => Duration::from_millis(33),
+            Self::Balanced => Duration::from_millis(100),
+            Self::PowerSaving => Duration::from_millis(250),
+        }
+    }
+}
+
+impl ToString for UpdateRate {
+    fn to_string(&self) -> String {
+        match self {
+            Self::Smooth => lang::state::smooth(),
+            Self::Balanced => lang::state::balanced(),
+            Self::PowerSaving => lang::state::power_saving(),
         }
     }
 }
@@ -177,19 +805,86 @@ mod tests {
     #[test]
     fn can_parse_optional_fields_when_present_in_config() {
         let config = Config::load_from_string(
-            r#"
+            r##"
                 release:
                   check: false
                 view:
                   theme: light
                   confirm_discard_playlist: false
+                  confirm_exit_while_playing: true
+                  show_no_media_notice: false
+                  show_media_date: true
+                  show_info: true
+                  show_progress_bars: true
+                  autosave_playlist: true
+                  save_window_size_in_playlist: true
+                  restore_last_session: true
+                  app_background:
+                    color: "#102030"
+                  min_tile_px: 80
+                  kiosk: true
+                  log_level: debug
+                  pinned_destinations:
+                    - /tmp/pinned
+                  recent_destinations:
+                    - /tmp/keep
+                  refresh_button: both
+                  selection:
+                    thickness: 4
+                    color: "#ffcc00"
+                    high_contrast: true
+                  autohide_after: 3
+                  overlay_hover_delay_ms: 300
+                  tile_radius: 20
+                  tile_shadow: true
                 playback:
                   muted: true
                   volume: 0.5
                   image_duration: 2
                   pause_on_unfocus: true
+                  mute_on_minimize: true
+                  pause_when_hidden: true
+                  pause_on_low_battery: 20
                   synchronized: true
-            "#,
+                  click_to_pause: double_click
+                  mute_new_players: true
+                  seek_previews: 5
+                  video_pipeline: playbin uri="{uri}"
+                  load_subtitles: true
+                  auto_rescan: false
+                  auto_rescan_minutes: 30
+                  follow_symlinks: false
+                  ignore_marker: .skip
+                  max_file_size_mb:
+                    all: 500
+                    video: 4000
+                  collapse_duplicates: true
+                  scan_concurrency: 2
+                  seek_step_image: 1
+                  seek_step_audio: 30
+                  seek_step_video: 5
+                  keep_awake: false
+                  normalize_audio: true
+                  on_end: stop
+                  update_rate: smooth
+                  memory_budget_mb: 512
+                input:
+                  space_target: hovered_tile
+                api:
+                  enabled: true
+                  host: 0.0.0.0
+                  port: 8080
+                  token: secret
+                status_file:
+                  enabled: true
+                  path: /tmp/status.json
+                rotation:
+                  playlists:
+                    - path: /tmp/first.madamiru
+                      duration: 60
+                    - path: /tmp/second.madamiru
+                      duration: 300
+            "##,
         )
         .unwrap();
 
@@ -199,21 +894,158 @@ mod tests {
                 view: View {
                     language: Language::English,
                     theme: Theme::Light,
-                    confirm_discard_playlist: false
+                    confirm_discard_playlist: false,
+                    confirm_exit_while_playing: true,
+                    show_no_media_notice: false,
+                    show_media_date: true,
+                    show_info: true,
+                    show_progress_bars: true,
+                    autosave_playlist: true,
+                    save_window_size_in_playlist: true,
+                    restore_last_session: true,
+                    app_background: AppBackground::Color("#102030".to_string()),
+                    min_tile_px: 80,
+                    kiosk: true,
+                    log_level: LogLevel::Debug,
+                    pinned_destinations: vec![StrictPath::new("/tmp/pinned")],
+                    recent_destinations: vec![StrictPath::new("/tmp/keep")],
+                    refresh_button: RefreshButton::Both,
+                    selection: Selection {
+                        thickness: 4,
+                        color: Some("#ffcc00".to_string()),
+                        high_contrast: true,
+                    },
+                    autohide_after: NonZeroUsize::new(3),
+                    overlay_hover_delay_ms: 300,
+                    tile_radius: 20,
+                    tile_shadow: true,
                 },
                 playback: Playback {
                     paused: false,
+                    display_filter: DisplayFilter::None,
+                    clip_length: None,
                     muted: true,
                     volume: 0.5,
                     image_duration: NonZeroUsize::new(2).unwrap(),
                     pause_on_unfocus: true,
+                    mute_on_minimize: true,
+                    pause_when_hidden: true,
+                    pause_on_low_battery: Some(20),
                     synchronized: true,
+                    click_to_pause: ClickToPause::DoubleClick,
+                    mute_new_players: true,
+                    seek_previews: 5,
+                    video_pipeline: Some(r#"playbin uri="{uri}""#.to_string()),
+                    load_subtitles: true,
+                    auto_rescan: false,
+                    auto_rescan_minutes: NonZeroUsize::new(30).unwrap(),
+                    follow_symlinks: false,
+                    ignore_marker: ".skip".to_string(),
+                    max_file_size_mb: media::SizeLimits {
+                        all: Some(500),
+                        image: None,
+                        audio: None,
+                        video: Some(4000),
+                    },
+                    collapse_duplicates: true,
+                    scan_concurrency: NonZeroUsize::new(2),
+                    seek_step_image: NonZeroUsize::new(1).unwrap(),
+                    seek_step_audio: NonZeroUsize::new(30).unwrap(),
+                    seek_step_video: NonZeroUsize::new(5).unwrap(),
+                    keep_awake: false,
+                    normalize_audio: true,
+                    on_end: OnEnd::Stop,
+                    update_rate: UpdateRate::Smooth,
+                    memory_budget_mb: NonZeroUsize::new(512),
+                },
+                input: Input {
+                    space_target: SpaceTarget::HoveredTile,
+                },
+                api: Api {
+                    enabled: true,
+                    host: "0.0.0.0".to_string(),
+                    port: 8080,
+                    token: Some("secret".to_string()),
+                },
+                status_file: StatusFile {
+                    enabled: true,
+                    path: Some(StrictPath::new("/tmp/status.json")),
+                },
+                rotation: Rotation {
+                    playlists: vec![
+                        RotationEntry {
+                            path: StrictPath::new("/tmp/first.madamiru"),
+                            duration: NonZeroUsize::new(60).unwrap(),
+                        },
+                        RotationEntry {
+                            path: StrictPath::new("/tmp/second.madamiru"),
+                            duration: NonZeroUsize::new(300).unwrap(),
+                        },
+                    ],
                 },
             },
             config,
         );
     }
 
+    #[test]
+    fn can_add_recent_destination() {
+        let mut config = Config::default();
+
+        config.add_recent_destination(StrictPath::new("/a"));
+        config.add_recent_destination(StrictPath::new("/b"));
+        config.add_recent_destination(StrictPath::new("/a"));
+        assert_eq!(
+            vec![StrictPath::new("/a"), StrictPath::new("/b")],
+            config.view.recent_destinations
+        );
+
+        for i in 0..10 {
+            config.add_recent_destination(StrictPath::new(format!("/{i}")));
+        }
+        assert_eq!(MAX_RECENT_DESTINATIONS, config.view.recent_destinations.len());
+        assert_eq!(StrictPath::new("/9"), config.view.recent_destinations[0]);
+    }
+
+    #[test]
+    fn can_pin_destination() {
+        let mut config = Config::default();
+
+        config.pin_destination(StrictPath::new("/a"));
+        config.pin_destination(StrictPath::new("/b"));
+        config.pin_destination(StrictPath::new("/a"));
+        config.add_recent_destination(StrictPath::new("/b"));
+        assert_eq!(
+            vec![StrictPath::new("/a"), StrictPath::new("/b")],
+            config.view.pinned_destinations
+        );
+
+        config.unpin_destination(&StrictPath::new("/a"));
+        assert_eq!(vec![StrictPath::new("/b")], config.view.pinned_destinations);
+
+        for i in 0..10 {
+            config.pin_destination(StrictPath::new(format!("/{i}")));
+        }
+        assert_eq!(MAX_PINNED_DESTINATIONS, config.view.pinned_destinations.len());
+        assert_eq!(StrictPath::new("/b"), config.view.pinned_destinations[0]);
+    }
+
+    #[test]
+    fn can_parse_app_background_color() {
+        assert_eq!(
+            Some(iced::Color::from_rgb8(0x10, 0x20, 0x30)),
+            AppBackground::Color("#102030".to_string()).color()
+        );
+        assert_eq!(
+            Some(iced::Color::from_rgba8(0x10, 0x20, 0x30, 0.0)),
+            AppBackground::Color("#10203000".to_string()).color()
+        );
+        assert_eq!(None, AppBackground::Color("102030".to_string()).color());
+        assert_eq!(None, AppBackground::Color("#1020".to_string()).color());
+        assert_eq!(None, AppBackground::Color("#zz2030".to_string()).color());
+        assert_eq!(None, AppBackground::Theme.color());
+    }
+
     #[test]
     fn can_be_serialized() {
         assert_eq!(
@@ -225,12 +1057,74 @@ view:
   language: en-US
   theme: dark
   confirm_discard_playlist: true
+  confirm_exit_while_playing: false
+  show_no_media_notice: true
+  show_media_date: false
+  show_info: false
+  show_progress_bars: false
+  autosave_playlist: false
+  save_window_size_in_playlist: false
+  restore_last_session: false
+  app_background: theme
+  min_tile_px: 0
+  kiosk: false
+  log_level: warn
+  pinned_destinations: []
+  recent_destinations: []
+  refresh_button: shuffle
+  selection:
+    thickness: 1
+    color: ~
+    high_contrast: false
+  autohide_after: ~
+  overlay_hover_delay_ms: 0
+  tile_radius: 10
+  tile_shadow: false
 playback:
   muted: false
   volume: 1.0
   image_duration: 10
   pause_on_unfocus: false
+  mute_on_minimize: false
+  pause_when_hidden: false
+  pause_on_low_battery: ~
   synchronized: false
+  click_to_pause: off
+  mute_new_players: false
+  seek_previews: 20
+  video_pipeline: ~
+  load_subtitles: false
+  auto_rescan: true
+  auto_rescan_minutes: 10
+  follow_symlinks: true
+  ignore_marker: ".madamiru-ignore"
+  max_file_size_mb:
+    all: ~
+    image: ~
+    audio: ~
+    video: ~
+  collapse_duplicates: false
+  scan_concurrency: ~
+  seek_step_image: 2
+  seek_step_audio: 10
+  seek_step_video: 10
+  keep_awake: true
+  normalize_audio: false
+  on_end: swap_random
+  update_rate: balanced
+  memory_budget_mb: ~
+input:
+  space_target: global
+api:
+  enabled: false
+  host: 127.0.0.1
+  port: 47808
+  token: ~
+status_file:
+  enabled: false
+  path: ~
+rotation:
+  playlists: []
 "#
             .trim(),
             serde_yaml::to_string(&Config::default()).unwrap().trim(),
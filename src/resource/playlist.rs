@@ -1,4 +1,4 @@
-use std::num::NonZeroUsize;
+use std::{num::NonZeroUsize, time::Duration};
 
 use itertools::Itertools;
 
@@ -15,6 +15,17 @@ const HINT: &str = "# madamiru-playlist";
 #[serde(default)]
 pub struct Playlist {
     pub layout: Layout,
+    /// The grid/player that was selected when the playlist was saved.
+    pub active: Option<Active>,
+    /// The window size that the layout was designed for.
+    /// This is only saved when enabled in the settings.
+    pub window: Option<WindowSize>,
+    /// Whether to prevent grids from being dragged or resized.
+    pub layout_locked: bool,
+    /// Audio file to play alongside all of the grids.
+    pub soundtrack: Option<StrictPath>,
+    /// Session settings to apply when the playlist is loaded.
+    pub settings: Option<Settings>,
 }
 
 impl ResourceFile for Playlist {
@@ -24,8 +35,22 @@ impl ResourceFile for Playlist {
 impl Playlist {
     pub const EXTENSION: &'static str = "madamiru";
 
-    pub fn new(layout: Layout) -> Self {
-        Self { layout }
+    pub fn new(
+        layout: Layout,
+        active: Option<Active>,
+        window: Option<WindowSize>,
+        layout_locked: bool,
+        soundtrack: Option<StrictPath>,
+        settings: Option<Settings>,
+    ) -> Self {
+        Self {
+            layout,
+            active,
+            window,
+            layout_locked,
+            soundtrack,
+            settings,
+        }
     }
 
     pub fn load_from(path: &StrictPath) -> Result<Self, Error> {
@@ -62,6 +87,61 @@ impl Playlist {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(default)]
+pub struct Active {
+    /// Index of the grid, in the order that grids appear in the layout.
+    pub grid: usize,
+    /// Index of the player within the grid.
+    /// If unset, then the grid as a whole is selected.
+    pub player: Option<usize>,
+}
+
+/// Session settings to apply when a playlist is loaded.
+/// These take precedence over the config file,
+/// but you can still change them afterward as usual.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(default)]
+pub struct Settings {
+    /// Whether to pause or play all media.
+    /// If unset, then the current state is kept.
+    pub paused: Option<bool>,
+    /// Whether to mute or unmute all media.
+    /// If unset, then the current state is kept.
+    pub muted: Option<bool>,
+    /// Whether to switch the window to fullscreen.
+    pub fullscreen: bool,
+    /// Shuffle all grids every this many seconds.
+    pub shuffle_interval: Option<NonZeroUsize>,
+}
+
+impl Settings {
+    pub fn shuffle_interval(&self) -> Option<Duration> {
+        self.shuffle_interval
+            .map(|seconds| Duration::from_secs(seconds.get() as u64))
+    }
+}
+
+/// Window size in logical pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(default)]
+pub struct WindowSize {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl WindowSize {
+    const MIN_WIDTH: u32 = 480;
+    const MIN_HEIGHT: u32 = 360;
+    const MAX: u32 = 16_384;
+
+    /// Whether the size is worth applying,
+    /// as opposed to a typo or a window that was minimized when saved.
+    pub fn is_reasonable(&self) -> bool {
+        (Self::MIN_WIDTH..=Self::MAX).contains(&self.width) && (Self::MIN_HEIGHT..=Self::MAX).contains(&self.height)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum Layout {
@@ -79,7 +159,21 @@ impl Layout {
                 .chain(split.second.sources())
                 .unique()
                 .collect(),
-            Layout::Group(group) => group.sources.iter().unique().cloned().collect(),
+            Layout::Group(group) => group
+                .sources
+                .iter()
+                .chain(&group.fallback_source)
+                .unique()
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// All of the groups, in the same order as their indices.
+    pub fn groups(&self) -> Vec<&Group> {
+        match self {
+            Layout::Split(split) => split.first.groups().into_iter().chain(split.second.groups()).collect(),
+            Layout::Group(group) => vec![group],
         }
     }
 }
@@ -121,21 +215,70 @@ pub enum SplitAxis {
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 #[serde(default)]
 pub struct Group {
+    /// Label to show in the grid's title bar.
+    pub name: Option<String>,
     pub sources: Vec<media::Source>,
+    /// Where to find media for players that the main sources can't fill,
+    /// such as a folder of filler images.
+    pub fallback_source: Option<media::Source>,
     pub max_media: usize,
     pub content_fit: ContentFit,
+    /// How to smooth images when they're scaled.
+    pub filter_method: FilterMethod,
     pub orientation: Orientation,
     pub orientation_limit: OrientationLimit,
+    pub aspect_override: AspectOverride,
+    /// How long to show images, in seconds.
+    /// If unset, then the global setting is used.
+    pub image_duration: Option<NonZeroUsize>,
+    /// If set, then audio and videos longer than this many seconds
+    /// only play a random part of this length before moving on.
+    pub clip_length: Option<NonZeroUsize>,
+    /// Color effect to apply to images and videos.
+    pub filter: DisplayFilter,
+    /// What to do when a player reaches the end of its media.
+    /// If unset, then the global setting is used.
+    pub on_end: Option<OnEnd>,
+    /// Whether to show how many players are active
+    /// and how much media is available in the title bar.
+    pub show_counter: bool,
+    /// Whether this group can show media that's already shown in another group.
+    /// If disabled, duplicates are only shown when there isn't enough other media.
+    pub allow_duplicates_on_screen: bool,
+    /// Whether to switch to new media whenever this group is selected.
+    pub shuffle_on_focus: bool,
+    /// Whether to go through the media in order by file path instead of at random.
+    pub cycle_in_order: bool,
+    /// How visible the media is, as a percentage.
+    /// Lower values dim the media against the background.
+    pub opacity: u8,
+    /// Show the same media as another group instead of choosing its own.
+    /// This is the other group's index in the layout, counting from the top-left.
+    pub mirror_of: Option<usize>,
 }
 
 impl Default for Group {
     fn default() -> Self {
         Self {
+            name: None,
             sources: Default::default(),
+            fallback_source: None,
             max_media: 1,
             content_fit: Default::default(),
+            filter_method: Default::default(),
             orientation: Default::default(),
             orientation_limit: Default::default(),
+            aspect_override: Default::default(),
+            image_duration: None,
+            clip_length: None,
+            filter: Default::default(),
+            on_end: None,
+            show_counter: false,
+            allow_duplicates_on_screen: true,
+            shuffle_on_focus: false,
+            cycle_in_order: false,
+            opacity: 100,
+            mirror_of: None,
         }
     }
 }
@@ -208,6 +351,12 @@ pub enum ContentFit {
 
 impl ContentFit {
     pub const ALL: &'static [Self] = &[Self::Scale, Self::ScaleDown, Self::Crop, Self::Stretch];
+
+    /// The next option in `ALL`, wrapping around to the first.
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|x| x == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
 }
 
 impl ToString for ContentFit {
@@ -232,6 +381,168 @@ impl From<ContentFit> for iced::ContentFit {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterMethod {
+    /// Blend neighboring pixels, which looks best for photos and most other images.
+    #[default]
+    Smooth,
+
+    /// Use the nearest pixel without blending,
+    /// which keeps pixel art crisp when it's scaled up.
+    Pixelated,
+}
+
+impl FilterMethod {
+    pub const ALL: &'static [Self] = &[Self::Smooth, Self::Pixelated];
+}
+
+impl ToString for FilterMethod {
+    fn to_string(&self) -> String {
+        match self {
+            FilterMethod::Smooth => lang::action::scale_smoothly(),
+            FilterMethod::Pixelated => lang::action::scale_pixelated(),
+        }
+    }
+}
+
+impl From<FilterMethod> for iced::widget::image::FilterMethod {
+    fn from(value: FilterMethod) -> Self {
+        match value {
+            FilterMethod::Smooth => iced::widget::image::FilterMethod::Linear,
+            FilterMethod::Pixelated => iced::widget::image::FilterMethod::Nearest,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AspectOverride {
+    /// Use the media's own aspect ratio.
+    #[default]
+    Original,
+
+    /// Stretch the media to fill all of the available space.
+    Stretch,
+
+    /// Force a 16:9 aspect ratio.
+    #[serde(rename = "16:9")]
+    Widescreen,
+
+    /// Force a 4:3 aspect ratio.
+    #[serde(rename = "4:3")]
+    Standard,
+}
+
+impl AspectOverride {
+    pub const ALL: &'static [Self] = &[Self::Original, Self::Stretch, Self::Widescreen, Self::Standard];
+
+    /// Width divided by height, if this overrides the media's own ratio.
+    pub fn ratio(&self) -> Option<f32> {
+        match self {
+            Self::Original => None,
+            Self::Stretch => None,
+            Self::Widescreen => Some(16.0 / 9.0),
+            Self::Standard => Some(4.0 / 3.0),
+        }
+    }
+}
+
+impl ToString for AspectOverride {
+    fn to_string(&self) -> String {
+        match self {
+            Self::Original => lang::state::original(),
+            Self::Stretch => lang::action::stretch(),
+            Self::Widescreen => "16:9".to_string(),
+            Self::Standard => "4:3".to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DisplayFilter {
+    /// Show the media's original colors.
+    #[default]
+    None,
+
+    /// Remove all color.
+    Grayscale,
+
+    /// Use brownish tones, like an old photograph.
+    Sepia,
+
+    /// Invert all colors.
+    /// For videos, this requires GStreamer's OpenGL plugin.
+    Invert,
+}
+
+impl DisplayFilter {
+    pub const ALL: &'static [Self] = &[Self::None, Self::Grayscale, Self::Sepia, Self::Invert];
+
+    /// Transform an RGBA pixel, leaving the alpha channel as-is.
+    pub fn apply(&self, pixel: [u8; 4]) -> [u8; 4] {
+        let [r, g, b, a] = pixel;
+        let (r, g, b) = (r as f32, g as f32, b as f32);
+        let clamp = |value: f32| value.round().min(255.0) as u8;
+
+        match self {
+            Self::None => pixel,
+            Self::Grayscale => {
+                let luma = clamp(0.299 * r + 0.587 * g + 0.114 * b);
+                [luma, luma, luma, a]
+            }
+            Self::Sepia => [
+                clamp(0.393 * r + 0.769 * g + 0.189 * b),
+                clamp(0.349 * r + 0.686 * g + 0.168 * b),
+                clamp(0.272 * r + 0.534 * g + 0.131 * b),
+                a,
+            ],
+            Self::Invert => [255 - pixel[0], 255 - pixel[1], 255 - pixel[2], a],
+        }
+    }
+}
+
+impl ToString for DisplayFilter {
+    fn to_string(&self) -> String {
+        match self {
+            Self::None => lang::state::off(),
+            Self::Grayscale => lang::state::grayscale(),
+            Self::Sepia => lang::state::sepia(),
+            Self::Invert => lang::state::inverted(),
+        }
+    }
+}
+
+/// What a player should do when it reaches the end of its media.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OnEnd {
+    /// Switch to another random media from the grid's sources.
+    #[default]
+    SwapRandom,
+
+    /// Play the same media again.
+    Loop,
+
+    /// Unload the media, but keep the player in place.
+    Stop,
+}
+
+impl OnEnd {
+    pub const ALL: &'static [Self] = &[Self::SwapRandom, Self::Loop, Self::Stop];
+}
+
+impl ToString for OnEnd {
+    fn to_string(&self) -> String {
+        match self {
+            Self::SwapRandom => lang::action::shuffle(),
+            Self::Loop => lang::action::repeat(),
+            Self::Stop => lang::action::stop(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
@@ -251,14 +562,43 @@ mod tests {
             r#"
                 layout:
                   group:
+                    name: Photos
                     sources:
                       - path:
                           path: tmp
+                    fallback_source:
+                      glob:
+                        pattern: filler/*.jpg
                     max_media: 4
                     content_fit: crop
+                    filter_method: pixelated
                     orientation: vertical
                     orientation_limit:
                       fixed: 2
+                    aspect_override: "16:9"
+                    image_duration: 3
+                    clip_length: 20
+                    filter: sepia
+                    on_end: loop
+                    show_counter: true
+                    allow_duplicates_on_screen: false
+                    shuffle_on_focus: true
+                    cycle_in_order: true
+                    opacity: 50
+                    mirror_of: 1
+                active:
+                  grid: 0
+                  player: 1
+                window:
+                  width: 1280
+                  height: 720
+                layout_locked: true
+                soundtrack: music.mp3
+                settings:
+                  paused: true
+                  muted: false
+                  fullscreen: true
+                  shuffle_interval: 30
             "#,
         )
         .unwrap();
@@ -266,12 +606,42 @@ mod tests {
         assert_eq!(
             Playlist {
                 layout: Layout::Group(Group {
+                    name: Some("Photos".to_string()),
                     sources: vec![media::Source::new_path(StrictPath::new("tmp"))],
+                    fallback_source: Some(media::Source::new_glob("filler/*.jpg".to_string())),
                     max_media: 4,
                     content_fit: ContentFit::Crop,
+                    filter_method: FilterMethod::Pixelated,
                     orientation: Orientation::Vertical,
-                    orientation_limit: OrientationLimit::Fixed(NonZeroUsize::new(2).unwrap())
-                })
+                    orientation_limit: OrientationLimit::Fixed(NonZeroUsize::new(2).unwrap()),
+                    aspect_override: AspectOverride::Widescreen,
+                    image_duration: Some(NonZeroUsize::new(3).unwrap()),
+                    clip_length: Some(NonZeroUsize::new(20).unwrap()),
+                    filter: DisplayFilter::Sepia,
+                    on_end: Some(OnEnd::Loop),
+                    show_counter: true,
+                    allow_duplicates_on_screen: false,
+                    shuffle_on_focus: true,
+                    cycle_in_order: true,
+                    opacity: 50,
+                    mirror_of: Some(1),
+                }),
+                active: Some(Active {
+                    grid: 0,
+                    player: Some(1),
+                }),
+                window: Some(WindowSize {
+                    width: 1280,
+                    height: 720,
+                }),
+                layout_locked: true,
+                soundtrack: Some(StrictPath::new("music.mp3")),
+                settings: Some(Settings {
+                    paused: Some(true),
+                    muted: Some(false),
+                    fullscreen: true,
+                    shuffle_interval: NonZeroUsize::new(30),
+                }),
             },
             playlist,
         );
@@ -285,14 +655,59 @@ mod tests {
 # madamiru-playlist
 layout:
   group:
+    name: ~
     sources: []
+    fallback_source: ~
     max_media: 1
     content_fit: scale
+    filter_method: smooth
     orientation: horizontal
     orientation_limit: automatic
+    aspect_override: original
+    image_duration: ~
+    clip_length: ~
+    filter: none
+    on_end: ~
+    show_counter: false
+    allow_duplicates_on_screen: true
+    shuffle_on_focus: false
+    cycle_in_order: false
+    opacity: 100
+    mirror_of: ~
+active: ~
+window: ~
+layout_locked: false
+soundtrack: ~
+settings: ~
 "#
             .trim(),
             Playlist::default().serialize().trim(),
         );
     }
+
+    #[test]
+    fn can_check_window_size() {
+        assert!(WindowSize {
+            width: 1280,
+            height: 720
+        }
+        .is_reasonable());
+        assert!(!WindowSize { width: 0, height: 720 }.is_reasonable());
+        assert!(!WindowSize {
+            width: 1280,
+            height: 100_000
+        }
+        .is_reasonable());
+    }
+
+    #[test]
+    fn can_apply_display_filter() {
+        let pixel = [200, 100, 50, 128];
+
+        assert_eq!([200, 100, 50, 128], DisplayFilter::None.apply(pixel));
+        assert_eq!([124, 124, 124, 128], DisplayFilter::Grayscale.apply(pixel));
+        assert_eq!([165, 147, 114, 128], DisplayFilter::Sepia.apply(pixel));
+        assert_eq!([55, 155, 205, 128], DisplayFilter::Invert.apply(pixel));
+        assert_eq!([255, 255, 239, 255], DisplayFilter::Sepia.apply([255, 255, 255, 255]));
+    }
 }
@@ -0,0 +1,98 @@
+use crate::prelude::{app_dir, StrictPath};
+
+/// Snapshot of the playback state for other programs to poll,
+/// as a lighter alternative to the HTTP API.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize)]
+pub struct Status {
+    pub paused: bool,
+    pub muted: bool,
+    pub playlist: Option<StrictPath>,
+    /// How many players currently have media loaded.
+    pub active_players: usize,
+}
+
+impl Status {
+    pub const FILE_NAME: &'static str = "status.json";
+
+    pub fn default_path() -> StrictPath {
+        app_dir().joined(Self::FILE_NAME)
+    }
+
+    pub fn save_to(&self, path: &StrictPath) {
+        let new_content = match serde_json::to_string_pretty(self) {
+            Ok(content) => content,
+            Err(e) => {
+                log::error!("Unable to serialize status: {e:?}");
+                return;
+            }
+        };
+
+        if let Ok(old_content) = path.try_read() {
+            if old_content == new_content {
+                return;
+            }
+        }
+
+        // Other programs may read the file at any time,
+        // so we replace it all at once instead of writing it in place.
+        let temp = StrictPath::new(format!("{}.tmp", path.resolve()));
+        if let Err(e) = path
+            .create_parent_dir()
+            .and_then(|_| temp.write_with_content(&new_content))
+            .and_then(|_| temp.move_to(path))
+        {
+            log::error!("Unable to write status file: {path:?} | {e:?}");
+            let _ = temp.remove();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::testing::TempDir;
+
+    #[test]
+    fn can_be_serialized() {
+        let status = Status {
+            paused: false,
+            muted: true,
+            playlist: Some(StrictPath::new("/tmp/playlist.madamiru")),
+            active_players: 3,
+        };
+
+        assert_eq!(
+            r#"
+{
+  "paused": false,
+  "muted": true,
+  "playlist": "/tmp/playlist.madamiru",
+  "active_players": 3
+}
+"#
+            .trim(),
+            serde_json::to_string_pretty(&status).unwrap(),
+        );
+    }
+
+    #[test]
+    fn can_save_without_leaving_temporary_file() {
+        let root = TempDir::new("status");
+        let path = StrictPath::from(root.join("status.json"));
+
+        let status = Status {
+            paused: true,
+            ..Default::default()
+        };
+        status.save_to(&path);
+        Status::default().save_to(&path);
+
+        assert_eq!(
+            serde_json::to_string_pretty(&Status::default()).unwrap(),
+            path.try_read().unwrap()
+        );
+        assert!(!root.join("status.json.tmp").exists());
+    }
+}